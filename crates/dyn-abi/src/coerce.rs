@@ -1,18 +1,18 @@
 use crate::{ty::as_tuple, DynSolType, DynSolValue, Result};
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use alloy_primitives::{Address, FixedBytes, Function, Sign, I256, U256};
 use alloy_sol_types::Word;
 use core::fmt;
 use hex::FromHexError;
-use parser::utils::{array_parser, char_parser, spanned};
+use parser::utils::{char_parser, spanned};
 use winnow::{
-    ascii::{alpha0, alpha1, digit1, hex_digit0, hex_digit1, space0},
-    combinator::{cut_err, dispatch, empty, fail, opt, preceded, trace},
+    ascii::{alpha0, alpha1, digit1, hex_digit0, hex_digit1, multispace0, space0},
+    combinator::{cut_err, delimited, dispatch, empty, fail, opt, preceded, separated, trace},
     error::{
-        AddContext, ContextError, ErrMode, ErrorKind, FromExternalError, StrContext,
+        AddContext, ContextError, ErrMode, ErrorKind, FromExternalError, ParserError, StrContext,
         StrContextValue,
     },
-    stream::Stream,
+    stream::{Accumulate, Stream},
     token::take_while,
     PResult, Parser,
 };
@@ -29,14 +29,13 @@ impl DynSolType {
     ///     `0b`, `0o`, or `0x` respectively.
     ///   - unit: same as [Solidity ether units](https://docs.soliditylang.org/en/latest/units-and-global-variables.html#ether-units)
     ///   - decimals with more digits than the unit's exponent value are not allowed
-    ///   - decimals are only allowed when the `std` feature is enabled due to floating point
-    ///     operations; this may be relaxed in the future
     /// - [`FixedBytes`](DynSolType::FixedBytes): `(0x)?[0-9A-Fa-f]{$0*2}`
     /// - [`Address`](DynSolType::Address): `(0x)?[0-9A-Fa-f]{40}`
     /// - [`Function`](DynSolType::Function): `(0x)?[0-9A-Fa-f]{48}`
     /// - [`Bytes`](DynSolType::Bytes): `(0x)?[0-9A-Fa-f]+`
     /// - [`String`](DynSolType::String): `.*`
-    ///   - can be surrounded by a pair of `"` or `'`
+    ///   - can be surrounded by a pair of `"` or `'`, in which case Solidity escape sequences
+    ///     (`\\`, `\"`, `\'`, `\n`, `\r`, `\t`, `\xNN` and `\uNNNN`) are unescaped
     ///   - trims whitespace if not surrounded
     /// - [`Array`](DynSolType::Array): any number of the inner type delimited by commas (`,`) and
     ///   surrounded by brackets (`[]`)
@@ -44,6 +43,10 @@ impl DynSolType {
     ///   delimited by commas (`,`) and surrounded by brackets (`[]`)
     /// - [`Tuple`](DynSolType::Tuple): the inner types delimited by commas (`,`) and surrounded by
     ///   parentheses (`()`)
+    ///
+    /// Arrays and tuples may contain any whitespace, including newlines, between their elements.
+    ///
+    /// This is the inverse of [`DynSolValue::format_sol`].
     #[cfg_attr(
         feature = "eip712",
         doc = "- [`CustomStruct`](DynSolType::CustomStruct): the same as `Tuple`"
@@ -108,7 +111,7 @@ impl<'i> Parser<&'i str, DynSolValue, ContextError> for ValueParser<'_> {
             DynSolType::Function => function(input).map(DynSolValue::Function),
             DynSolType::Bytes => bytes(input).map(DynSolValue::Bytes),
             DynSolType::String => {
-                self.string().parse_next(input).map(|s| DynSolValue::String(s.into()))
+                self.string().parse_next(input).map(|s| DynSolValue::String(s.into_owned()))
             }
             DynSolType::Array(ty) => self.in_list(']', |this| {
                 this.with(ty).array().parse_next(input).map(DynSolValue::Array)
@@ -144,41 +147,24 @@ impl<'a> ValueParser<'a> {
     }
 
     #[inline]
-    fn string<'s, 'i: 's>(&'s self) -> impl Parser<&'i str, &'i str, ContextError> + 's {
+    fn string<'s, 'i: 's>(&'s self) -> impl Parser<&'i str, Cow<'i, str>, ContextError> + 's {
         trace("string", |input: &mut &'i str| {
             let Some(delim) = input.chars().next() else {
-                return Ok("");
+                return Ok(Cow::Borrowed(""));
             };
-            let has_delim = matches!(delim, '"' | '\'');
-            if has_delim {
+            if matches!(delim, '"' | '\'') {
                 *input = &input[1..];
+                return cut_err(quoted(delim))
+                    .context(StrContext::Label("string"))
+                    .parse_next(input);
             }
 
-            // TODO: escapes?
-            let mut s = if has_delim || self.list_end.is_some() {
-                let (chs, l) = if has_delim {
-                    ([delim, '\0'], 1)
-                } else if let Some(c) = self.list_end {
-                    ([',', c], 2)
-                } else {
-                    unreachable!()
-                };
-                let min = if has_delim { 0 } else { 1 };
-                take_while(min.., move |c: char| !unsafe { chs.get_unchecked(..l) }.contains(&c))
-                    .parse_next(input)?
+            let s = if let Some(list_end) = self.list_end {
+                take_while(1.., move |c: char| c != ',' && c != list_end).parse_next(input)?
             } else {
                 input.next_slice(input.len())
             };
-
-            if has_delim {
-                cut_err(char_parser(delim))
-                    .context(StrContext::Label("string"))
-                    .parse_next(input)?;
-            } else {
-                s = s.trim_end();
-            }
-
-            Ok(s)
+            Ok(Cow::Borrowed(s.trim_end()))
         })
     }
 
@@ -188,7 +174,7 @@ impl<'a> ValueParser<'a> {
         let name = format!("{}[]", self.ty);
         #[cfg(not(feature = "debug"))]
         let name = "array";
-        trace(name, array_parser(self))
+        trace(name, list(self))
     }
 
     #[inline]
@@ -202,7 +188,7 @@ impl<'a> ValueParser<'a> {
         let name = "fixed_array";
         trace(
             name,
-            array_parser(self).try_map(move |values: Vec<DynSolValue>| {
+            list(self).try_map(move |values: Vec<DynSolValue>| {
                 if values.len() == len {
                     Ok(values)
                 } else {
@@ -223,20 +209,20 @@ impl<'a> ValueParser<'a> {
         #[cfg(not(feature = "debug"))]
         let name = "tuple";
         trace(name, move |input: &mut &'i str| {
-            space0(input)?;
+            multispace0(input)?;
             char_parser('(').parse_next(input)?;
 
             let mut values = Vec::with_capacity(tuple.len());
             for (i, ty) in tuple.iter().enumerate() {
                 if i > 0 {
-                    space0(input)?;
+                    multispace0(input)?;
                     char_parser(',').parse_next(input)?;
                 }
-                space0(input)?;
+                multispace0(input)?;
                 values.push(self.with(ty).parse_next(input)?);
             }
 
-            space0(input)?;
+            multispace0(input)?;
             char_parser(')').parse_next(input)?;
 
             Ok(values)
@@ -247,14 +233,11 @@ impl<'a> ValueParser<'a> {
 #[derive(Debug)]
 enum Error {
     IntOverflow,
-    #[cfg(not(feature = "std"))]
-    FloatNoStd(f64),
-    #[cfg(feature = "std")]
-    FractionalNotAllowed(f64),
-    #[cfg(feature = "std")]
+    FractionalNotAllowed(String),
     TooManyDecimals(usize, usize),
     InvalidFixedBytesLength(usize),
     FixedArrayLengthMismatch(usize, usize),
+    InvalidEscape,
 }
 
 #[cfg(feature = "std")]
@@ -264,18 +247,12 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::IntOverflow => f.write_str("number too large to fit in target type"),
-            #[cfg(not(feature = "std"))]
-            Self::FloatNoStd(n) => {
-                write!(f, "fractional numbers are not supported without `std`: {n}")
-            }
-            #[cfg(feature = "std")]
             Self::TooManyDecimals(expected, actual) => {
                 write!(f, "expected at most {expected} decimals, got {actual}")
             }
-            #[cfg(feature = "std")]
-            Self::FractionalNotAllowed(n) => write!(
+            Self::FractionalNotAllowed(fract) => write!(
                 f,
-                "non-zero fraction {n} not allowed without specifying non-wei units (gwei, ether, etc.)"
+                "non-zero fraction 0.{fract} not allowed without specifying non-wei units (gwei, ether, etc.)"
             ),
             Self::InvalidFixedBytesLength(len) => {
                 write!(f, "fixed bytes length {len} greater than 32")
@@ -284,10 +261,104 @@ impl fmt::Display for Error {
                 f,
                 "fixed array length mismatch: expected {expected} elements, got {actual}"
             ),
+            Self::InvalidEscape => f.write_str("invalid escape sequence"),
         }
     }
 }
 
+/// Parses a comma-separated list of values surrounded by brackets (`[]`).
+///
+/// Unlike [`array_parser`](parser::utils::array_parser), any whitespace,
+/// including newlines, is allowed between the elements.
+#[inline]
+fn list<'i, O1, O2>(
+    f: impl Parser<&'i str, O1, ContextError>,
+) -> impl Parser<&'i str, O2, ContextError>
+where
+    O2: Accumulate<O1>,
+{
+    trace(
+        "list",
+        delimited(
+            (char_parser('['), multispace0),
+            cut_err(separated(0.., f, (char_parser(','), multispace0))),
+            (opt(','), multispace0, cut_err(char_parser(']'))),
+        ),
+    )
+}
+
+/// Parses the rest of a string literal delimited by `delim`, after the
+/// opening delimiter, unescaping Solidity escape sequences: `\\`, `\"`,
+/// `\'`, `\n`, `\r`, `\t`, `\xNN` for ASCII characters, `\uNNNN` and
+/// escaped newlines. Unknown escape sequences are kept as-is.
+fn quoted<'i>(delim: char) -> impl Parser<&'i str, Cow<'i, str>, ContextError> {
+    trace("quoted", move |input: &mut &'i str| {
+        let mut out: Option<String> = None;
+        let mut start = 0;
+        let mut chars = input.char_indices();
+        while let Some((i, c)) = chars.next() {
+            if c == delim {
+                let s = match out {
+                    Some(mut out) => {
+                        out.push_str(&input[start..i]);
+                        Cow::Owned(out)
+                    }
+                    None => Cow::Borrowed(&input[..i]),
+                };
+                *input = &input[i + 1..];
+                return Ok(s);
+            }
+            if c != '\\' {
+                continue;
+            }
+
+            let out = out.get_or_insert_with(String::new);
+            out.push_str(&input[start..i]);
+            let unescaped = match chars.next().map(|(_, c)| c) {
+                Some(c @ ('\\' | '"' | '\'')) => Some(c),
+                Some('n') => Some('\n'),
+                Some('r') => Some('\r'),
+                Some('t') => Some('\t'),
+                Some('\n') => None,
+                Some('x') => {
+                    match hex_escape(&mut chars, 2).filter(|&b| b < 0x80).and_then(char::from_u32) {
+                        Some(c) => Some(c),
+                        None => return Err(escape_error(input, i)),
+                    }
+                }
+                Some('u') => match hex_escape(&mut chars, 4).and_then(char::from_u32) {
+                    Some(c) => Some(c),
+                    None => return Err(escape_error(input, i)),
+                },
+                // unknown escape sequences are kept as-is
+                Some(_) => {
+                    start = i;
+                    continue;
+                }
+                None => break,
+            };
+            out.extend(unescaped);
+            start = chars.clone().next().map_or(input.len(), |(i, _)| i);
+        }
+
+        *input = &input[input.len()..];
+        Err(ErrMode::from_error_kind(input, ErrorKind::Eof))
+    })
+}
+
+fn hex_escape(chars: &mut core::str::CharIndices<'_>, n: usize) -> Option<u32> {
+    let mut value = 0;
+    for _ in 0..n {
+        value = value * 16 + chars.next()?.1.to_digit(16)?;
+    }
+    Some(value)
+}
+
+fn escape_error(input: &mut &str, at: usize) -> ErrMode<ContextError> {
+    *input = &input[at..];
+    ErrMode::from_external_error(input, ErrorKind::Verify, Error::InvalidEscape)
+}
+
 #[inline]
 fn bool(input: &mut &str) -> PResult<bool> {
     trace(
@@ -356,50 +427,42 @@ fn uint<'i>(len: usize) -> impl Parser<&'i str, U256, ContextError> {
         let _ = space0(input)?;
         let units = int_units(input)?;
 
+        let overflow =
+            || ErrMode::from_external_error(input, ErrorKind::Verify, Error::IntOverflow);
         let uint = if let Some(fract) = fract {
-            let x = s
-                .parse::<f64>()
-                .map_err(|e| ErrMode::from_external_error(input, ErrorKind::Verify, e))?;
-
-            // TODO: add num_traits with libm feature to support this
-            #[cfg(not(feature = "std"))]
-            {
-                let _ = fract;
+            if units == 0 && fract.bytes().any(|b| b != b'0') {
                 return Err(ErrMode::from_external_error(
                     input,
                     ErrorKind::Verify,
-                    Error::FloatNoStd(x),
+                    Error::FractionalNotAllowed(fract.into()),
                 ));
             }
 
-            #[cfg(feature = "std")]
-            {
-                if units == 0 && x.fract() != 0.0 {
-                    return Err(ErrMode::from_external_error(
-                        input,
-                        ErrorKind::Verify,
-                        Error::FractionalNotAllowed(x.fract()),
-                    ));
-                }
-
-                if fract.len() > units {
-                    return Err(ErrMode::from_external_error(
-                        input,
-                        ErrorKind::Verify,
-                        Error::TooManyDecimals(units, fract.len()),
-                    ));
-                }
-
-                U256::try_from(x * 10f64.powi(units as i32))
-                    .map_err(|e| ErrMode::from_external_error(input, ErrorKind::Verify, e))
+            if fract.len() > units {
+                return Err(ErrMode::from_external_error(
+                    input,
+                    ErrorKind::Verify,
+                    Error::TooManyDecimals(units, fract.len()),
+                ));
             }
+
+            // exact decimal arithmetic: `int * 10^units + fract * 10^(units - fract.len())`
+            let int = &s[..s.len() - fract.len() - 1];
+            let int = int
+                .parse::<U256>()
+                .map_err(|e| ErrMode::from_external_error(input, ErrorKind::Verify, e))?;
+            let fract_value = fract
+                .parse::<U256>()
+                .map_err(|e| ErrMode::from_external_error(input, ErrorKind::Verify, e))?;
+            let pow = |exp: usize| U256::from(10).pow(U256::from(exp));
+            int.checked_mul(pow(units))
+                .and_then(|int| int.checked_add(fract_value * pow(units - fract.len())))
+                .ok_or_else(overflow)
         } else {
             s.parse::<U256>()
                 .map_err(|e| ErrMode::from_external_error(input, ErrorKind::Verify, e))?
                 .checked_mul(U256::from(10usize.pow(units as u32)))
-                .ok_or_else(|| {
-                    ErrMode::from_external_error(input, ErrorKind::Verify, Error::IntOverflow)
-                })
+                .ok_or_else(overflow)
         }?;
 
         if uint.bit_len() > len {
@@ -767,6 +830,12 @@ mod tests {
                 DynSolType::Uint(256).coerce_str("1wei"),
             );
         }
+
+        // decimals are exact
+        assert_eq!(
+            DynSolType::Uint(256).coerce_str("123456789.123456789123456789 ether").unwrap(),
+            DynSolValue::Uint(U256::from_str("123456789123456789123456789").unwrap(), 256)
+        );
     }
 
     #[test]
@@ -978,6 +1047,31 @@ mod tests {
         assert_eq!(DynSolType::String.coerce_str(s).unwrap(), DynSolValue::String(s.into()));
     }
 
+    #[test]
+    fn coerce_string_escapes() {
+        let coerce = |s| DynSolType::String.coerce_str(s).unwrap();
+        assert_eq!(coerce(r#""a\"b""#), DynSolValue::String("a\"b".into()));
+        assert_eq!(coerce(r"'it\'s'"), DynSolValue::String("it's".into()));
+        assert_eq!(coerce(r#""\\\n\r\t""#), DynSolValue::String("\\\n\r\t".into()));
+        assert_eq!(coerce(r#""\x41\u00e9""#), DynSolValue::String("A\u{e9}".into()));
+        assert_eq!(coerce(r#""C:\foo""#), DynSolValue::String(r"C:\foo".into()));
+        // unquoted strings are not unescaped
+        assert_eq!(coerce(r"a\nb"), DynSolValue::String(r"a\nb".into()));
+
+        assert!(DynSolType::String.coerce_str(r#""\xff""#).is_err());
+        assert!(DynSolType::String.coerce_str(r#""\u12""#).is_err());
+        assert!(DynSolType::String.coerce_str(r#""a\""#).is_err());
+
+        let arr = DynSolType::Array(Box::new(DynSolType::String));
+        assert_eq!(
+            arr.coerce_str("[\n    \"a\\\"\",\n    'b'\n]").unwrap(),
+            DynSolValue::Array(vec![
+                DynSolValue::String("a\"".into()),
+                DynSolValue::String("b".into())
+            ])
+        );
+    }
+
     #[test]
     fn coerce_strings() {
         let arr = DynSolType::Array(Box::new(DynSolType::String));
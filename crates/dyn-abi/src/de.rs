@@ -0,0 +1,413 @@
+//! Deserialization of Rust types from [`DynSolValue`]s.
+
+use crate::{ty::as_tuple, DynSolType, DynSolValue, Error, Result};
+use alloc::{string::ToString, vec::Vec};
+use core::iter::zip;
+use serde::de::{
+    self, value::SeqDeserializer, DeserializeOwned, Deserializer as _, IntoDeserializer, Visitor,
+};
+
+/// Deserializes an instance of `T` from a [`DynSolValue`] of the given type.
+///
+/// See [`Deserializer`] for the supported conversions.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{from_value, DynSolType};
+/// use alloy_primitives::{hex, Address, U256};
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Transfer {
+///     to: Address,
+///     amount: U256,
+/// }
+///
+/// let ty: DynSolType = "(address,uint256)".parse()?;
+/// let data = hex!(
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000064"
+/// );
+/// let transfer: Transfer = from_value(&ty, ty.abi_decode_params(&data)?)?;
+/// assert_eq!(transfer, Transfer { to: Address::with_last_byte(1), amount: U256::from(100) });
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+pub fn from_value<T: DeserializeOwned>(ty: &DynSolType, value: DynSolValue) -> Result<T> {
+    T::deserialize(Deserializer::new(ty, value)?)
+}
+
+/// A [`serde::Deserializer`] over a [`DynSolValue`] and its type.
+///
+/// The value is presented to the deserialized type as follows:
+/// - booleans and strings as themselves
+/// - integers as primitive integers if they fit in 64 or 128 bits, or as decimal strings otherwise.
+///   This means that they can be deserialized into any primitive integer type that fits the value,
+///   or into [`U256`] and [`I256`]
+/// - addresses as checksummed hex strings, and other bytes as `0x`-prefixed hex strings, or as byte
+///   sequences when deserializing sequences or bytes
+/// - arrays and tuples as sequences, which can be deserialized into vectors, arrays, tuples and
+///   structs
+#[cfg_attr(
+    feature = "eip712",
+    doc = "- [`CustomStruct`](DynSolValue::CustomStruct)s as maps keyed by their property \
+           names, or as sequences"
+)]
+/// - unsigned integers and strings as unit enum variants, by index or name respectively, which maps
+///   Solidity enums to Rust enums
+///
+/// [`U256`]: alloy_primitives::U256
+/// [`I256`]: alloy_primitives::I256
+#[derive(Clone, Debug)]
+pub struct Deserializer<'a> {
+    ty: &'a DynSolType,
+    value: DynSolValue,
+}
+
+impl<'a> Deserializer<'a> {
+    /// Creates a new deserializer for the given value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value does not match the type.
+    pub fn new(ty: &'a DynSolType, value: DynSolValue) -> Result<Self> {
+        if !ty.matches(&value) {
+            return Err(mismatch(ty, &value));
+        }
+        Ok(Self { ty, value })
+    }
+
+    /// Visits the bytes of a bytes-like value, or the elements of an array or
+    /// tuple, as a sequence.
+    fn visit_seq<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if let Some(bytes) = self.bytes() {
+            let mut seq = SeqDeserializer::<_, Error>::new(bytes.into_iter());
+            let value = visitor.visit_seq(&mut seq)?;
+            seq.end()?;
+            return Ok(value);
+        }
+
+        let elements: Vec<_> = match (self.ty, self.value) {
+            (
+                DynSolType::Array(ty) | DynSolType::FixedArray(ty, _),
+                DynSolValue::Array(values) | DynSolValue::FixedArray(values),
+            ) => values.into_iter().map(|value| Self { ty, value }).collect(),
+            (as_tuple!(DynSolType types), as_tuple!(DynSolValue values)) => {
+                zip(types, values).map(|(ty, value)| Self { ty, value }).collect()
+            }
+            (ty, value) => return Self { ty, value }.deserialize_any(visitor),
+        };
+        let mut seq = SeqDeserializer::new(elements.into_iter());
+        let value = visitor.visit_seq(&mut seq)?;
+        seq.end()?;
+        Ok(value)
+    }
+
+    /// Returns the bytes of a bytes-like value.
+    fn bytes(&self) -> Option<Vec<u8>> {
+        match &self.value {
+            DynSolValue::FixedBytes(word, size) => Some(word[..*size].to_vec()),
+            DynSolValue::Address(address) => Some(address.to_vec()),
+            DynSolValue::Function(function) => Some(function.to_vec()),
+            DynSolValue::Bytes(bytes) => Some(bytes.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            DynSolValue::Bool(b) => visitor.visit_bool(b),
+            DynSolValue::Int(int, _) => {
+                if let Ok(int) = i64::try_from(int) {
+                    visitor.visit_i64(int)
+                } else if let Ok(int) = i128::try_from(int) {
+                    visitor.visit_i128(int)
+                } else {
+                    visitor.visit_string(int.to_string())
+                }
+            }
+            DynSolValue::Uint(uint, _) => {
+                if let Ok(uint) = u64::try_from(uint) {
+                    visitor.visit_u64(uint)
+                } else if let Ok(uint) = u128::try_from(uint) {
+                    visitor.visit_u128(uint)
+                } else {
+                    visitor.visit_string(uint.to_string())
+                }
+            }
+            DynSolValue::Address(address) => visitor.visit_string(address.to_checksum(None)),
+            DynSolValue::FixedBytes(word, size) => {
+                visitor.visit_string(hex::encode_prefixed(&word[..size]))
+            }
+            DynSolValue::Function(function) => visitor.visit_string(hex::encode_prefixed(function)),
+            DynSolValue::Bytes(bytes) => visitor.visit_string(hex::encode_prefixed(bytes)),
+            DynSolValue::String(s) => visitor.visit_string(s),
+            #[cfg(feature = "eip712")]
+            value @ DynSolValue::CustomStruct { .. } => {
+                Self { ty: self.ty, value }.deserialize_map(visitor)
+            }
+            value
+            @ (DynSolValue::Array(_) | DynSolValue::FixedArray(_) | DynSolValue::Tuple(_)) => {
+                Self { ty: self.ty, value }.visit_seq(visitor)
+            }
+        }
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match (self.ty, self.value) {
+            // the type may also be a plain tuple, so use the value's names
+            #[cfg(feature = "eip712")]
+            (
+                as_tuple!(DynSolType types),
+                DynSolValue::CustomStruct { prop_names, tuple: values, .. },
+            ) => {
+                let entries = zip(prop_names, zip(types, values))
+                    .map(|(name, (ty, value))| (name, Self { ty, value }));
+                let mut map = de::value::MapDeserializer::new(entries);
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            #[cfg(feature = "eip712")]
+            (ty, value @ DynSolValue::CustomStruct { .. }) => Err(mismatch(ty, &value)),
+            (ty, value) => Self { ty, value }.visit_seq(visitor),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.visit_seq(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.visit_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.visit_seq(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.bytes() {
+            Some(bytes) => visitor.visit_byte_buf(bytes),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match &self.value {
+            DynSolValue::Tuple(values) if values.is_empty() => visitor.visit_unit(),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.value {
+            DynSolValue::Uint(index, _) => {
+                let index = u32::try_from(index)
+                    .map_err(|_| Error::custom("enum variant index out of range"))?;
+                visitor.visit_enum(index.into_deserializer())
+            }
+            DynSolValue::String(name) => visitor.visit_enum(name.into_deserializer()),
+            _ => Err(Error::TypeMismatch {
+                expected: "uint or string".into(),
+                actual: self.ty.sol_type_name().into_owned(),
+            }),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        identifier ignored_any
+    }
+}
+
+impl<'de, 'a> IntoDeserializer<'de, Error> for Deserializer<'a> {
+    type Deserializer = Self;
+
+    #[inline]
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+fn mismatch(ty: &DynSolType, value: &DynSolValue) -> Error {
+    Error::TypeMismatch {
+        expected: ty.sol_type_name().into_owned(),
+        actual: value.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Self::custom(msg.to_string())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl de::StdError for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::String, vec};
+    use alloy_primitives::{Address, I256, U256};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Order {
+        maker: Address,
+        side: Side,
+        amounts: Vec<u64>,
+        price: U256,
+        delta: I256,
+        data: Vec<u8>,
+        salt: [u8; 4],
+    }
+
+    #[test]
+    fn deserialize_struct() {
+        let ty: DynSolType =
+            "(address,uint8,uint256[],uint256,int256,bytes,bytes4)".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            Address::with_last_byte(1).into(),
+            DynSolValue::Uint(U256::from(1), 8),
+            DynSolValue::Array(vec![U256::from(2).into(), U256::from(3).into()]),
+            U256::MAX.into(),
+            I256::MIN.into(),
+            vec![1, 2, 3].into(),
+            DynSolValue::FixedBytes(alloy_primitives::B256::repeat_byte(0xff), 4),
+        ]);
+        let order: Order = from_value(&ty, value).unwrap();
+        assert_eq!(
+            order,
+            Order {
+                maker: Address::with_last_byte(1),
+                side: Side::Sell,
+                amounts: vec![2, 3],
+                price: U256::MAX,
+                delta: I256::MIN,
+                data: vec![1, 2, 3],
+                salt: [0xff; 4],
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_primitives() {
+        let uint = |x: u64| DynSolValue::Uint(U256::from(x), 256);
+        assert_eq!(from_value::<u8>(&DynSolType::Uint(256), uint(255)), Ok(255));
+        from_value::<u8>(&DynSolType::Uint(256), uint(256)).unwrap_err();
+        assert_eq!(
+            from_value::<(bool, String)>(
+                &"(bool,string)".parse().unwrap(),
+                DynSolValue::Tuple(vec![true.into(), DynSolValue::String("a".into())]),
+            ),
+            Ok((true, "a".into()))
+        );
+        assert_eq!(from_value::<String>(&DynSolType::Bytes, vec![0xab].into()), Ok("0xab".into()));
+        assert_eq!(from_value::<Option<u64>>(&DynSolType::Uint(256), uint(1)), Ok(Some(1)));
+
+        // type mismatch and trailing elements
+        from_value::<bool>(&DynSolType::Uint(256), true.into()).unwrap_err();
+        from_value::<(u64,)>(
+            &"(uint256,uint256)".parse().unwrap(),
+            DynSolValue::Tuple(vec![uint(1), uint(2)]),
+        )
+        .unwrap_err();
+    }
+
+    #[cfg(feature = "eip712")]
+    #[test]
+    fn deserialize_custom_struct() {
+        use alloc::borrow::ToOwned;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Person {
+            wallet: Address,
+            name: String,
+        }
+
+        let ty = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".into(), "wallet".into()],
+            tuple: vec![DynSolType::String, DynSolType::Address],
+        };
+        let value = DynSolValue::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".into(), "wallet".into()],
+            tuple: vec![DynSolValue::String("Bob".into()), Address::with_last_byte(2).into()],
+        };
+        assert_eq!(
+            from_value::<Person>(&ty, value.clone()),
+            Ok(Person { wallet: Address::with_last_byte(2), name: "Bob".into() })
+        );
+        assert_eq!(
+            from_value::<(String, Address)>(&ty, value.clone()),
+            Ok(("Bob".into(), Address::with_last_byte(2)))
+        );
+
+        // plain tuple types with struct values
+        let ty = DynSolType::Tuple(vec![DynSolType::String, DynSolType::Address]);
+        assert_eq!(
+            from_value::<Person>(&ty, value.clone()),
+            Ok(Person { wallet: Address::with_last_byte(2), name: "Bob".into() })
+        );
+        assert_eq!(
+            from_value::<(String, Address)>(&ty, value),
+            Ok(("Bob".into(), Address::with_last_byte(2)))
+        );
+    }
+}
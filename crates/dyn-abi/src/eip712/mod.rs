@@ -14,5 +14,3 @@ pub use typed_data::{Eip712Types, TypedData};
 
 mod resolver;
 pub use resolver::{PropertyDef, Resolver, TypeDef};
-
-pub(crate) mod coerce;
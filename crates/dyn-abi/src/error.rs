@@ -1,5 +1,5 @@
-use alloc::{borrow::Cow, string::String};
-use alloy_primitives::B256;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloy_primitives::{Selector, B256};
 use alloy_sol_types::Error as SolTypesError;
 use core::fmt;
 use hex::FromHexError;
@@ -53,6 +53,17 @@ pub enum Error {
         actual: B256,
     },
 
+    /// No function in the ABI has the given selector.
+    UnknownSelector(Selector),
+    /// Multiple functions in the ABI share the given selector, and the data
+    /// could not be used to tell them apart.
+    AmbiguousSelector {
+        /// The selector.
+        selector: Selector,
+        /// The signatures of the functions with the selector.
+        candidates: Vec<String>,
+    },
+
     /// [`hex`] error.
     Hex(hex::FromHexError),
     /// [`alloy_sol_type_parser`] error.
@@ -117,6 +128,13 @@ impl fmt::Display for Error {
                 write!(f, "invalid event signature: expected {expected}, got {actual}",)
             }
 
+            Self::UnknownSelector(selector) => write!(f, "unknown function selector {selector}"),
+            Self::AmbiguousSelector { selector, candidates } => write!(
+                f,
+                "ambiguous function selector {selector}: matches {}",
+                candidates.join(", ")
+            ),
+
             Self::Hex(e) => e.fmt(f),
             Self::TypeParser(e) => e.fmt(f),
             Self::SolTypes(e) => e.fmt(f),
@@ -130,8 +148,8 @@ impl Error {
         Self::SolTypes(SolTypesError::custom(s))
     }
 
-    #[cfg(feature = "eip712")]
-    pub(crate) fn eip712_coerce(expected: &crate::DynSolType, actual: &serde_json::Value) -> Self {
+    #[cfg(feature = "serde_json")]
+    pub(crate) fn json_coerce(expected: &crate::DynSolType, actual: &serde_json::Value) -> Self {
         #[allow(unused_imports)]
         use alloc::string::ToString;
         Self::TypeMismatch { expected: expected.to_string(), actual: actual.to_string() }
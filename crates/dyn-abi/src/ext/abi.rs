@@ -1,4 +1,4 @@
-use crate::{DynSolValue, Error as CrateError, ResolveSolType, Result};
+use crate::{DynSolType, DynSolValue, Error as CrateError, NamedValue, ResolveSolType, Result};
 use alloc::vec::Vec;
 use alloy_json_abi::{Constructor, Error, Function, Param};
 use alloy_primitives::Selector;
@@ -56,6 +56,50 @@ pub trait JsonAbiExt: Sealed {
     /// This function will return an error if the decoded data does not match
     /// the expected input types.
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>>;
+
+    /// ABI-decodes the input value at `path`, without decoding any other
+    /// values. The data must not be prefixed by the selector.
+    ///
+    /// The path segments can be either indices or parameter and component
+    /// names, like `orders[17].maker`. See [`DynSolType::extract`] for more
+    /// details.
+    ///
+    /// [`DynSolType::extract`]: crate::DynSolType::extract
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the path does not match the
+    /// input types, or if the data along the path is invalid.
+    fn abi_extract_input(&self, data: &[u8], path: &str, validate: bool) -> Result<DynSolValue>;
+
+    /// ABI-decodes the given data according to this item's input types, and
+    /// annotates the values with the names of the inputs and their
+    /// components.
+    ///
+    /// The returned value is a tuple named after this item, or unnamed for
+    /// [`Constructor`], with a component for each input.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the decoded data does not match
+    /// the expected input types.
+    fn abi_decode_input_named(&self, data: &[u8], validate: bool) -> Result<NamedValue>;
+
+    /// Coerces the given JSON value to this item's input types.
+    ///
+    /// The value can be either an array of the arguments or an object keyed
+    /// by the input names. Tuple arguments can likewise be given as objects
+    /// keyed by their component names. See [`DynSolType::coerce_json`] for
+    /// the format of the individual values.
+    ///
+    /// [`DynSolType::coerce_json`]: crate::DynSolType::coerce_json
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given value does not match
+    /// the expected input types.
+    #[cfg(feature = "serde_json")]
+    fn coerce_json_input(&self, value: &serde_json::Value) -> Result<Vec<DynSolValue>>;
 }
 
 /// Provide ABI encoding and decoding for the [`Function`] type.
@@ -79,6 +123,20 @@ pub trait FunctionExt: JsonAbiExt + Sealed {
     ///
     /// This method does not check for any prefixes or selectors.
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>>;
+
+    /// ABI-decodes the output value at `path`, without decoding any other
+    /// values.
+    ///
+    /// See [`abi_extract_input`](JsonAbiExt::abi_extract_input) for the
+    /// format of the path.
+    fn abi_extract_output(&self, data: &[u8], path: &str, validate: bool) -> Result<DynSolValue>;
+
+    /// ABI-decodes the given data according to this function's output types,
+    /// and annotates the values with the names of the outputs and their
+    /// components.
+    ///
+    /// See [`abi_decode_input_named`](JsonAbiExt::abi_decode_input_named).
+    fn abi_decode_output_named(&self, data: &[u8], validate: bool) -> Result<NamedValue>;
 }
 
 impl JsonAbiExt for Constructor {
@@ -96,6 +154,23 @@ impl JsonAbiExt for Constructor {
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
         abi_decode(data, &self.inputs, validate)
     }
+
+    #[inline]
+    fn abi_extract_input(&self, data: &[u8], path: &str, validate: bool) -> Result<DynSolValue> {
+        abi_extract(data, &self.inputs, path, validate)
+    }
+
+    #[inline]
+    fn abi_decode_input_named(&self, data: &[u8], validate: bool) -> Result<NamedValue> {
+        let values = abi_decode(data, &self.inputs, validate)?;
+        NamedValue::from_params("", &self.inputs, values)
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[inline]
+    fn coerce_json_input(&self, value: &serde_json::Value) -> Result<Vec<DynSolValue>> {
+        crate::json::coerce_json_params(&self.inputs, value)
+    }
 }
 
 impl JsonAbiExt for Error {
@@ -113,6 +188,23 @@ impl JsonAbiExt for Error {
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
         abi_decode(data, &self.inputs, validate)
     }
+
+    #[inline]
+    fn abi_extract_input(&self, data: &[u8], path: &str, validate: bool) -> Result<DynSolValue> {
+        abi_extract(data, &self.inputs, path, validate)
+    }
+
+    #[inline]
+    fn abi_decode_input_named(&self, data: &[u8], validate: bool) -> Result<NamedValue> {
+        let values = abi_decode(data, &self.inputs, validate)?;
+        NamedValue::from_params(&self.name, &self.inputs, values)
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[inline]
+    fn coerce_json_input(&self, value: &serde_json::Value) -> Result<Vec<DynSolValue>> {
+        crate::json::coerce_json_params(&self.inputs, value)
+    }
 }
 
 impl JsonAbiExt for Function {
@@ -130,6 +222,23 @@ impl JsonAbiExt for Function {
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
         abi_decode(data, &self.inputs, validate)
     }
+
+    #[inline]
+    fn abi_extract_input(&self, data: &[u8], path: &str, validate: bool) -> Result<DynSolValue> {
+        abi_extract(data, &self.inputs, path, validate)
+    }
+
+    #[inline]
+    fn abi_decode_input_named(&self, data: &[u8], validate: bool) -> Result<NamedValue> {
+        let values = abi_decode(data, &self.inputs, validate)?;
+        NamedValue::from_params(&self.name, &self.inputs, values)
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[inline]
+    fn coerce_json_input(&self, value: &serde_json::Value) -> Result<Vec<DynSolValue>> {
+        crate::json::coerce_json_params(&self.inputs, value)
+    }
 }
 
 impl FunctionExt for Function {
@@ -142,6 +251,17 @@ impl FunctionExt for Function {
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
         abi_decode(data, &self.outputs, validate)
     }
+
+    #[inline]
+    fn abi_extract_output(&self, data: &[u8], path: &str, validate: bool) -> Result<DynSolValue> {
+        abi_extract(data, &self.outputs, path, validate)
+    }
+
+    #[inline]
+    fn abi_decode_output_named(&self, data: &[u8], validate: bool) -> Result<NamedValue> {
+        let values = abi_decode(data, &self.outputs, validate)?;
+        NamedValue::from_params(&self.name, &self.outputs, values)
+    }
 }
 
 #[inline]
@@ -190,6 +310,11 @@ fn abi_decode(data: &[u8], params: &[Param], validate: bool) -> Result<Vec<DynSo
     Ok(values)
 }
 
+fn abi_extract(data: &[u8], params: &[Param], path: &str, validate: bool) -> Result<DynSolValue> {
+    let ty = DynSolType::Tuple(params.iter().map(Param::resolve).collect::<Result<_>>()?);
+    crate::extract::extract_params(&ty, Some(params), &mut Decoder::new(data, validate), path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
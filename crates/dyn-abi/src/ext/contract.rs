@@ -0,0 +1,194 @@
+use crate::{DynSolValue, Error, Result};
+use alloc::{string::String, vec::Vec};
+use alloy_json_abi::ContractObject;
+use alloy_primitives::bytecode::{split_metadata, CompilerMetadata};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for alloy_json_abi::ContractObject {}
+}
+use sealed::Sealed;
+
+/// The maximum length of the compiler metadata searched for when the creation
+/// input's metadata differs from the contract's.
+const MAX_METADATA_LEN: usize = 256;
+
+/// Provides constructor argument extraction for the [`ContractObject`] type.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`ContractObject`].
+pub trait ContractObjectExt: Sealed {
+    /// Splits contract creation input into the init code and the ABI-encoded
+    /// constructor arguments which follow it.
+    ///
+    /// The init code must either start with the contract's creation bytecode,
+    /// or differ from it only in the [compiler metadata] appended to it, which
+    /// may also have a different length.
+    ///
+    /// [compiler metadata]: CompilerMetadata
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the creation bytecode is missing
+    /// or not linked, or if it does not match the input.
+    fn split_creation_input<'a>(&self, input: &'a [u8]) -> Result<(&'a [u8], &'a [u8])>;
+
+    /// Splits contract creation input into the init code and constructor
+    /// arguments, and decodes the arguments according to the ABI's
+    /// constructor.
+    ///
+    /// See [`split_creation_input`](ContractObjectExt::split_creation_input)
+    /// for how the init code is matched.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input does not match the
+    /// creation bytecode, or if the arguments do not match the constructor's
+    /// input types.
+    fn decode_constructor_args(&self, input: &[u8], validate: bool) -> Result<DecodedConstructor>;
+}
+
+impl ContractObjectExt for ContractObject {
+    fn split_creation_input<'a>(&self, input: &'a [u8]) -> Result<(&'a [u8], &'a [u8])> {
+        let code = self
+            .bytecode
+            .as_ref()
+            .ok_or_else(|| Error::custom("missing creation bytecode"))?
+            .as_bytes()
+            .ok_or_else(|| Error::custom("creation bytecode is not linked"))?;
+
+        if input.starts_with(&code[..]) {
+            return Ok(input.split_at(code.len()));
+        }
+
+        let mismatch = || Error::custom("creation input does not match the creation bytecode");
+        let (code, _) = split_metadata(code).ok_or_else(mismatch)?;
+        let rest = input.strip_prefix(code).ok_or_else(mismatch)?;
+        // The metadata is followed by its 2-byte big-endian length, then by the
+        // arguments, which are a multiple of 32 bytes long
+        (2..=rest.len().min(MAX_METADATA_LEN + 2))
+            .find(|&end| {
+                let len = u16::from_be_bytes([rest[end - 2], rest[end - 1]]) as usize;
+                len + 2 == end
+                    && (rest.len() - end) % 32 == 0
+                    && CompilerMetadata::decode(&rest[..len]).is_some()
+            })
+            .map(|end| input.split_at(code.len() + end))
+            .ok_or_else(mismatch)
+    }
+
+    fn decode_constructor_args(&self, input: &[u8], validate: bool) -> Result<DecodedConstructor> {
+        let (init_code, args) = self.split_creation_input(input)?;
+        let exact_match = self
+            .bytecode
+            .as_ref()
+            .and_then(|code| code.as_bytes())
+            .map_or(false, |code| code[..] == *init_code);
+
+        let constructor = self.abi.as_ref().and_then(|abi| abi.constructor());
+        let args = super::decoder::decode_constructor(constructor, args, validate)?;
+
+        Ok(DecodedConstructor { init_code_len: init_code.len(), exact_match, args })
+    }
+}
+
+/// Constructor arguments decoded from contract creation input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedConstructor {
+    /// The length of the init code at the start of the creation input. The
+    /// ABI-encoded arguments follow it.
+    pub init_code_len: usize,
+    /// Whether the init code matched the creation bytecode exactly, including
+    /// its compiler metadata.
+    pub exact_match: bool,
+    /// The decoded arguments, paired with their parameter names.
+    pub args: Vec<(String, DynSolValue)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_abi::{Bytecode, JsonAbi};
+    use alloy_primitives::{hex, Address, Bytes, U256};
+
+    // Creation bytecode with `solc 0.8.20` IPFS metadata
+    const INIT_CODE: [u8; 62] = hex!(
+        "6080604052600080fd"
+        "a2646970667358221220"
+        "2a4f8d12a9e5a1b3e23ad5cb1fa4f3c9f1c52ecf2f6c7d5e6a1c5d19c6b3f2e1"
+        "64736f6c6343000814"
+        "0033"
+    );
+
+    fn contract(constructor: Option<&str>) -> ContractObject {
+        let abi = constructor.map(|c| JsonAbi::parse([c]).unwrap());
+        ContractObject {
+            abi,
+            bytecode: Some(Bytecode::Linked(Bytes::from_static(&INIT_CODE))),
+            deployed_bytecode: None,
+        }
+    }
+
+    fn args() -> Vec<u8> {
+        let owner = DynSolValue::Address(Address::repeat_byte(0x11));
+        let supply = DynSolValue::Uint(U256::from(1000), 256);
+        DynSolValue::Tuple(vec![owner, supply]).abi_encode_params()
+    }
+
+    #[test]
+    fn exact() {
+        let contract = contract(Some("constructor(address owner, uint256 supply)"));
+        let input = [&INIT_CODE[..], &args()].concat();
+
+        let (init_code, encoded) = contract.split_creation_input(&input).unwrap();
+        assert_eq!(init_code, INIT_CODE);
+        assert_eq!(encoded, args());
+
+        let decoded = contract.decode_constructor_args(&input, true).unwrap();
+        assert_eq!(decoded.init_code_len, INIT_CODE.len());
+        assert!(decoded.exact_match);
+        assert_eq!(
+            decoded.args,
+            [
+                ("owner".into(), DynSolValue::Address(Address::repeat_byte(0x11))),
+                ("supply".into(), DynSolValue::Uint(U256::from(1000), 256)),
+            ]
+        );
+    }
+
+    #[test]
+    fn different_metadata() {
+        let contract = contract(Some("constructor(address owner, uint256 supply)"));
+
+        // different IPFS hash
+        let mut init_code = INIT_CODE.to_vec();
+        init_code[20] ^= 0xff;
+        let input = [&init_code[..], &args()].concat();
+        let decoded = contract.decode_constructor_args(&input, true).unwrap();
+        assert_eq!(decoded.init_code_len, INIT_CODE.len());
+        assert!(!decoded.exact_match);
+
+        // no IPFS hash: `{"solc": 0.8.20}`
+        let init_code = hex!("6080604052600080fd" "a164736f6c6343000814" "000a");
+        let input = [&init_code[..], &args()].concat();
+        let decoded = contract.decode_constructor_args(&input, true).unwrap();
+        assert_eq!(decoded.init_code_len, init_code.len());
+        assert_eq!(decoded.args.len(), 2);
+
+        // different code
+        let mut init_code = INIT_CODE.to_vec();
+        init_code[0] = 0x61;
+        let input = [&init_code[..], &args()].concat();
+        assert!(contract.split_creation_input(&input).is_err());
+    }
+
+    #[test]
+    fn no_constructor() {
+        let contract = contract(None);
+        let decoded = contract.decode_constructor_args(&INIT_CODE, true).unwrap();
+        assert!(decoded.args.is_empty());
+
+        let input = [&INIT_CODE[..], &args()].concat();
+        assert!(contract.decode_constructor_args(&input, true).is_err());
+    }
+}
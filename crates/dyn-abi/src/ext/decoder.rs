@@ -0,0 +1,600 @@
+use crate::{DecodedRevert, DynSolValue, Error, EventExt, FunctionExt, JsonAbiExt, Result};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_json_abi::{Constructor, Error as AbiError, Event, Function, JsonAbi, Param};
+use alloy_primitives::{LogData, Selector, B256};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for alloy_json_abi::JsonAbi {}
+}
+use sealed::Sealed;
+
+/// Provides ABI-driven decoding for the [`JsonAbi`] type.
+///
+/// Decoding calldata, logs and revert data requires looking up items by
+/// their selector or topic, which is done through an [`AbiDecoder`]. The
+/// methods of this trait build a new one on every call; when decoding many
+/// inputs against the same ABI, create one with [`decoder`](Self::decoder)
+/// instead and reuse it.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`JsonAbi`].
+pub trait AbiDecodeExt: Sealed {
+    /// Creates an [`AbiDecoder`] for this ABI, indexing its functions and
+    /// errors by selector and its events by topic.
+    ///
+    /// See [`AbiDecoder::new`].
+    fn decoder(&self) -> AbiDecoder<'_>;
+
+    /// Decodes function calldata, looking up the function by its selector.
+    ///
+    /// This builds a new [`AbiDecoder`] on every call. See
+    /// [`AbiDecoder::decode_calldata`].
+    fn decode_calldata(&self, data: &[u8], validate: bool) -> Result<DecodedCall<'_>>;
+
+    /// Decodes the return data of the given function.
+    ///
+    /// See [`AbiDecoder::decode_return`].
+    fn decode_return(
+        &self,
+        function: &str,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<Vec<(String, DynSolValue)>>;
+
+    /// Decodes ABI-encoded constructor arguments.
+    ///
+    /// See [`AbiDecoder::decode_constructor`].
+    fn decode_constructor(&self, data: &[u8], validate: bool)
+        -> Result<Vec<(String, DynSolValue)>>;
+
+    /// Decodes a log, looking up the event by its first topic.
+    ///
+    /// This builds a new [`AbiDecoder`] on every call. See
+    /// [`AbiDecoder::decode_log`].
+    fn decode_log(&self, log: &LogData, validate: bool) -> Result<DecodedLog<'_>>;
+
+    /// Decodes revert data.
+    ///
+    /// This builds a new [`AbiDecoder`] on every call. See
+    /// [`AbiDecoder::decode_revert`].
+    fn decode_revert(&self, data: &[u8]) -> DecodedRevert<'_>;
+}
+
+impl AbiDecodeExt for JsonAbi {
+    #[inline]
+    fn decoder(&self) -> AbiDecoder<'_> {
+        AbiDecoder::new(self)
+    }
+
+    #[inline]
+    fn decode_calldata(&self, data: &[u8], validate: bool) -> Result<DecodedCall<'_>> {
+        self.decoder().decode_calldata(data, validate)
+    }
+
+    #[inline]
+    fn decode_return(
+        &self,
+        function: &str,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<Vec<(String, DynSolValue)>> {
+        decode_return(self, function, data, validate)
+    }
+
+    #[inline]
+    fn decode_constructor(
+        &self,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<Vec<(String, DynSolValue)>> {
+        decode_constructor(self.constructor(), data, validate)
+    }
+
+    #[inline]
+    fn decode_log(&self, log: &LogData, validate: bool) -> Result<DecodedLog<'_>> {
+        self.decoder().decode_log(log, validate)
+    }
+
+    #[inline]
+    fn decode_revert(&self, data: &[u8]) -> DecodedRevert<'_> {
+        self.decoder().decode_revert(data)
+    }
+}
+
+/// Decodes calldata, return data, constructor arguments, logs and revert data
+/// against a [`JsonAbi`].
+///
+/// The function and error selectors and the event topics are computed once,
+/// when the decoder is created.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{AbiDecoder, DynSolValue, JsonAbiExt};
+/// use alloy_json_abi::JsonAbi;
+/// use alloy_primitives::{Address, U256};
+///
+/// let abi = JsonAbi::parse([
+///     "function transfer(address to, uint256 amount) returns (bool success)",
+///     "function balanceOf(address owner) returns (uint256)",
+/// ])?;
+/// let decoder = AbiDecoder::new(&abi);
+///
+/// let args = [DynSolValue::Address(Address::ZERO), DynSolValue::Uint(U256::from(10), 256)];
+/// let calldata = abi.function("transfer").unwrap()[0].abi_encode_input(&args)?;
+///
+/// let call = decoder.decode_calldata(&calldata, true)?;
+/// assert_eq!(call.function.name, "transfer");
+/// assert_eq!(call.args[1], ("amount".into(), args[1].clone()));
+/// # Ok::<(), alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct AbiDecoder<'a> {
+    abi: &'a JsonAbi,
+    functions: BTreeMap<Selector, Vec<&'a Function>>,
+    events: BTreeMap<B256, Vec<&'a Event>>,
+    anonymous_events: Vec<&'a Event>,
+    errors: BTreeMap<Selector, Vec<&'a AbiError>>,
+}
+
+impl<'a> AbiDecoder<'a> {
+    /// Creates a new decoder, indexing the ABI's functions and errors by
+    /// selector and its events by topic.
+    pub fn new(abi: &'a JsonAbi) -> Self {
+        let mut functions = BTreeMap::<_, Vec<_>>::new();
+        for function in abi.functions() {
+            functions.entry(function.selector()).or_default().push(function);
+        }
+        let mut events = BTreeMap::<_, Vec<_>>::new();
+        let mut anonymous_events = Vec::new();
+        for event in abi.events() {
+            if event.anonymous {
+                anonymous_events.push(event);
+            } else {
+                events.entry(event.selector()).or_default().push(event);
+            }
+        }
+        let mut errors = BTreeMap::<_, Vec<_>>::new();
+        for error in abi.errors() {
+            errors.entry(error.selector()).or_default().push(error);
+        }
+        Self { abi, functions, events, anonymous_events, errors }
+    }
+
+    /// Returns the underlying ABI.
+    #[inline]
+    pub const fn abi(&self) -> &'a JsonAbi {
+        self.abi
+    }
+
+    /// Returns the functions with the given selector.
+    ///
+    /// This contains more than one function only if their signatures collide.
+    #[inline]
+    pub fn functions(&self, selector: Selector) -> &[&'a Function] {
+        self.functions.get(&selector).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the non-anonymous events with the given topic.
+    ///
+    /// This contains more than one event if their signatures are the same, but
+    /// their parameters are indexed differently, like the ERC-20 and ERC-721
+    /// `Transfer` events.
+    #[inline]
+    pub fn events(&self, topic: B256) -> &[&'a Event] {
+        self.events.get(&topic).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the anonymous events.
+    #[inline]
+    pub fn anonymous_events(&self) -> &[&'a Event] {
+        &self.anonymous_events
+    }
+
+    /// Returns the custom errors with the given selector.
+    #[inline]
+    pub fn errors(&self, selector: Selector) -> &[&'a AbiError] {
+        self.errors.get(&selector).map_or(&[], Vec::as_slice)
+    }
+
+    /// Decodes function calldata, looking up the function by the selector in
+    /// the first 4 bytes.
+    ///
+    /// If multiple functions share the selector, the one which successfully
+    /// decodes the arguments is returned. If several do, the one whose
+    /// arguments re-encode to the exact input is preferred.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the calldata is shorter than a
+    /// selector, if no function has its selector, if it is ambiguous, or if
+    /// the arguments do not match the function's input types.
+    pub fn decode_calldata(&self, data: &[u8], validate: bool) -> Result<DecodedCall<'a>> {
+        if data.len() < 4 {
+            return Err(Error::custom("calldata is shorter than a function selector"));
+        }
+        let (selector, args) = data.split_at(4);
+        let selector = Selector::from_slice(selector);
+
+        match self.functions(selector) {
+            [] => Err(Error::UnknownSelector(selector)),
+            &[function] => {
+                let values = function.abi_decode_input(args, validate)?;
+                Ok(DecodedCall { function, args: named(&function.inputs, values) })
+            }
+            candidates => {
+                let mut decoded: Vec<_> = candidates
+                    .iter()
+                    .filter_map(|&function| {
+                        let values = function.abi_decode_input(args, validate).ok()?;
+                        Some((function, values))
+                    })
+                    .collect();
+                // Prefer the functions whose arguments re-encode to the exact
+                // same data, as decoding ignores trailing bytes
+                if decoded.len() > 1 {
+                    decoded.retain(|(_, values)| DynSolValue::encode_seq(values) == args);
+                }
+                match <[_; 1]>::try_from(decoded) {
+                    Ok([(function, values)]) => {
+                        Ok(DecodedCall { function, args: named(&function.inputs, values) })
+                    }
+                    Err(_) => Err(Error::AmbiguousSelector {
+                        selector,
+                        candidates: candidates.iter().map(|f| f.signature()).collect(),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Decodes the return data of the given function.
+    ///
+    /// The function can be given either by its name, if it is not overloaded,
+    /// or by its signature, like `balanceOf(address)`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the function does not exist or
+    /// is ambiguous, or if the data does not match its output types.
+    pub fn decode_return(
+        &self,
+        function: &str,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<Vec<(String, DynSolValue)>> {
+        decode_return(self.abi, function, data, validate)
+    }
+
+    /// Decodes ABI-encoded constructor arguments, without the init code.
+    ///
+    /// If the ABI has no constructor, the arguments must be empty.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the arguments do not match the
+    /// constructor's input types.
+    pub fn decode_constructor(
+        &self,
+        data: &[u8],
+        validate: bool,
+    ) -> Result<Vec<(String, DynSolValue)>> {
+        decode_constructor(self.abi.constructor(), data, validate)
+    }
+
+    /// Decodes a log.
+    ///
+    /// The event is looked up by the log's first topic among the
+    /// non-anonymous events with as many topics as the log. If none of them
+    /// matches, the anonymous events with as many indexed parameters as the
+    /// log has topics are tried. The first event that successfully decodes
+    /// the log is returned.
+    ///
+    /// Indexed parameters of dynamic types, like `string` or structs, are
+    /// stored as the hashes of their values, and are returned as such.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if no event matches the log or
+    /// successfully decodes it.
+    pub fn decode_log(&self, log: &LogData, validate: bool) -> Result<DecodedLog<'a>> {
+        let topics = log.topics();
+        let signed = topics.first().map_or(&[][..], |&topic| self.events(topic));
+        let anonymous = self.anonymous_events.iter().filter(|e| e.num_topics() == topics.len());
+
+        let mut error = None;
+        for &event in signed.iter().chain(anonymous) {
+            // also tells apart events which differ only in their indexing
+            if event.num_topics() != topics.len() {
+                error.get_or_insert(Error::TopicLengthMismatch {
+                    expected: event.num_topics(),
+                    actual: topics.len(),
+                });
+                continue;
+            }
+            match event.decode_log(log, validate) {
+                Ok(decoded) => {
+                    let names = |indexed: bool, values: Vec<_>| {
+                        let params = event.inputs.iter().filter(|p| p.indexed == indexed);
+                        params.map(|p| p.name.clone()).zip(values).collect()
+                    };
+                    return Ok(DecodedLog {
+                        event,
+                        indexed: names(true, decoded.indexed),
+                        body: names(false, decoded.body),
+                    });
+                }
+                // keep the errors of the events matching the signature first
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+
+        Err(error.unwrap_or_else(|| match topics.first() {
+            Some(topic) => Error::custom(format!("no event in the ABI matches the topic {topic}")),
+            None => Error::custom("no anonymous event in the ABI matches the log"),
+        }))
+    }
+}
+
+fn decode_return(
+    abi: &JsonAbi,
+    function: &str,
+    data: &[u8],
+    validate: bool,
+) -> Result<Vec<(String, DynSolValue)>> {
+    let function = resolve_function(abi, function)?;
+    let values = function.abi_decode_output(data, validate)?;
+    Ok(named(&function.outputs, values))
+}
+
+/// Looks up a function by its name, if it is not overloaded, or signature.
+fn resolve_function<'a>(abi: &'a JsonAbi, function: &str) -> Result<&'a Function> {
+    let unknown = || Error::custom(format!("function `{function}` not found in the ABI"));
+    let Some((name, _)) = function.split_once('(') else {
+        return match abi.function(function).map(Vec::as_slice) {
+            Some([function]) => Ok(function),
+            Some(overloads) => Err(Error::custom(format!(
+                "function `{function}` is overloaded, use its signature instead: {}",
+                overloads.iter().map(|f| f.signature()).collect::<Vec<_>>().join(", ")
+            ))),
+            None => Err(unknown()),
+        };
+    };
+    let function = function.replace(' ', "");
+    abi.function(name)
+        .and_then(|overloads| overloads.iter().find(|f| f.signature() == function))
+        .ok_or_else(unknown)
+}
+
+/// Decodes constructor arguments. If there is no constructor, the arguments
+/// must be empty.
+pub(crate) fn decode_constructor(
+    constructor: Option<&Constructor>,
+    data: &[u8],
+    validate: bool,
+) -> Result<Vec<(String, DynSolValue)>> {
+    match constructor {
+        Some(constructor) => {
+            let values = constructor.abi_decode_input(data, validate)?;
+            Ok(named(&constructor.inputs, values))
+        }
+        None if data.is_empty() => Ok(Vec::new()),
+        None => Err(Error::custom(format!(
+            "found {} bytes of constructor arguments, but the contract has no constructor",
+            data.len()
+        ))),
+    }
+}
+
+/// Function calldata decoded against a [`JsonAbi`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedCall<'a> {
+    /// The function that was called.
+    pub function: &'a Function,
+    /// The decoded arguments, paired with their parameter names.
+    pub args: Vec<(String, DynSolValue)>,
+}
+
+/// A log decoded against a [`JsonAbi`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedLog<'a> {
+    /// The event that was emitted.
+    pub event: &'a Event,
+    /// The indexed values, paired with their parameter names.
+    ///
+    /// Values of dynamic types are the hashes of the original values.
+    pub indexed: Vec<(String, DynSolValue)>,
+    /// The un-indexed values, paired with their parameter names.
+    pub body: Vec<(String, DynSolValue)>,
+}
+
+/// Pairs decoded values with the names of their parameters.
+pub(crate) fn named(params: &[Param], values: Vec<DynSolValue>) -> Vec<(String, DynSolValue)> {
+    params.iter().map(|param| param.name.clone()).zip(values).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, keccak256, Address, U256};
+
+    fn abi() -> JsonAbi {
+        JsonAbi::parse([
+            "constructor(address owner, uint256 supply)",
+            "function transfer(address to, uint256 amount) returns (bool success)",
+            "function balanceOf(address owner) returns (uint256 balance)",
+            "function approve(address spender, uint256 amount) returns (bool)",
+            "function approve(address spender) returns (bool)",
+        ])
+        .unwrap()
+    }
+
+    fn transfer_args() -> [DynSolValue; 2] {
+        [DynSolValue::Address(Address::repeat_byte(0x11)), DynSolValue::Uint(U256::from(1000), 256)]
+    }
+
+    #[test]
+    fn calldata() {
+        let abi = abi();
+        let decoder = AbiDecoder::new(&abi);
+
+        let args = transfer_args();
+        let calldata = abi.function("transfer").unwrap()[0].abi_encode_input(&args).unwrap();
+        let call = decoder.decode_calldata(&calldata, true).unwrap();
+        assert_eq!(call.function.signature(), "transfer(address,uint256)");
+        assert_eq!(call.args, [("to".into(), args[0].clone()), ("amount".into(), args[1].clone())]);
+        assert_eq!(abi.decode_calldata(&calldata, true).unwrap(), call);
+
+        // overloads have different selectors
+        let approve = &abi.function("approve").unwrap()[1];
+        let calldata = approve.abi_encode_input(&args[..1]).unwrap();
+        let call = decoder.decode_calldata(&calldata, true).unwrap();
+        assert_eq!(call.function.signature(), "approve(address)");
+
+        assert_eq!(
+            decoder.decode_calldata(&hex!("deadbeef"), true),
+            Err(Error::UnknownSelector(hex!("deadbeef").into()))
+        );
+        decoder.decode_calldata(&hex!("a9059c"), true).unwrap_err();
+        decoder.decode_calldata(&calldata[..20], true).unwrap_err();
+    }
+
+    #[test]
+    fn colliding_selectors() {
+        // `transfer(address,uint256)` and `many_msg_babbage(bytes1)` share the
+        // selector `0xa9059cbb`
+        let abi = JsonAbi::parse([
+            "function transfer(address to, uint256 amount)",
+            "function many_msg_babbage(bytes1 b)",
+        ])
+        .unwrap();
+        let decoder = AbiDecoder::new(&abi);
+        let selector = abi.function("transfer").unwrap()[0].selector();
+        assert_eq!(decoder.functions(selector).len(), 2);
+
+        // both decode, but only `transfer` uses all of the data
+        let calldata = abi.function("transfer").unwrap()[0].abi_encode_input(&transfer_args());
+        let call = decoder.decode_calldata(&calldata.unwrap(), true).unwrap();
+        assert_eq!(call.function.name, "transfer");
+
+        let calldata = [&selector[..], &[0; 32]].concat();
+        let call = decoder.decode_calldata(&calldata, true).unwrap();
+        assert_eq!(call.function.name, "many_msg_babbage");
+
+        // duplicate entries cannot be told apart
+        let abi = JsonAbi::parse(["function f(uint256 a)", "function f(uint256 b)"]).unwrap();
+        let decoder = AbiDecoder::new(&abi);
+        let selector = abi.function("f").unwrap()[0].selector();
+        let calldata = [&selector[..], &[0; 32]].concat();
+        assert_eq!(
+            decoder.decode_calldata(&calldata, true),
+            Err(Error::AmbiguousSelector {
+                selector,
+                candidates: vec!["f(uint256)".into(), "f(uint256)".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn return_data() {
+        let abi = abi();
+        let data = DynSolValue::Uint(U256::from(5), 256).abi_encode();
+        assert_eq!(
+            abi.decode_return("balanceOf", &data, true).unwrap(),
+            [("balance".into(), DynSolValue::Uint(U256::from(5), 256))]
+        );
+
+        let data = DynSolValue::Bool(true).abi_encode();
+        let values = abi.decode_return("approve(address, uint256)", &data, true).unwrap();
+        assert_eq!(values, [(String::new(), DynSolValue::Bool(true))]);
+
+        abi.decode_return("approve", &data, true).unwrap_err();
+        abi.decode_return("approve(uint256)", &data, true).unwrap_err();
+        abi.decode_return("mint", &data, true).unwrap_err();
+    }
+
+    #[test]
+    fn constructor() {
+        let abi = abi();
+        let args = DynSolValue::Tuple(transfer_args().to_vec()).abi_encode_params();
+        let values = abi.decode_constructor(&args, true).unwrap();
+        assert_eq!(values[0].0, "owner");
+        assert_eq!(values[1], ("supply".into(), DynSolValue::Uint(U256::from(1000), 256)));
+
+        let abi = JsonAbi::new();
+        assert_eq!(abi.decode_constructor(&[], true).unwrap(), []);
+        abi.decode_constructor(&args, true).unwrap_err();
+    }
+
+    #[test]
+    fn logs() {
+        let abi = JsonAbi::parse([
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Transfer(address indexed from, address indexed to, uint256 indexed id)",
+            "event Named(string indexed name, bytes data)",
+            "event Anon(address indexed who, bool flag) anonymous",
+        ])
+        .unwrap();
+        let decoder = AbiDecoder::new(&abi);
+        let from = Address::repeat_byte(0x11);
+        let to = Address::repeat_byte(0x22);
+        let topic = abi.event("Transfer").unwrap()[0].selector();
+        assert_eq!(decoder.events(topic).len(), 2);
+
+        // ERC-20
+        let value = DynSolValue::Uint(U256::from(42), 256);
+        let log = LogData::new_unchecked(
+            vec![topic, from.into_word(), to.into_word()],
+            value.abi_encode().into(),
+        );
+        let decoded = decoder.decode_log(&log, true).unwrap();
+        assert_eq!(decoded.event, &abi.event("Transfer").unwrap()[0]);
+        assert_eq!(
+            decoded.indexed,
+            [("from".into(), DynSolValue::Address(from)), ("to".into(), DynSolValue::Address(to))]
+        );
+        assert_eq!(decoded.body, [("value".into(), value.clone())]);
+        assert_eq!(abi.decode_log(&log, true).unwrap(), decoded);
+
+        // ERC-721
+        let log = LogData::new_unchecked(
+            vec![topic, from.into_word(), to.into_word(), U256::from(42).into()],
+            Default::default(),
+        );
+        let decoded = decoder.decode_log(&log, true).unwrap();
+        assert_eq!(decoded.event, &abi.event("Transfer").unwrap()[1]);
+        assert_eq!(decoded.indexed[2], ("id".into(), value));
+        assert_eq!(decoded.body, []);
+
+        // indexed dynamic values are hashed
+        let event = &abi.event("Named").unwrap()[0];
+        let data = DynSolValue::Tuple(vec![DynSolValue::Bytes(vec![1, 2])]).abi_encode_params();
+        let log = LogData::new_unchecked(vec![event.selector(), keccak256("alice")], data.into());
+        let decoded = decoder.decode_log(&log, true).unwrap();
+        assert_eq!(
+            decoded.indexed,
+            [("name".into(), DynSolValue::FixedBytes(keccak256("alice"), 32))]
+        );
+        assert_eq!(decoded.body, [("data".into(), DynSolValue::Bytes(vec![1, 2]))]);
+
+        // anonymous
+        let data = DynSolValue::Bool(true).abi_encode();
+        let log = LogData::new_unchecked(vec![from.into_word()], data.into());
+        let decoded = decoder.decode_log(&log, true).unwrap();
+        assert_eq!(decoded.event.name, "Anon");
+        assert_eq!(decoded.indexed, [("who".into(), DynSolValue::Address(from))]);
+        assert_eq!(decoded.body, [("flag".into(), DynSolValue::Bool(true))]);
+
+        // unknown and malformed
+        let log = LogData::new_unchecked(vec![B256::ZERO, B256::ZERO], Default::default());
+        decoder.decode_log(&log, true).unwrap_err();
+        let log = LogData::new_unchecked(vec![topic, from.into_word()], Default::default());
+        assert_eq!(
+            decoder.decode_log(&log, true),
+            Err(Error::TopicLengthMismatch { expected: 3, actual: 2 })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    resolve::ResolveSolEvent, DecodedEvent, DynSolEvent, DynSolType, Error, ResolveSolType, Result,
+    resolve::ResolveSolEvent, DecodedEvent, DynSolEvent, DynSolType, Error, NamedValue,
+    ResolveSolType, Result,
 };
 use alloc::vec::Vec;
 use alloy_json_abi::Event;
@@ -65,6 +66,21 @@ pub trait EventExt: Sealed {
     fn decode_log(&self, log: &LogData, validate: bool) -> Result<DecodedEvent> {
         self.decode_log_parts(log.topics().iter().copied(), &log.data, validate)
     }
+
+    /// Decodes the given log object according to this item's input types, and
+    /// annotates the values with the names of the inputs and their
+    /// components.
+    ///
+    /// The returned value is a tuple named after this event, with a component
+    /// for each input, in declaration order. Indexed inputs of non-value
+    /// types are annotated as `bytes32`, as only their hashes are stored in
+    /// the topics.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the decoded data does not match
+    /// the expected input types.
+    fn decode_log_named(&self, log: &LogData, validate: bool) -> Result<NamedValue>;
 }
 
 impl EventExt for Event {
@@ -74,6 +90,10 @@ impl EventExt for Event {
     {
         ResolveSolEvent::resolve(self)?.decode_log_parts(topics, data, validate)
     }
+
+    fn decode_log_named(&self, log: &LogData, validate: bool) -> Result<NamedValue> {
+        NamedValue::from_event(self, self.decode_log(log, validate)?)
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,14 @@
 mod abi;
 pub use abi::{FunctionExt, JsonAbiExt};
 
+mod contract;
+pub use contract::{ContractObjectExt, DecodedConstructor};
+
+mod decoder;
+pub use decoder::{AbiDecodeExt, AbiDecoder, DecodedCall, DecodedLog};
+
 mod event;
 pub use event::EventExt;
+
+mod revert;
+pub use revert::DecodedRevert;
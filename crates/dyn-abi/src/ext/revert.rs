@@ -0,0 +1,340 @@
+use super::decoder::named;
+use crate::{AbiDecoder, DynSolType, DynSolValue, JsonAbiExt};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec, vec::Vec};
+use alloy_json_abi::{Error, Param};
+use alloy_primitives::{hex, Bytes, Selector};
+use alloy_sol_types::{Panic, Revert, SolError};
+use core::fmt;
+
+/// The maximum nesting depth of revert data wrapped in custom errors.
+const MAX_DEPTH: usize = 8;
+
+/// Custom errors which are decoded even if they are not part of the ABI, as
+/// they are commonly bubbled up from other contracts.
+const WRAPPERS: [Wrapper; 3] = [
+    // ERC-4337 `EntryPoint`
+    Wrapper {
+        selector: Selector::new(hex!("220266b6")),
+        name: "FailedOp",
+        inputs: &[("opIndex", "uint256"), ("reason", "string")],
+    },
+    Wrapper {
+        selector: Selector::new(hex!("65c8fd4d")),
+        name: "FailedOpWithRevert",
+        inputs: &[("opIndex", "uint256"), ("reason", "string"), ("inner", "bytes")],
+    },
+    // smart accounts and executors
+    Wrapper {
+        selector: Selector::new(hex!("15fcd675")),
+        name: "ExecutionFailed",
+        inputs: &[("reason", "bytes")],
+    },
+];
+
+/// A well-known custom error, with its precomputed selector. The [`Error`] is
+/// only built when the selector matches.
+struct Wrapper {
+    selector: Selector,
+    name: &'static str,
+    /// `(name, type)` pairs.
+    inputs: &'static [(&'static str, &'static str)],
+}
+
+impl Wrapper {
+    fn error(&self) -> Error {
+        let inputs = self.inputs.iter().map(|&(name, ty)| Param {
+            ty: ty.into(),
+            name: name.into(),
+            components: Vec::new(),
+            internal_type: None,
+        });
+        Error { name: self.name.into(), inputs: inputs.collect() }
+    }
+}
+
+/// Revert data decoded against a [`JsonAbi`](alloy_json_abi::JsonAbi).
+///
+/// The [`Display`](fmt::Display) implementation renders a human-readable
+/// message, including the reasons of any nested reverts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodedRevert<'a> {
+    /// A [`Revert`] with the given reason, thrown by `revert("...")` and
+    /// `require(condition, "...")`. This also includes raw UTF-8 revert data.
+    Revert(String),
+    /// A [`Panic`], thrown by `assert` and internal Solidity checks. Its
+    /// [kind](Panic::kind) is known for Solidity's panic codes.
+    Panic(Panic),
+    /// A custom error.
+    Custom {
+        /// The error, which is either from the ABI or one of the well-known
+        /// errors that wrap the revert data of an inner call.
+        error: Cow<'a, Error>,
+        /// The decoded arguments, paired with their parameter names.
+        values: Vec<(String, DynSolValue)>,
+        /// The revert data of an inner call, decoded from the first `bytes`
+        /// argument, if it is recognized.
+        inner: Option<Box<DecodedRevert<'a>>>,
+    },
+    /// Unrecognized revert data.
+    Unknown(Bytes),
+}
+
+impl fmt::Display for DecodedRevert<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Revert(reason) if reason.is_empty() => f.write_str("revert: <empty>"),
+            Self::Revert(reason) => write!(f, "revert: {reason}"),
+            Self::Panic(panic) => panic.fmt(f),
+            Self::Custom { error, values, inner } => {
+                write!(f, "{}(", error.name)?;
+                for (i, (name, value)) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    if !name.is_empty() {
+                        write!(f, "{name}: ")?;
+                    }
+                    write!(f, "{}", value.display_sol(Default::default()))?;
+                }
+                f.write_str(")")?;
+                match inner {
+                    Some(inner) => write!(f, ": {inner}"),
+                    None => Ok(()),
+                }
+            }
+            Self::Unknown(data) if data.is_empty() => f.write_str("execution reverted"),
+            Self::Unknown(data) => write!(f, "unknown revert data: {data}"),
+        }
+    }
+}
+
+impl<'a> AbiDecoder<'a> {
+    /// Decodes revert data.
+    ///
+    /// The data is recognized as, in order:
+    /// - a [`Revert`] or a [`Panic`];
+    /// - a custom error from the ABI, or a well-known wrapper error, like ERC-4337's `FailedOp`,
+    ///   `FailedOpWithRevert` and `ExecutionFailed`;
+    /// - revert data ABI-encoded as `bytes`, as bubbled up by some multicall contracts, which is
+    ///   unwrapped;
+    /// - a raw UTF-8 string.
+    ///
+    /// The first `bytes` argument of a custom error is recursively decoded as
+    /// the revert data of an inner call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{AbiDecoder, DecodedRevert, DynSolValue, JsonAbiExt};
+    /// use alloy_json_abi::JsonAbi;
+    /// use alloy_primitives::U256;
+    /// use alloy_sol_types::{Revert, SolError};
+    ///
+    /// let abi = JsonAbi::parse(["error CallFailed(uint256 index, bytes reason)"])?;
+    /// let decoder = AbiDecoder::new(&abi);
+    ///
+    /// let reason = Revert::from("insufficient balance").abi_encode();
+    /// let args = [DynSolValue::Uint(U256::from(2), 256), DynSolValue::Bytes(reason)];
+    /// let data = abi.errors().next().unwrap().abi_encode_input(&args)?;
+    ///
+    /// let revert = decoder.decode_revert(&data);
+    /// assert!(matches!(revert, DecodedRevert::Custom { .. }));
+    /// assert!(revert.to_string().ends_with(": revert: insufficient balance"));
+    /// # Ok::<(), alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn decode_revert(&self, data: &[u8]) -> DecodedRevert<'a> {
+        if let Some(decoded) = self.decode_revert_inner(data, 0) {
+            return decoded;
+        }
+
+        match core::str::from_utf8(data) {
+            Ok(s) if !s.is_empty() && !s.contains(|c: char| c.is_control() && c != '\n') => {
+                DecodedRevert::Revert(s.into())
+            }
+            _ => DecodedRevert::Unknown(Bytes::copy_from_slice(data)),
+        }
+    }
+
+    fn decode_revert_inner(&self, data: &[u8], depth: usize) -> Option<DecodedRevert<'a>> {
+        if depth > MAX_DEPTH || data.len() < 4 {
+            return None;
+        }
+
+        let (selector, args) = data.split_at(4);
+        let selector = Selector::from_slice(selector);
+        if selector == Revert::SELECTOR {
+            if let Ok(revert) = Revert::abi_decode(data, true) {
+                return Some(DecodedRevert::Revert(revert.reason));
+            }
+        } else if selector == Panic::SELECTOR {
+            if let Ok(panic) = Panic::abi_decode(data, true) {
+                return Some(DecodedRevert::Panic(panic));
+            }
+        }
+
+        let known = self.errors(selector).iter().map(|&error| Cow::Borrowed(error));
+        let matching = WRAPPERS.iter().filter(|wrapper| wrapper.selector == selector);
+        for error in known.chain(matching.map(|wrapper| Cow::Owned(wrapper.error()))) {
+            let Ok(values) = error.abi_decode_input(args, true) else { continue };
+            let inner = values.iter().find_map(|value| match value {
+                DynSolValue::Bytes(inner) => self.decode_revert_inner(inner, depth + 1),
+                _ => None,
+            });
+            let values = named(&error.inputs, values);
+            return Some(DecodedRevert::Custom { error, values, inner: inner.map(Box::new) });
+        }
+
+        // `abi.encode(bytes)`, which must be canonical to avoid false positives
+        let ty = DynSolType::Tuple(vec![DynSolType::Bytes]);
+        match ty.abi_decode_params(data) {
+            Ok(DynSolValue::Tuple(values)) if DynSolValue::encode_seq(&values) == data => {
+                match values.as_slice() {
+                    [DynSolValue::Bytes(inner)] => self.decode_revert_inner(inner, depth + 1),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AbiDecodeExt;
+    use alloc::string::ToString;
+    use alloy_json_abi::JsonAbi;
+    use alloy_primitives::{hex, U256};
+    use alloy_sol_types::PanicKind;
+
+    fn abi() -> JsonAbi {
+        JsonAbi::parse([
+            "error InsufficientBalance(uint256 available, uint256 required)",
+            "error CallFailed(address target, bytes)",
+        ])
+        .unwrap()
+    }
+
+    fn error(abi: &JsonAbi, name: &str) -> Result<Error, String> {
+        match abi.error(name) {
+            Some(errors) => Ok(errors[0].clone()),
+            None => WRAPPERS
+                .iter()
+                .find(|wrapper| wrapper.name == name)
+                .map(Wrapper::error)
+                .ok_or_else(|| format!("unknown error {name}")),
+        }
+    }
+
+    #[test]
+    fn wrappers() {
+        let signatures = [
+            "error FailedOp(uint256 opIndex, string reason)",
+            "error FailedOpWithRevert(uint256 opIndex, string reason, bytes inner)",
+            "error ExecutionFailed(bytes reason)",
+        ];
+        for (wrapper, signature) in WRAPPERS.iter().zip(signatures) {
+            let error = wrapper.error();
+            assert_eq!(error, Error::parse(signature).unwrap());
+            assert_eq!(error.selector(), wrapper.selector);
+        }
+    }
+
+    #[test]
+    fn builtin() {
+        let abi = abi();
+        let revert = abi.decode_revert(&Revert::from("not owner").abi_encode());
+        assert_eq!(revert, DecodedRevert::Revert("not owner".into()));
+        assert_eq!(revert.to_string(), "revert: not owner");
+
+        let panic = Panic::from(PanicKind::UnderOverflow);
+        let revert = abi.decode_revert(&panic.abi_encode());
+        assert_eq!(revert, DecodedRevert::Panic(panic));
+        assert_eq!(revert.to_string(), "panic: arithmetic underflow or overflow (0x11)");
+
+        let revert = abi.decode_revert(b"raw reason");
+        assert_eq!(revert, DecodedRevert::Revert("raw reason".into()));
+
+        let revert = abi.decode_revert(&[]);
+        assert_eq!(revert, DecodedRevert::Unknown(Bytes::new()));
+        assert_eq!(revert.to_string(), "execution reverted");
+
+        let revert = abi.decode_revert(&hex!("deadbeef"));
+        assert_eq!(revert.to_string(), "unknown revert data: 0xdeadbeef");
+    }
+
+    #[test]
+    fn custom() -> Result<(), String> {
+        let abi = abi();
+        let insufficient = error(&abi, "InsufficientBalance")?;
+        let data =
+            insufficient.abi_encode_input(&[U256::from(1).into(), U256::from(5).into()]).unwrap();
+        let revert = abi.decode_revert(&data);
+        assert_eq!(
+            revert,
+            DecodedRevert::Custom {
+                error: Cow::Borrowed(&abi.error("InsufficientBalance").unwrap()[0]),
+                values: vec![
+                    ("available".into(), U256::from(1).into()),
+                    ("required".into(), U256::from(5).into())
+                ],
+                inner: None,
+            }
+        );
+        assert_eq!(revert.to_string(), "InsufficientBalance(available: 1, required: 5)");
+
+        // bad encoding
+        let revert = abi.decode_revert(&data[..36]);
+        assert!(matches!(revert, DecodedRevert::Unknown(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> Result<(), String> {
+        let abi = abi();
+        let inner = error(&abi, "InsufficientBalance")?
+            .abi_encode_input(&[U256::from(1).into(), U256::from(5).into()]);
+        let target = DynSolValue::Address(Default::default());
+        let call_failed = error(&abi, "CallFailed")?
+            .abi_encode_input(&[target, DynSolValue::Bytes(inner.unwrap())])
+            .unwrap();
+
+        let revert = abi.decode_revert(&call_failed);
+        let DecodedRevert::Custom { inner: Some(inner), .. } = &revert else {
+            panic!("{revert:?}")
+        };
+        assert!(matches!(**inner, DecodedRevert::Custom { .. }));
+        assert_eq!(
+            revert.to_string(),
+            format!(
+                "CallFailed(target: 0x0000000000000000000000000000000000000000, 0x{}): \
+                 InsufficientBalance(available: 1, required: 5)",
+                hex::encode(&call_failed[100..168])
+            )
+        );
+
+        // not in the ABI
+        let failed_op = error(&abi, "FailedOpWithRevert")?.abi_encode_input(&[
+            U256::from(0).into(),
+            DynSolValue::String("AA23 reverted".into()),
+            DynSolValue::Bytes(call_failed.clone()),
+        ]);
+        let revert = abi.decode_revert(&failed_op.unwrap());
+        let DecodedRevert::Custom { error, inner: Some(inner), .. } = &revert else {
+            panic!("{revert:?}")
+        };
+        assert!(matches!(error, Cow::Owned(_)));
+        assert!(revert
+            .to_string()
+            .starts_with(r#"FailedOpWithRevert(opIndex: 0, reason: "AA23 reverted", inner: 0x"#));
+        assert_eq!(**inner, abi.decode_revert(&call_failed));
+
+        // `abi.encode(bytes)`
+        let panic = Panic::from(PanicKind::DivisionByZero).abi_encode();
+        let data = DynSolValue::Tuple(vec![DynSolValue::Bytes(panic.clone())]).abi_encode_params();
+        assert_eq!(abi.decode_revert(&data), abi.decode_revert(&panic));
+        let data = [&data[..], &[0; 32]].concat();
+        assert!(matches!(abi.decode_revert(&data), DecodedRevert::Unknown(_)));
+        Ok(())
+    }
+}
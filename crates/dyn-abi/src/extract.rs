@@ -0,0 +1,381 @@
+//! Lazy extraction of single values from ABI-encoded data.
+
+use crate::{Decoder, DynSolType, DynSolValue, DynToken, Error, Result};
+use alloc::vec::Vec;
+use alloy_json_abi::Param;
+
+/// A segment of an extraction path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    /// A tuple component, fixed array or array element, by index.
+    Index(usize),
+    /// A tuple component, by name.
+    Name(&'a str),
+}
+
+impl DynSolType {
+    /// Decodes the value at `path` from ABI-encoded data, without decoding
+    /// any other values.
+    ///
+    /// The data is decoded like [`abi_decode_params`](Self::abi_decode_params):
+    /// if this type is a tuple, the data is decoded as a sequence, and the
+    /// first segment of the path selects one of its components.
+    ///
+    /// The path is a `.`-separated list of segments, each of which is either
+    /// an index or a struct field name, and may be followed by any number of
+    /// `[index]` suffixes. Field names can only be resolved through custom
+    /// structs; use [`JsonAbiExt::abi_extract_input`] to resolve them through
+    /// the component names of the parameters instead.
+    ///
+    /// Offsets are followed directly to the target value, so that extracting
+    /// an element of a large array does not decode any of its siblings.
+    ///
+    /// [`JsonAbiExt::abi_extract_input`]: crate::JsonAbiExt::abi_extract_input
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::{Address, U256};
+    ///
+    /// let ty: DynSolType = "(uint256,(address,uint256)[])".parse()?;
+    /// let orders = (0..100)
+    ///     .map(|i| {
+    ///         DynSolValue::Tuple(vec![
+    ///             DynSolValue::Address(Address::with_last_byte(i)),
+    ///             DynSolValue::Uint(U256::from(i), 256),
+    ///         ])
+    ///     })
+    ///     .collect();
+    /// let data =
+    ///     DynSolValue::Tuple(vec![DynSolValue::Uint(U256::from(1), 256), DynSolValue::Array(orders)])
+    ///         .abi_encode_params();
+    ///
+    /// let maker = ty.extract(&data, "1[17].0")?;
+    /// assert_eq!(maker, DynSolValue::Address(Address::with_last_byte(17)));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn extract(&self, data: &[u8], path: &str) -> Result<DynSolValue> {
+        extract_params(self, None, &mut Decoder::new(data, false), path)
+    }
+}
+
+/// Extracts the value at `path` from data encoded like
+/// [`DynSolType::abi_decode_params`], resolving field names through the given
+/// parameters, if any.
+pub(crate) fn extract_params(
+    ty: &DynSolType,
+    params: Option<&[Param]>,
+    decoder: &mut Decoder<'_>,
+    path: &str,
+) -> Result<DynSolValue> {
+    let path = parse_path(path)?;
+    match ty {
+        DynSolType::Tuple(types) => match path.split_first() {
+            Some((&segment, rest)) => {
+                let index = component(ty, params, segment)?;
+                seek(decoder, head_size(types[..index].iter()))?;
+                let params = params.and_then(|params| params.get(index));
+                walk(&types[index], params.map(|p| &p.components[..]), decoder, rest)
+            }
+            None => ty.abi_decode_inner(decoder, DynToken::decode_sequence_populate),
+        },
+        _ => walk(ty, params, decoder, &path),
+    }
+}
+
+/// Decodes the value at `path` relative to a value of type `ty`, whose head
+/// is at the decoder's current offset.
+fn walk(
+    ty: &DynSolType,
+    params: Option<&[Param]>,
+    decoder: &mut Decoder<'_>,
+    path: &[Segment<'_>],
+) -> Result<DynSolValue> {
+    let Some((&segment, rest)) = path.split_first() else {
+        return ty.abi_decode_inner(decoder, DynToken::decode_single_populate);
+    };
+
+    let index = component(ty, params, segment)?;
+    let (inner, params, mut child, offset) = match ty {
+        DynSolType::Array(inner) => {
+            let mut child = decoder.take_indirection()?;
+            let len = child.take_offset()?;
+            if index >= len {
+                return Err(out_of_bounds(ty, index, len));
+            }
+            // element offsets are relative to the word after the length
+            let offset = index.saturating_mul(head_size([&**inner]));
+            (&**inner, params, child.child(child.offset())?, offset)
+        }
+        DynSolType::FixedArray(inner, _) => {
+            let offset = index.saturating_mul(head_size([&**inner]));
+            (&**inner, params, enter(ty, decoder)?, offset)
+        }
+        DynSolType::Tuple(types) => {
+            let params = params.and_then(|params| params.get(index));
+            let offset = head_size(types[..index].iter());
+            (&types[index], params.map(|p| &p.components[..]), enter(ty, decoder)?, offset)
+        }
+        #[cfg(feature = "eip712")]
+        DynSolType::CustomStruct { tuple, .. } => {
+            let params = params.and_then(|params| params.get(index));
+            let offset = head_size(tuple[..index].iter());
+            (&tuple[index], params.map(|p| &p.components[..]), enter(ty, decoder)?, offset)
+        }
+        _ => unreachable!("checked in `component`"),
+    };
+
+    seek(&mut child, offset)?;
+    walk(inner, params, &mut child, rest)
+}
+
+/// Resolves a path segment to the index of a component of `ty`, checking
+/// the bounds of tuples and fixed arrays.
+fn component(ty: &DynSolType, params: Option<&[Param]>, segment: Segment<'_>) -> Result<usize> {
+    let len = match ty {
+        DynSolType::Array(_) => usize::MAX,
+        DynSolType::FixedArray(_, len) => *len,
+        DynSolType::Tuple(types) => types.len(),
+        #[cfg(feature = "eip712")]
+        DynSolType::CustomStruct { tuple, .. } => tuple.len(),
+        _ => return Err(Error::custom(format!("cannot index into `{}`", ty.sol_type_name()))),
+    };
+
+    let index = match segment {
+        Segment::Index(index) => index,
+        Segment::Name(name) => {
+            let position = match (ty, params) {
+                (DynSolType::Tuple(_), Some(params)) => {
+                    params.iter().position(|param| param.name == name)
+                }
+                #[cfg(feature = "eip712")]
+                (DynSolType::CustomStruct { prop_names, .. }, None) => {
+                    prop_names.iter().position(|prop| prop == name)
+                }
+                #[cfg(feature = "eip712")]
+                (DynSolType::CustomStruct { .. }, Some(params)) => {
+                    params.iter().position(|param| param.name == name)
+                }
+                _ => None,
+            };
+            position.ok_or_else(|| {
+                Error::custom(format!("no component named `{name}` in `{}`", ty.sol_type_name()))
+            })?
+        }
+    };
+    if index >= len {
+        return Err(out_of_bounds(ty, index, len));
+    }
+    Ok(index)
+}
+
+/// Follows the head of a tuple or fixed array to its contents.
+fn enter<'d>(ty: &DynSolType, decoder: &mut Decoder<'d>) -> Result<Decoder<'d>> {
+    if is_dynamic(ty) {
+        decoder.take_indirection().map_err(Into::into)
+    } else {
+        decoder.child(decoder.offset()).map_err(Into::into)
+    }
+}
+
+/// Sets the decoder's offset, failing if it is past the end of the data.
+fn seek(decoder: &mut Decoder<'_>, offset: usize) -> Result<()> {
+    decoder.peek(offset..)?;
+    decoder.set_offset(offset);
+    Ok(())
+}
+
+fn out_of_bounds(ty: &DynSolType, index: usize, len: usize) -> Error {
+    Error::custom(format!(
+        "index {index} out of bounds for `{}` of length {len}",
+        ty.sol_type_name()
+    ))
+}
+
+/// Returns the total size of the heads of the given types, in bytes.
+fn head_size<'a>(types: impl IntoIterator<Item = &'a DynSolType>) -> usize {
+    types
+        .into_iter()
+        .map(|ty| if is_dynamic(ty) { 32 } else { static_size(ty) })
+        .fold(0, usize::saturating_add)
+}
+
+/// Returns the encoded size of a static type, in bytes.
+fn static_size(ty: &DynSolType) -> usize {
+    match ty {
+        DynSolType::FixedArray(inner, len) => static_size(inner).saturating_mul(*len),
+        DynSolType::Tuple(types) => types.iter().map(static_size).fold(0, usize::saturating_add),
+        #[cfg(feature = "eip712")]
+        DynSolType::CustomStruct { tuple, .. } => {
+            tuple.iter().map(static_size).fold(0, usize::saturating_add)
+        }
+        _ => 32,
+    }
+}
+
+fn is_dynamic(ty: &DynSolType) -> bool {
+    match ty {
+        DynSolType::Bytes | DynSolType::String | DynSolType::Array(_) => true,
+        DynSolType::FixedArray(inner, len) => *len > 0 && is_dynamic(inner),
+        DynSolType::Tuple(types) => types.iter().any(is_dynamic),
+        #[cfg(feature = "eip712")]
+        DynSolType::CustomStruct { tuple, .. } => tuple.iter().any(is_dynamic),
+        _ => false,
+    }
+}
+
+/// Parses an extraction path, like `0.orders[17].maker`.
+pub(crate) fn parse_path(path: &str) -> Result<Vec<Segment<'_>>> {
+    let invalid = || Error::custom(format!("invalid extraction path `{path}`"));
+
+    let mut segments = Vec::new();
+    if path.is_empty() {
+        return Ok(segments);
+    }
+    for part in path.split('.') {
+        let (head, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if !head.is_empty() {
+            segments.push(match head.parse() {
+                Ok(index) => Segment::Index(index),
+                Err(_) if head.starts_with(|c: char| c.is_ascii_digit()) => return Err(invalid()),
+                Err(_) => Segment::Name(head),
+            });
+        } else if rest.is_empty() {
+            return Err(invalid());
+        }
+        while !rest.is_empty() {
+            let (index, tail) = rest
+                .strip_prefix('[')
+                .and_then(|s| s.split_once(']'))
+                .and_then(|(index, tail)| Some((index.parse().ok()?, tail)))
+                .ok_or_else(invalid)?;
+            segments.push(Segment::Index(index));
+            rest = tail;
+        }
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::JsonAbiExt;
+    use alloc::string::ToString;
+    use alloy_json_abi::Function;
+    use alloy_primitives::{Address, U256};
+
+    fn order(i: usize) -> DynSolValue {
+        DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::with_last_byte(i as u8)),
+            DynSolValue::Array(vec![U256::from(i).into(); i % 3]),
+            DynSolValue::Tuple(vec![DynSolValue::String(i.to_string()), U256::from(i).into()]),
+        ])
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(parse_path("").unwrap(), []);
+        assert_eq!(
+            parse_path("0.orders[17][2].maker").unwrap(),
+            [
+                Segment::Index(0),
+                Segment::Name("orders"),
+                Segment::Index(17),
+                Segment::Index(2),
+                Segment::Name("maker"),
+            ]
+        );
+        assert_eq!(parse_path("[1].a").unwrap(), [Segment::Index(1), Segment::Name("a")]);
+        for path in ["a..b", ".", "a[", "a[b]", "a[1]b", "1a", "a.[x]"] {
+            parse_path(path).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn extract() {
+        let ty: DynSolType =
+            "(bool,uint256[2],(address,uint256[],(string,uint256))[],string)".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Bool(true),
+            DynSolValue::FixedArray(vec![U256::from(1).into(), U256::from(2).into()]),
+            DynSolValue::Array((0..20).map(order).collect()),
+            DynSolValue::String("end".into()),
+        ]);
+        let data = value.abi_encode_params();
+
+        assert_eq!(ty.extract(&data, "").unwrap(), value);
+        assert_eq!(ty.extract(&data, "0").unwrap(), DynSolValue::Bool(true));
+        assert_eq!(ty.extract(&data, "1[1]").unwrap(), U256::from(2).into());
+        assert_eq!(ty.extract(&data, "3").unwrap(), DynSolValue::String("end".into()));
+        for i in [0, 5, 17, 19] {
+            assert_eq!(ty.extract(&data, &format!("2[{i}]")).unwrap(), order(i));
+            let DynSolValue::Tuple(fields) = order(i) else { unreachable!() };
+            assert_eq!(ty.extract(&data, &format!("2.{i}.0")).unwrap(), fields[0]);
+            assert_eq!(ty.extract(&data, &format!("2[{i}].1")).unwrap(), fields[1]);
+            assert_eq!(
+                ty.extract(&data, &format!("2[{i}].2.0")).unwrap(),
+                DynSolValue::String(i.to_string())
+            );
+        }
+        assert_eq!(ty.extract(&data, "2[17][1][1]").unwrap(), U256::from(17).into());
+
+        for path in ["4", "1[2]", "2[20]", "2[1][1][1]", "0.0", "x"] {
+            ty.extract(&data, path).unwrap_err();
+        }
+
+        // only the target's offsets are followed
+        let mut corrupt = data.clone();
+        let string_len = data.len() - 2 * 32;
+        corrupt[string_len..string_len + 32]
+            .copy_from_slice(&U256::from(1 << 20).to_be_bytes::<32>());
+        assert_eq!(ty.extract(&corrupt, "2[3].0").unwrap(), ty.extract(&data, "2[3].0").unwrap());
+        ty.abi_decode_params(&corrupt).unwrap_err();
+
+        let ty: DynSolType = "string[]".parse().unwrap();
+        let data = DynSolValue::Array(vec![DynSolValue::String("a".into()); 3]).abi_encode();
+        assert_eq!(ty.extract(&data, "[2]").unwrap(), DynSolValue::String("a".into()));
+    }
+
+    #[test]
+    fn extract_named() {
+        let function = Function::parse(
+            "fill(uint256 id, (address maker, uint256[] amounts, (string memo, uint256 fee) \
+             extra)[] orders)",
+        )
+        .unwrap();
+        let orders: Vec<_> = (0..20).map(order).collect();
+        let data = function
+            .abi_encode_input(&[U256::from(7).into(), DynSolValue::Array(orders.clone())])
+            .unwrap();
+
+        let extract = |path| function.abi_extract_input(&data[4..], path, true);
+        assert_eq!(extract("id").unwrap(), U256::from(7).into());
+        assert_eq!(extract("orders[17]").unwrap(), orders[17]);
+        assert_eq!(
+            extract("orders[17].maker").unwrap(),
+            DynSolValue::Address(Address::with_last_byte(17))
+        );
+        assert_eq!(extract("1.17.extra.fee").unwrap(), U256::from(17).into());
+        assert_eq!(extract("orders.2.amounts[1]").unwrap(), U256::from(2).into());
+        extract("orders[17].taker").unwrap_err();
+        extract("orders.maker").unwrap_err();
+    }
+
+    #[test]
+    fn extract_truncated() {
+        let ty: DynSolType = "(uint256,(uint256,uint256))".parse().unwrap();
+        ty.extract(&[], "1.0").unwrap_err();
+        ty.extract(&[0; 64], "1.1").unwrap_err();
+        assert_eq!(ty.extract(&[0; 96], "1.1").unwrap(), U256::ZERO.into());
+
+        let ty: DynSolType = "(uint256[2][],uint256[3])".parse().unwrap();
+        ty.extract(&[0; 32], "0[0][1]").unwrap_err();
+        ty.extract(&[0; 64], "1[2]").unwrap_err();
+
+        let huge = usize::MAX / 32 + 1;
+        let ty: DynSolType = format!("(uint256[{huge}],uint256[{huge}][2])").parse().unwrap();
+        ty.extract(&[0; 64], "1").unwrap_err();
+        ty.extract(&[0; 64], "1[1][0]").unwrap_err();
+    }
+}
@@ -0,0 +1,409 @@
+use crate::{ty::as_tuple, DynSolValue};
+use alloc::string::{String, ToString};
+use alloy_primitives::{Sign, U256};
+use core::fmt::{self, Write};
+
+/// An Ether unit, used to format integers as amounts with
+/// [`SolFormatOptions::unit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EtherUnit {
+    /// `1 gwei = 10^9 wei`.
+    Gwei,
+    /// `1 ether = 10^18 wei`.
+    Ether,
+}
+
+impl EtherUnit {
+    /// Returns the number of decimals of this unit.
+    #[inline]
+    pub const fn decimals(self) -> usize {
+        match self {
+            Self::Gwei => 9,
+            Self::Ether => 18,
+        }
+    }
+
+    /// Returns the name of this unit, as used in Solidity.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Gwei => "gwei",
+            Self::Ether => "ether",
+        }
+    }
+}
+
+impl fmt::Display for EtherUnit {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options for formatting [`DynSolValue`]s as Solidity literals with
+/// [`DynSolValue::display_sol`].
+///
+/// The output can always be parsed back with
+/// [`DynSolType::coerce_str`](crate::DynSolType::coerce_str), unless string
+/// escaping is disabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SolFormatOptions {
+    checksum: bool,
+    hex_ints: bool,
+    unit: Option<EtherUnit>,
+    escape_strings: bool,
+    pretty: bool,
+}
+
+impl Default for SolFormatOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SolFormatOptions {
+    /// Creates the default options: checksummed addresses, decimal integers
+    /// without units, escaped strings and single-line output.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { checksum: true, hex_ints: false, unit: None, escape_strings: true, pretty: false }
+    }
+
+    /// Sets whether to checksum addresses, as per [EIP-55]. Otherwise, they
+    /// are formatted in lowercase.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    #[inline]
+    pub const fn checksum(mut self, yes: bool) -> Self {
+        self.checksum = yes;
+        self
+    }
+
+    /// Sets whether to format integers as `0x`-prefixed hexadecimal instead
+    /// of decimal.
+    #[inline]
+    pub const fn hex_ints(mut self, yes: bool) -> Self {
+        self.hex_ints = yes;
+        self
+    }
+
+    /// Sets the unit to format integers in, like `1.5 ether`.
+    ///
+    /// This applies to all integers, and takes precedence over
+    /// [`hex_ints`](Self::hex_ints).
+    #[inline]
+    pub const fn unit(mut self, unit: Option<EtherUnit>) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets whether to escape quotes, backslashes and control characters in
+    /// strings.
+    ///
+    /// If disabled, strings are written verbatim between quotes, and may not
+    /// be able to be parsed back.
+    #[inline]
+    pub const fn escape_strings(mut self, yes: bool) -> Self {
+        self.escape_strings = yes;
+        self
+    }
+
+    /// Sets whether to write arrays and tuples which contain other non-empty
+    /// arrays or tuples over multiple lines, with one element per line.
+    #[inline]
+    pub const fn pretty(mut self, yes: bool) -> Self {
+        self.pretty = yes;
+        self
+    }
+
+    fn write(&self, value: &DynSolValue, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        match value {
+            DynSolValue::Bool(b) => write!(f, "{b}"),
+            DynSolValue::Int(int, _) => {
+                let (sign, abs) = int.into_sign_and_abs();
+                self.write_int(sign, abs, f)
+            }
+            DynSolValue::Uint(uint, _) => self.write_int(Sign::Positive, *uint, f),
+            DynSolValue::FixedBytes(word, size) => {
+                f.write_str(&hex::encode_prefixed(&word[..*size]))
+            }
+            DynSolValue::Address(address) if self.checksum => {
+                f.write_str(&address.to_checksum(None))
+            }
+            DynSolValue::Address(address) => f.write_str(&hex::encode_prefixed(address)),
+            DynSolValue::Function(function) => f.write_str(&hex::encode_prefixed(function)),
+            DynSolValue::Bytes(bytes) => f.write_str(&hex::encode_prefixed(bytes)),
+            DynSolValue::String(s) => self.write_string(s, f),
+            DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+                self.write_list(values, ('[', ']'), f, indent)
+            }
+            as_tuple!(DynSolValue values) => self.write_list(values, ('(', ')'), f, indent),
+        }
+    }
+
+    fn write_int(&self, sign: Sign, abs: U256, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if sign.is_negative() {
+            f.write_char('-')?;
+        }
+        if let Some(unit) = self.unit {
+            let decimals = unit.decimals();
+            let (int, fract) = abs.div_rem(U256::from(10).pow(U256::from(decimals)));
+            write!(f, "{int}")?;
+            if fract != U256::ZERO {
+                let fract = format!("{:0>decimals$}", fract.to_string());
+                write!(f, ".{}", fract.trim_end_matches('0'))?;
+            }
+            write!(f, " {unit}")
+        } else if self.hex_ints {
+            f.write_str(&hex_uint(abs))
+        } else {
+            write!(f, "{abs}")
+        }
+    }
+
+    fn write_string(&self, s: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.escape_strings {
+            let quote = if s.contains('"') && !s.contains('\'') { '\'' } else { '"' };
+            return write!(f, "{quote}{s}{quote}");
+        }
+
+        f.write_char('"')?;
+        for c in s.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_ascii_control() => write!(f, "\\x{:02x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+
+    fn write_list(
+        &self,
+        values: &[DynSolValue],
+        (open, close): (char, char),
+        f: &mut fmt::Formatter<'_>,
+        indent: usize,
+    ) -> fmt::Result {
+        let multiline = self.pretty && values.iter().any(is_nested);
+        f.write_char(open)?;
+        for (i, value) in values.iter().enumerate() {
+            if multiline {
+                if i > 0 {
+                    f.write_char(',')?;
+                }
+                write!(f, "\n{:1$}", "", (indent + 1) * 4)?;
+            } else if i > 0 {
+                f.write_str(", ")?;
+            }
+            self.write(value, f, indent + 1)?;
+        }
+        if multiline {
+            write!(f, "\n{:1$}", "", indent * 4)?;
+        }
+        f.write_char(close)
+    }
+}
+
+/// Returns `true` if the value is a non-empty array or tuple.
+fn is_nested(value: &DynSolValue) -> bool {
+    match value {
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => !values.is_empty(),
+        as_tuple!(DynSolValue values) => !values.is_empty(),
+        _ => false,
+    }
+}
+
+/// Formats an integer as a `0x`-prefixed hex string, without leading zeros.
+pub(crate) fn hex_uint(uint: U256) -> String {
+    let s = format!("{uint:x}");
+    let trimmed = s.trim_start_matches('0');
+    format!("0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+}
+
+/// Formats a [`DynSolValue`] as a Solidity literal.
+///
+/// Created by [`DynSolValue::display_sol`].
+#[derive(Clone, Copy, Debug)]
+pub struct SolDisplay<'a> {
+    value: &'a DynSolValue,
+    options: SolFormatOptions,
+}
+
+impl fmt::Display for SolDisplay<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.options.write(self.value, f, 0)
+    }
+}
+
+impl DynSolValue {
+    /// Formats this value as a Solidity literal, with the default
+    /// [`SolFormatOptions`].
+    ///
+    /// This is the inverse of
+    /// [`DynSolType::coerce_str`](crate::DynSolType::coerce_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    ///
+    /// let ty: DynSolType = "(uint256,string)[]".parse()?;
+    /// let value = ty.coerce_str("[(0, \"hello\"), (42, 'say \"hi\"')]")?;
+    /// let s = value.format_sol();
+    /// assert_eq!(s, r#"[(0, "hello"), (42, "say \"hi\"")]"#);
+    /// assert_eq!(ty.coerce_str(&s)?, value);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    #[inline]
+    pub fn format_sol(&self) -> String {
+        self.display_sol(SolFormatOptions::new()).to_string()
+    }
+
+    /// Returns a [`Display`](fmt::Display) adaptor which formats this value
+    /// as a Solidity literal with the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue, EtherUnit, SolFormatOptions};
+    ///
+    /// let ty: DynSolType = "(address,uint256[])".parse()?;
+    /// let value =
+    ///     ty.coerce_str("(0xd8da6bf26964af9d7eed9e03e53415d37aa96045, [1.5 ether, 100 gwei])")?;
+    ///
+    /// let options = SolFormatOptions::new().unit(Some(EtherUnit::Ether)).pretty(true);
+    /// let s = value.display_sol(options).to_string();
+    /// assert_eq!(
+    ///     s,
+    ///     "(
+    ///     0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045,
+    ///     [1.5 ether, 0.0000001 ether]
+    /// )"
+    /// );
+    /// assert_eq!(ty.coerce_str(&s)?, value);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    #[inline]
+    pub const fn display_sol(&self, options: SolFormatOptions) -> SolDisplay<'_> {
+        SolDisplay { value: self, options }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynSolType;
+    use alloy_primitives::{Address, I256};
+
+    #[track_caller]
+    fn roundtrip(ty: &str, value: DynSolValue, options: SolFormatOptions) -> String {
+        let ty: DynSolType = ty.parse().unwrap();
+        let s = value.display_sol(options).to_string();
+        assert_eq!(ty.coerce_str(&s), Ok(value), "{s}");
+        s
+    }
+
+    #[test]
+    fn format_ints() {
+        let default = SolFormatOptions::new();
+        let hex = default.hex_ints(true);
+        let ether = default.unit(Some(EtherUnit::Ether));
+        let gwei = default.unit(Some(EtherUnit::Gwei));
+
+        let uint = |x: U256| DynSolValue::Uint(x, 256);
+        let int = |x: I256| DynSolValue::Int(x, 256);
+
+        assert_eq!(roundtrip("uint256", uint(U256::from(1000)), default), "1000");
+        assert_eq!(roundtrip("uint256", uint(U256::from(1000)), hex), "0x3e8");
+        assert_eq!(roundtrip("uint256", uint(U256::ZERO), hex), "0x0");
+        assert_eq!(roundtrip("uint256", uint(U256::MAX), default), U256::MAX.to_string());
+        assert_eq!(roundtrip("int256", int(I256::MINUS_ONE), hex), "-0x1");
+        assert_eq!(roundtrip("int256", int(I256::MIN), default), I256::MIN.to_string());
+
+        let amount = U256::from(1_500_000_000_000_000_001u64);
+        assert_eq!(roundtrip("uint256", uint(amount), ether), "1.500000000000000001 ether");
+        assert_eq!(roundtrip("uint256", uint(amount), gwei), "1500000000.000000001 gwei");
+        assert_eq!(
+            roundtrip("uint256", uint(U256::from(10).pow(U256::from(18))), ether),
+            "1 ether"
+        );
+        assert_eq!(roundtrip("uint256", uint(U256::ZERO), ether), "0 ether");
+        roundtrip("uint256", uint(U256::MAX), ether);
+        assert_eq!(
+            roundtrip("int256", int(I256::try_from(-1_500_000_000i64).unwrap()), gwei),
+            "-1.5 gwei"
+        );
+    }
+
+    #[test]
+    fn format_strings() {
+        let string = |s: &str| DynSolValue::String(s.into());
+        let default = SolFormatOptions::new();
+        assert_eq!(roundtrip("string", string("hello"), default), r#""hello""#);
+        assert_eq!(roundtrip("string", string(""), default), r#""""#);
+        assert_eq!(
+            roundtrip("string", string("a\"b'c\\d\n\u{7}é"), default),
+            r#""a\"b'c\\d\n\x07é""#
+        );
+        assert_eq!(
+            roundtrip("string[]", DynSolValue::Array(vec![string("a, b]")]), default),
+            r#"["a, b]"]"#
+        );
+
+        let raw = default.escape_strings(false);
+        assert_eq!(roundtrip("string", string("say \"hi\""), raw), r#"'say "hi"'"#);
+        assert_eq!(string("a\nb").display_sol(raw).to_string(), "\"a\nb\"");
+    }
+
+    #[test]
+    fn format_nested() {
+        let ty = "(address,bytes4,bytes,bool,(uint8,string)[],uint8[][2],())";
+        let value = DynSolValue::Tuple(vec![
+            Address::with_last_byte(0xab).into(),
+            DynSolValue::FixedBytes(crate::Word::right_padding_from(&[0x11; 4]), 4),
+            DynSolValue::Bytes(vec![]),
+            true.into(),
+            DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(1), 8),
+                DynSolValue::String("x".into()),
+            ])]),
+            DynSolValue::FixedArray(vec![
+                DynSolValue::Array(vec![]),
+                DynSolValue::Array(vec![
+                    DynSolValue::Uint(U256::from(2), 8),
+                    DynSolValue::Uint(U256::from(3), 8),
+                ]),
+            ]),
+            DynSolValue::Tuple(vec![]),
+        ]);
+
+        assert_eq!(
+            roundtrip(ty, value.clone(), SolFormatOptions::new().checksum(false)),
+            r#"(0x00000000000000000000000000000000000000ab, 0x11111111, 0x, true, [(1, "x")], [[], [2, 3]], ())"#
+        );
+        assert_eq!(
+            roundtrip(ty, value, SolFormatOptions::new().pretty(true)),
+            r#"(
+    0x00000000000000000000000000000000000000AB,
+    0x11111111,
+    0x,
+    true,
+    [
+        (1, "x")
+    ],
+    [
+        [],
+        [2, 3]
+    ],
+    ()
+)"#
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use crate::DynSolType;
+use alloc::{boxed::Box, vec::Vec};
+use alloy_sol_types::{abi::Decoder, Word};
+
+/// The maximum nesting depth of guessed dynamic types.
+const MAX_DEPTH: usize = 8;
+
+/// The maximum number of head layouts and tails tried for a single guess,
+/// shared by all the nested values.
+const MAX_SEARCH: usize = 1024;
+
+impl DynSolType {
+    /// Guesses the types of ABI-encoded parameters, without a selector, such
+    /// as the calldata of an unknown function after its first 4 bytes.
+    ///
+    /// This walks the head of the encoding looking for plausible offsets to
+    /// dynamic values, whose tails are then guessed from their lengths and
+    /// contents. The words which are not offsets are guessed as:
+    /// - `bool`, if they are `0` or `1`;
+    /// - `address`, if they have exactly 12 leading zero bytes;
+    /// - `uint256` or `int256`, if they are small positive or negative numbers;
+    /// - `bytes32` otherwise.
+    ///
+    /// Tails are guessed as `string`, `bytes`, arrays of static values or
+    /// tuples, or arrays of dynamic values, nested up to a limited depth.
+    ///
+    /// The guessed type is a tuple which is guaranteed to decode the data and
+    /// re-encode to exactly the same bytes. Returns `None` if the length of
+    /// the data is not a multiple of 32.
+    ///
+    /// This is a heuristic, and will often be wrong: for example, `0` is
+    /// always guessed as `false`, and a `uint256` which happens to look like
+    /// an offset may be guessed as a dynamic value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::{Address, U256};
+    ///
+    /// let args = DynSolValue::Tuple(vec![
+    ///     DynSolValue::Address(Address::repeat_byte(0x11)),
+    ///     DynSolValue::Array(vec![
+    ///         DynSolValue::Uint(U256::from(100), 256),
+    ///         DynSolValue::Uint(U256::from(200), 256),
+    ///     ]),
+    ///     DynSolValue::String("hello".into()),
+    /// ]);
+    /// let calldata = [&[0x12, 0x34, 0x56, 0x78], &args.abi_encode_params()[..]].concat();
+    ///
+    /// let ty = DynSolType::guess_params(&calldata[4..]).unwrap();
+    /// assert_eq!(ty.to_string(), "(address,uint256[],string)");
+    /// assert_eq!(ty.abi_decode_params(&calldata[4..])?, args);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn guess_params(data: &[u8]) -> Option<Self> {
+        if data.len() % 32 != 0 {
+            return None;
+        }
+        // the static words always re-encode to the same data
+        let mut budget = MAX_SEARCH;
+        [guess_tuple(data, 0, &mut budget), Some(data.chunks(32).map(guess_word).collect())]
+            .into_iter()
+            .flatten()
+            .map(Self::Tuple)
+            .find(|ty| {
+                ty.abi_decode_params(data).map_or(false, |value| value.abi_encode_params() == data)
+            })
+    }
+}
+
+/// Guesses the type of a single static word.
+fn guess_word(word: &[u8]) -> DynSolType {
+    let word = Word::from_slice(word);
+    if word[..31].iter().all(|&b| b == 0) && word[31] <= 1 {
+        DynSolType::Bool
+    } else if word[..12].iter().all(|&b| b == 0) && word[12..16].iter().any(|&b| b != 0) {
+        DynSolType::Address
+    } else if word[..16].iter().all(|&b| b == 0) {
+        DynSolType::Uint(256)
+    } else if word[..16].iter().all(|&b| b == 0xff) {
+        DynSolType::Int(256)
+    } else {
+        DynSolType::FixedBytes(32)
+    }
+}
+
+/// Guesses the types of a tuple with at least one dynamic member.
+fn guess_tuple(data: &[u8], depth: usize, budget: &mut usize) -> Option<Vec<DynSolType>> {
+    if depth > MAX_DEPTH || data.len() % 32 != 0 {
+        return None;
+    }
+    let decoder = Decoder::new(data, false);
+    // the first offset points right after the head
+    (0..data.len() / 32).find_map(|first| {
+        let head = decoder.peek_offset_at(first * 32).ok()?;
+        if head % 32 != 0 || head <= first * 32 || head >= data.len() {
+            return None;
+        }
+        guess_head(data, &decoder, first, head, depth, budget)
+    })
+}
+
+/// Guesses the types of a tuple whose head ends at `head`, with its first
+/// offset at word `first`.
+fn guess_head(
+    data: &[u8],
+    decoder: &Decoder<'_>,
+    first: usize,
+    head: usize,
+    depth: usize,
+    budget: &mut usize,
+) -> Option<Vec<DynSolType>> {
+    let candidates: Vec<_> = (first + 1..head / 32)
+        .filter_map(|i| {
+            let offset = decoder.peek_offset_at(i * 32).ok()?;
+            (offset % 32 == 0 && offset > head && offset < data.len()).then_some((i, offset))
+        })
+        .collect();
+    let mut types: Vec<_> = data[..head].chunks(32).map(guess_word).collect();
+    search(data, &candidates, (first, head), &mut types, depth, budget).then_some(types)
+}
+
+/// Searches for the words in `candidates` which are offsets, given that the
+/// tail of the word `last.0` starts at `last.1`. Every offset must be larger
+/// than the previous one, and the tails between them must be guessable.
+fn search(
+    data: &[u8],
+    candidates: &[(usize, usize)],
+    last: (usize, usize),
+    types: &mut [DynSolType],
+    depth: usize,
+    budget: &mut usize,
+) -> bool {
+    if !spend(budget) {
+        return false;
+    }
+
+    let Some((&(i, offset), rest)) = candidates.split_first() else {
+        return match guess_tail(&data[last.1..], depth + 1, budget) {
+            Some(ty) => {
+                types[last.0] = ty;
+                true
+            }
+            None => false,
+        };
+    };
+    if offset > last.1 {
+        if let Some(ty) = guess_tail(&data[last.1..offset], depth + 1, budget) {
+            if search(data, rest, (i, offset), types, depth, budget) {
+                types[last.0] = ty;
+                return true;
+            }
+        }
+    }
+    // the word is static after all
+    search(data, rest, last, types, depth, budget)
+}
+
+/// Consumes one unit of the search budget, returning `false` if it has run out.
+fn spend(budget: &mut usize) -> bool {
+    match budget.checked_sub(1) {
+        Some(rest) => {
+            *budget = rest;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Guesses the type of a dynamic value from its tail.
+fn guess_tail(tail: &[u8], depth: usize, budget: &mut usize) -> Option<DynSolType> {
+    if depth > MAX_DEPTH || !spend(budget) {
+        return None;
+    }
+    let decoder = Decoder::new(tail, false);
+    let len = decoder.peek_offset_at(0).ok()?;
+    let content = &tail[32..];
+    if len > content.len() {
+        return guess_tuple(tail, depth, budget).map(DynSolType::Tuple);
+    }
+
+    // `bytes` or `string`, padded with zeros
+    if content.len() == (len + 31) / 32 * 32 && content[len..].iter().all(|&b| b == 0) {
+        let is_text = |s: &str| !s.contains(|c: char| c.is_control() && !c.is_whitespace());
+        return Some(match core::str::from_utf8(&content[..len]) {
+            Ok(s) if len > 0 && is_text(s) => DynSolType::String,
+            _ => DynSolType::Bytes,
+        });
+    }
+
+    // an array of dynamic values, with their offsets in the head
+    if let Some(elements) = guess_dynamic_elements(content, len, depth, budget) {
+        return Some(DynSolType::Array(Box::new(elements)));
+    }
+
+    // an array of static values or tuples
+    if len > 0 && content.len() % (len * 32) == 0 {
+        let words = content.len() / (len * 32);
+        let mut columns: Vec<Vec<_>> = vec![Vec::with_capacity(len); words];
+        for (i, word) in content.chunks(32).enumerate() {
+            columns[i % words].push(guess_word(word));
+        }
+        let mut types = columns
+            .into_iter()
+            .map(|column| column.into_iter().map(Some).reduce(|a, b| unify(a?, b?)).flatten());
+        let ty = match words {
+            1 => types.next()??,
+            _ => DynSolType::Tuple(types.collect::<Option<_>>()?),
+        };
+        return Some(DynSolType::Array(Box::new(ty)));
+    }
+
+    // a tuple with dynamic members
+    guess_tuple(tail, depth, budget).map(DynSolType::Tuple)
+}
+
+/// Guesses the type of the elements of a dynamic array of `len` dynamic
+/// values.
+fn guess_dynamic_elements(
+    content: &[u8],
+    len: usize,
+    depth: usize,
+    budget: &mut usize,
+) -> Option<DynSolType> {
+    let decoder = Decoder::new(content, false);
+    let mut last = None;
+    let offsets = (0..len)
+        .map(|i| {
+            let offset = decoder.peek_offset_at(i * 32).ok()?;
+            let valid = match last {
+                None => offset == len * 32,
+                Some(last) => offset > last,
+            };
+            last = Some(offset);
+            (valid && offset < content.len()).then_some(offset)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let elements = offsets.iter().enumerate().map(|(k, &start)| {
+        let end = offsets.get(k + 1).copied().unwrap_or(content.len());
+        guess_tail(&content[start..end], depth + 1, budget)
+    });
+    elements.reduce(|a, b| unify(a?, b?))?
+}
+
+/// Returns a type which can hold the values of both types, if any.
+fn unify(a: DynSolType, b: DynSolType) -> Option<DynSolType> {
+    // each static word type can hold all the values of the previous ones
+    const fn rank(ty: &DynSolType) -> Option<u8> {
+        match ty {
+            DynSolType::Bool => Some(0),
+            DynSolType::Address => Some(1),
+            DynSolType::Uint(256) => Some(2),
+            DynSolType::Int(256) => Some(3),
+            DynSolType::FixedBytes(32) => Some(4),
+            _ => None,
+        }
+    }
+
+    if a == b {
+        return Some(a);
+    }
+    match (a, b) {
+        (a, b) if rank(&a).is_some() && rank(&b).is_some() => {
+            Some(if rank(&a) > rank(&b) { a } else { b })
+        }
+        (DynSolType::String | DynSolType::Bytes, DynSolType::String | DynSolType::Bytes) => {
+            Some(DynSolType::Bytes)
+        }
+        (DynSolType::Array(a), DynSolType::Array(b)) => {
+            unify(*a, *b).map(|ty| DynSolType::Array(Box::new(ty)))
+        }
+        (DynSolType::Tuple(a), DynSolType::Tuple(b)) if a.len() == b.len() => {
+            let types = a.into_iter().zip(b).map(|(a, b)| unify(a, b));
+            types.collect::<Option<_>>().map(DynSolType::Tuple)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynSolValue;
+    use alloc::string::String;
+    use alloy_primitives::{Address, I256, U256};
+
+    #[track_caller]
+    fn guess(value: DynSolValue) -> String {
+        let data = value.abi_encode_params();
+        let ty = DynSolType::guess_params(&data).unwrap();
+        assert_eq!(ty.abi_decode_params(&data).unwrap().abi_encode_params(), data);
+        ty.to_string()
+    }
+
+    #[test]
+    fn static_words() {
+        assert_eq!(DynSolType::guess_params(&[]), Some(DynSolType::Tuple(vec![])));
+        assert_eq!(DynSolType::guess_params(&[0; 31]), None);
+
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::repeat_byte(0xab)),
+            U256::from(1_000_000).into(),
+            DynSolValue::Bool(true),
+            DynSolValue::Int(I256::MINUS_ONE, 256),
+            DynSolValue::FixedBytes(Word::repeat_byte(0x42), 32),
+        ]);
+        assert_eq!(guess(value), "(address,uint256,bool,int256,bytes32)");
+    }
+
+    #[test]
+    fn dynamic() {
+        let value = DynSolValue::Tuple(vec![
+            U256::from(7).into(),
+            DynSolValue::String("hello world".into()),
+            DynSolValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0xff]),
+            DynSolValue::Array(vec![
+                DynSolValue::Address(Address::repeat_byte(0x11)),
+                DynSolValue::Address(Address::repeat_byte(0x22)),
+            ]),
+        ]);
+        assert_eq!(guess(value), "(uint256,string,bytes,address[])");
+
+        // nested arrays and arrays of tuples
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Array(vec![
+                DynSolValue::Array(vec![U256::from(2).into(), U256::from(3).into()]),
+                DynSolValue::Array(vec![U256::from(4).into()]),
+            ]),
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Address(Address::repeat_byte(0x11)),
+                    U256::from(5).into(),
+                ]),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Address(Address::repeat_byte(0x22)),
+                    U256::from(6).into(),
+                ]),
+            ]),
+            DynSolValue::Array(vec![
+                DynSolValue::String("a".into()),
+                DynSolValue::Bytes(vec![0xff; 40]),
+            ]),
+        ]);
+        assert_eq!(guess(value), "(uint256[][],(address,uint256)[],bytes[])");
+
+        // dynamic tuples
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Tuple(vec![U256::from(2).into(), DynSolValue::String("inner".into())]),
+            U256::from(9).into(),
+        ]);
+        assert_eq!(guess(value), "((uint256,string),uint256)");
+    }
+
+    #[test]
+    fn fake_offsets() {
+        // `64` looks like an offset past the head
+        let value = DynSolValue::Tuple(vec![
+            U256::from(64).into(),
+            U256::from(3).into(),
+            U256::from(5).into(),
+        ]);
+        assert_eq!(guess(value), "(uint256,uint256,uint256)");
+
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::String("abc".into()),
+            U256::from(192).into(),
+            DynSolValue::Bytes(vec![1, 2, 3]),
+        ]);
+        assert_eq!(guess(value), "(string,uint256,bytes)");
+    }
+
+    #[test]
+    fn search_budget() {
+        // every word looks like an offset or a length, at every depth
+        for n in [16, 64, 256] {
+            let data: Vec<u8> =
+                (1..=n).flat_map(|i| U256::from(i * 32).to_be_bytes::<32>()).collect();
+            let ty = DynSolType::guess_params(&data).unwrap();
+            assert_eq!(ty.abi_decode_params(&data).unwrap().abi_encode_params(), data);
+        }
+    }
+}
@@ -0,0 +1,599 @@
+use crate::{
+    format::hex_uint, ty::as_tuple, DynSolType, DynSolValue, Error, ResolveSolType, Result, Word,
+};
+use alloc::vec::Vec;
+use alloy_json_abi::Param;
+use alloy_primitives::{I256, U256};
+use core::iter::zip;
+use serde_json::Value;
+
+/// The JSON representation of integers used by
+/// [`DynSolValue::to_json_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JsonIntFormat {
+    /// Decimal strings, e.g. `"1000"` or `"-1"`. This is the default, as
+    /// JSON numbers may not be able to represent all Solidity integers
+    /// losslessly.
+    #[default]
+    Decimal,
+    /// `0x`-prefixed hexadecimal strings, e.g. `"0x3e8"` or `"-0x1"`.
+    Hex,
+    /// JSON numbers if the value fits in a 64-bit integer, decimal strings
+    /// otherwise.
+    Number,
+}
+
+impl JsonIntFormat {
+    fn int(self, int: I256) -> Value {
+        match self {
+            Self::Decimal => int.to_string().into(),
+            Self::Hex if int.is_negative() => format!("-{}", hex_uint(int.unsigned_abs())).into(),
+            Self::Hex => hex_uint(int.into_raw()).into(),
+            Self::Number => i64::try_from(int).map_or_else(|_| int.to_string().into(), Into::into),
+        }
+    }
+
+    fn uint(self, uint: U256) -> Value {
+        match self {
+            Self::Decimal => uint.to_string().into(),
+            Self::Hex => hex_uint(uint).into(),
+            Self::Number => {
+                u64::try_from(uint).map_or_else(|_| uint.to_string().into(), Into::into)
+            }
+        }
+    }
+}
+
+impl DynSolType {
+    /// Coerces a [`serde_json::Value`] to a [`DynSolValue`] via this type.
+    ///
+    /// Requires the `serde_json` feature, which is enabled by default.
+    ///
+    /// # Format
+    ///
+    /// - [`Bool`](DynSolType::Bool): a boolean, or `"true"` or `"false"`
+    /// - [`Int`](DynSolType::Int) and [`Uint`](DynSolType::Uint): an integer number, or a string in
+    ///   any of the formats accepted by [`coerce_str`](DynSolType::coerce_str), such as decimal or
+    ///   `0x`-prefixed hexadecimal. Prefer strings for values that may not fit in a 64-bit integer,
+    ///   as JSON numbers are not guaranteed to be represented losslessly
+    /// - [`FixedBytes`](DynSolType::FixedBytes): a hex string, which is right-padded with zeros or
+    ///   truncated to the type's length
+    /// - [`Address`](DynSolType::Address), [`Function`](DynSolType::Function) and
+    ///   [`Bytes`](DynSolType::Bytes): a hex string
+    /// - [`String`](DynSolType::String): a string
+    /// - [`Array`](DynSolType::Array) and [`FixedArray`](DynSolType::FixedArray): an array of the
+    ///   inner type
+    /// - [`Tuple`](DynSolType::Tuple): an array of the component types
+    #[cfg_attr(
+        feature = "eip712",
+        doc = "- [`CustomStruct`](DynSolType::CustomStruct): an array of the component types, or an \
+               object keyed by the property names"
+    )]
+    ///
+    /// Plain tuples don't have component names. To coerce objects keyed by
+    /// the component names of a JSON ABI item's parameters, use
+    /// [`JsonAbiExt::coerce_json_input`](crate::JsonAbiExt::coerce_json_input).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::{Address, U256};
+    /// use serde_json::json;
+    ///
+    /// let ty: DynSolType = "(uint256,address,bytes)[]".parse()?;
+    /// let value = ty.coerce_json(&json!([
+    ///     [42, "0x0000000000000000000000000000000000000001", "0x1234"],
+    ///     ["0x100", "0x0000000000000000000000000000000000000002", "0x"],
+    /// ]))?;
+    /// assert_eq!(
+    ///     value,
+    ///     DynSolValue::Array(vec![
+    ///         DynSolValue::Tuple(vec![
+    ///             U256::from(42).into(),
+    ///             Address::with_last_byte(1).into(),
+    ///             vec![0x12, 0x34].into(),
+    ///         ]),
+    ///         DynSolValue::Tuple(vec![
+    ///             U256::from(256).into(),
+    ///             Address::with_last_byte(2).into(),
+    ///             Vec::<u8>::new().into(),
+    ///         ]),
+    ///     ])
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn coerce_json(&self, value: &Value) -> Result<DynSolValue> {
+        let err = || Error::json_coerce(self, value);
+        match self {
+            Self::Bool => bool(value).map(DynSolValue::Bool).ok_or_else(err),
+            &Self::Int(n) => match value {
+                Value::String(s) => self.coerce_str(s),
+                value => int(n, value).map(|x| DynSolValue::Int(x, n)).ok_or_else(err),
+            },
+            &Self::Uint(n) => match value {
+                Value::String(s) => self.coerce_str(s),
+                value => uint(n, value).map(|x| DynSolValue::Uint(x, n)).ok_or_else(err),
+            },
+            &Self::FixedBytes(n) => {
+                fixed_bytes(n, value).map(|x| DynSolValue::FixedBytes(x, n)).ok_or_else(err)
+            }
+            Self::Address | Self::Function | Self::Bytes => {
+                value.as_str().ok_or_else(err).and_then(|s| self.coerce_str(s))
+            }
+            Self::String => value.as_str().map(|s| DynSolValue::String(s.into())).ok_or_else(err),
+
+            Self::Array(inner) => value
+                .as_array()
+                .ok_or_else(err)?
+                .iter()
+                .map(|v| inner.coerce_json(v))
+                .collect::<Result<_>>()
+                .map(DynSolValue::Array),
+            Self::FixedArray(inner, n) => value
+                .as_array()
+                .filter(|arr| arr.len() == *n)
+                .ok_or_else(err)?
+                .iter()
+                .map(|v| inner.coerce_json(v))
+                .collect::<Result<_>>()
+                .map(DynSolValue::FixedArray),
+            Self::Tuple(types) => value
+                .as_array()
+                .filter(|arr| arr.len() == types.len())
+                .ok_or_else(err)
+                .and_then(|arr| zip(types, arr).map(|(t, v)| t.coerce_json(v)).collect())
+                .map(DynSolValue::Tuple),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => {
+                let values =
+                    fields(prop_names.iter().map(String::as_str), value).ok_or_else(err)?;
+                Ok(DynSolValue::CustomStruct {
+                    name: name.clone(),
+                    prop_names: prop_names.clone(),
+                    tuple: zip(tuple, values)
+                        .map(|(t, v)| t.coerce_json(v))
+                        .collect::<Result<_>>()?,
+                })
+            }
+        }
+    }
+}
+
+impl DynSolValue {
+    /// Serializes this value to JSON according to the given type, with
+    /// integers as decimal strings.
+    ///
+    /// See [`to_json_with`](Self::to_json_with) for more details.
+    ///
+    /// Requires the `serde_json` feature, which is enabled by default.
+    #[inline]
+    pub fn to_json(&self, ty: &DynSolType) -> Result<Value> {
+        self.to_json_with(ty, JsonIntFormat::default())
+    }
+
+    /// Serializes this value to JSON according to the given type, with
+    /// integers in the given format.
+    ///
+    /// This is the inverse of [`DynSolType::coerce_json`]: bytes are
+    /// serialized as `0x`-prefixed hex strings, addresses as checksummed hex
+    /// strings, and arrays and tuples as arrays.
+    #[cfg_attr(
+        feature = "eip712",
+        doc = "\nValues of [`CustomStruct`](DynSolType::CustomStruct) types are serialized as \
+               objects keyed by the property names of the type."
+    )]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value does not match the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue, JsonIntFormat};
+    /// use alloy_primitives::U256;
+    /// use serde_json::json;
+    ///
+    /// let ty: DynSolType = "(uint256,int8,bytes2)".parse()?;
+    /// let value = ty.coerce_json(&json!(["1000", -1, "0x1234"]))?;
+    /// assert_eq!(value.to_json(&ty)?, json!(["1000", "-1", "0x1234"]));
+    /// assert_eq!(value.to_json_with(&ty, JsonIntFormat::Hex)?, json!(["0x3e8", "-0x1", "0x1234"]));
+    /// assert_eq!(value.to_json_with(&ty, JsonIntFormat::Number)?, json!([1000, -1, "0x1234"]));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn to_json_with(&self, ty: &DynSolType, format: JsonIntFormat) -> Result<Value> {
+        if !ty.matches(self) {
+            return Err(Error::TypeMismatch {
+                expected: ty.sol_type_name().into_owned(),
+                actual: self.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
+            });
+        }
+        Ok(self.to_json_unchecked(ty, format))
+    }
+
+    fn to_json_unchecked(&self, ty: &DynSolType, format: JsonIntFormat) -> Value {
+        match (self, ty) {
+            (Self::Bool(b), _) => Value::Bool(*b),
+            (Self::Int(i, _), _) => format.int(*i),
+            (Self::Uint(u, _), _) => format.uint(*u),
+            (Self::FixedBytes(word, n), _) => hex::encode_prefixed(&word[..*n]).into(),
+            (Self::Address(address), _) => address.to_checksum(None).into(),
+            (Self::Function(function), _) => hex::encode_prefixed(function).into(),
+            (Self::Bytes(bytes), _) => hex::encode_prefixed(bytes).into(),
+            (Self::String(s), _) => s.as_str().into(),
+            (
+                Self::Array(values) | Self::FixedArray(values),
+                DynSolType::Array(ty) | DynSolType::FixedArray(ty, _),
+            ) => values.iter().map(|v| v.to_json_unchecked(ty, format)).collect(),
+            #[cfg(feature = "eip712")]
+            (as_tuple!(Self values), DynSolType::CustomStruct { prop_names, tuple, .. }) => {
+                let mut map = serde_json::Map::with_capacity(values.len());
+                for (name, (v, ty)) in zip(prop_names, zip(values, tuple)) {
+                    map.insert(name.clone(), v.to_json_unchecked(ty, format));
+                }
+                Value::Object(map)
+            }
+            (as_tuple!(Self values), DynSolType::Tuple(types)) => {
+                zip(values, types).map(|(v, ty)| v.to_json_unchecked(ty, format)).collect()
+            }
+            _ => unreachable!("value does not match type"),
+        }
+    }
+}
+
+/// Coerces JSON arguments for `params`, either an array of values or an
+/// object keyed by the parameter names.
+///
+/// Tuple components may also be given as objects keyed by their names.
+pub(crate) fn coerce_json_params(params: &[Param], value: &Value) -> Result<Vec<DynSolValue>> {
+    let values = fields(params.iter().map(|p| p.name.as_str()), value).ok_or_else(|| {
+        Error::TypeMismatch {
+            expected: format!("{} arguments", params.len()),
+            actual: value.to_string(),
+        }
+    })?;
+    zip(params, values).map(|(p, v)| coerce_named(&p.resolve()?, &p.components, v)).collect()
+}
+
+fn coerce_named(ty: &DynSolType, components: &[Param], value: &Value) -> Result<DynSolValue> {
+    if components.is_empty() {
+        return ty.coerce_json(value);
+    }
+
+    let err = || Error::json_coerce(ty, value);
+    match ty {
+        DynSolType::Array(inner) => value
+            .as_array()
+            .ok_or_else(err)?
+            .iter()
+            .map(|v| coerce_named(inner, components, v))
+            .collect::<Result<_>>()
+            .map(DynSolValue::Array),
+        DynSolType::FixedArray(inner, n) => value
+            .as_array()
+            .filter(|arr| arr.len() == *n)
+            .ok_or_else(err)?
+            .iter()
+            .map(|v| coerce_named(inner, components, v))
+            .collect::<Result<_>>()
+            .map(DynSolValue::FixedArray),
+        as_tuple!(DynSolType types) => {
+            let values =
+                fields(components.iter().map(|c| c.name.as_str()), value).ok_or_else(err)?;
+            let tuple = zip(zip(types, components), values)
+                .map(|((ty, c), v)| coerce_named(ty, &c.components, v))
+                .collect::<Result<_>>()?;
+            Ok(match ty {
+                #[cfg(feature = "eip712")]
+                DynSolType::CustomStruct { name, prop_names, .. } => DynSolValue::CustomStruct {
+                    name: name.clone(),
+                    prop_names: prop_names.clone(),
+                    tuple,
+                },
+                _ => DynSolValue::Tuple(tuple),
+            })
+        }
+        _ => ty.coerce_json(value),
+    }
+}
+
+/// Returns the values of the given fields, from either an array of the same
+/// length or an object keyed by the field names.
+fn fields<'a, 'b>(
+    names: impl ExactSizeIterator<Item = &'b str>,
+    value: &'a Value,
+) -> Option<Vec<&'a Value>> {
+    match value {
+        Value::Array(arr) if arr.len() == names.len() => Some(arr.iter().collect()),
+        Value::Object(map) => names.map(|name| map.get(name)).collect(),
+        _ => None,
+    }
+}
+
+fn bool(value: &Value) -> Option<bool> {
+    value.as_bool().or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+/// Returns the value of an integer JSON number.
+fn number(value: &Value) -> Option<I256> {
+    let n = value.as_number()?;
+    if let Some(n) = n.as_i64() {
+        return Some(I256::try_from(n).unwrap());
+    }
+    if let Some(n) = n.as_u64() {
+        return Some(I256::try_from(n).unwrap());
+    }
+    // only integers which are exactly representable
+    let f = n.as_f64()?;
+    (f.fract() == 0.0 && f.abs() <= (1u64 << 53) as f64).then(|| I256::try_from(f as i64).unwrap())
+}
+
+fn int(n: usize, value: &Value) -> Option<I256> {
+    number(value).filter(|x| x.bits() <= n as u32)
+}
+
+fn uint(n: usize, value: &Value) -> Option<U256> {
+    number(value).and_then(|x| U256::try_from(x).ok()).filter(|x| x.bit_len() <= n)
+}
+
+fn fixed_bytes(n: usize, value: &Value) -> Option<Word> {
+    let buf = hex::decode(value.as_str()?).ok()?;
+    let len = n.min(buf.len());
+    let mut word = Word::ZERO;
+    word[..len].copy_from_slice(&buf[..len]);
+    Some(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_abi::Function;
+    use alloy_primitives::{address, Address};
+    use serde_json::json;
+
+    #[test]
+    fn coerce_simple() {
+        let coerce = |ty: &str, v: Value| ty.parse::<DynSolType>().unwrap().coerce_json(&v);
+
+        assert_eq!(coerce("bool", json!(true)), Ok(true.into()));
+        assert_eq!(coerce("bool", json!("false")), Ok(false.into()));
+        assert_eq!(coerce("uint8", json!(255)), Ok(DynSolValue::Uint(U256::from(255), 8)));
+        assert_eq!(coerce("uint8", json!("0xff")), Ok(DynSolValue::Uint(U256::from(255), 8)));
+        assert_eq!(
+            coerce("uint256", json!(1e15)),
+            Ok(DynSolValue::Uint(U256::from(10u64.pow(15)), 256))
+        );
+        assert_eq!(
+            coerce("uint256", json!("1000000000000000000000")),
+            Ok(DynSolValue::Uint(U256::from(10).pow(U256::from(21)), 256))
+        );
+        assert_eq!(
+            coerce("int16", json!(-300)),
+            Ok(DynSolValue::Int(I256::try_from(-300).unwrap(), 16))
+        );
+        assert_eq!(
+            coerce("int16", json!("-300")),
+            Ok(DynSolValue::Int(I256::try_from(-300).unwrap(), 16))
+        );
+        assert_eq!(
+            coerce("bytes4", json!("0x1234")),
+            Ok(DynSolValue::FixedBytes(Word::right_padding_from(&[0x12, 0x34]), 4))
+        );
+        assert_eq!(coerce("bytes", json!("0x1234")), Ok(vec![0x12, 0x34].into()));
+        assert_eq!(coerce("string", json!("hello")), Ok(DynSolValue::String("hello".into())));
+        assert_eq!(
+            coerce("address", json!("0x0000000000000000000000000000000000000001")),
+            Ok(Address::with_last_byte(1).into())
+        );
+
+        coerce("uint8", json!(256)).unwrap_err();
+        coerce("uint8", json!(-1)).unwrap_err();
+        coerce("uint8", json!(1.5)).unwrap_err();
+        coerce("uint256", json!(1e18)).unwrap_err();
+        coerce("int8", json!(128)).unwrap_err();
+        coerce("string", json!(1)).unwrap_err();
+        coerce("bool", json!(1)).unwrap_err();
+        coerce("uint8[2]", json!([1])).unwrap_err();
+        coerce("(uint8,bool)", json!([1])).unwrap_err();
+        coerce("(uint8,bool)", json!({ "a": 1, "b": true })).unwrap_err();
+    }
+
+    // Longer fixed bytes are truncated on purpose: EIP-712 typed data has
+    // always been coerced this way, and it now uses this implementation.
+    #[test]
+    fn fixed_bytes_truncation() {
+        let coerce = |ty: &str, v: Value| ty.parse::<DynSolType>().unwrap().coerce_json(&v);
+
+        assert_eq!(
+            coerce("bytes2", json!("0x123456")),
+            Ok(DynSolValue::FixedBytes(Word::right_padding_from(&[0x12, 0x34]), 2))
+        );
+        assert_eq!(
+            coerce("bytes1", json!("0x1234")),
+            Ok(DynSolValue::FixedBytes(Word::right_padding_from(&[0x12]), 1))
+        );
+        coerce("bytes1", json!("0x123")).unwrap_err();
+    }
+
+    #[test]
+    fn roundtrip() {
+        let ty: DynSolType = "(bool,int256,uint64[],bytes3,address,string,bytes)".parse().unwrap();
+        let json = json!([
+            true,
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+            ["18446744073709551615", "0"],
+            "0x010203",
+            "0xdAC17F958D2ee523a2206206994597C13D831ec7",
+            "hello",
+            "0x",
+        ]);
+        let value = ty.coerce_json(&json).unwrap();
+        assert_eq!(value.to_json(&ty).unwrap(), json);
+        assert_eq!(
+            ty.coerce_json(&value.to_json_with(&ty, JsonIntFormat::Hex).unwrap()),
+            Ok(value.clone())
+        );
+        assert_eq!(
+            ty.coerce_json(&value.to_json_with(&ty, JsonIntFormat::Number).unwrap()),
+            Ok(value.clone())
+        );
+
+        assert_eq!(
+            value.to_json_with(&ty, JsonIntFormat::Number).unwrap()[2],
+            json!([18446744073709551615u64, 0])
+        );
+        value.to_json(&DynSolType::Bool).unwrap_err();
+    }
+
+    #[test]
+    fn named_params() {
+        let f = Function::parse(
+            "function fill((address maker, (uint256 amount, bytes32 salt)[] parts) order, bool partial)",
+        )
+        .unwrap();
+        let maker = address!("00000000000000000000000000000000000000aa");
+        let expected = vec![
+            DynSolValue::Tuple(vec![
+                maker.into(),
+                DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+                    U256::from(1).into(),
+                    DynSolValue::FixedBytes(Word::with_last_byte(2), 32),
+                ])]),
+            ]),
+            false.into(),
+        ];
+
+        let by_name = json!({
+            "partial": false,
+            "order": {
+                "maker": maker,
+                "parts": [{ "amount": 1, "salt": Word::with_last_byte(2) }],
+            },
+        });
+        assert_eq!(coerce_json_params(&f.inputs, &by_name), Ok(expected.clone()));
+
+        let by_position = json!([[maker, [[1, Word::with_last_byte(2)]]], false]);
+        assert_eq!(coerce_json_params(&f.inputs, &by_position), Ok(expected));
+
+        coerce_json_params(&f.inputs, &json!([1])).unwrap_err();
+        coerce_json_params(&f.inputs, &json!({ "order": by_name["order"] })).unwrap_err();
+    }
+
+    #[cfg(feature = "eip712")]
+    #[test]
+    fn custom_struct() {
+        use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+        let ty = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string(), "wallets".to_string()],
+            tuple: vec![DynSolType::String, DynSolType::Array(Box::new(DynSolType::Address))],
+        };
+        let json =
+            json!({ "name": "Cow", "wallets": ["0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"] });
+        let value = ty.coerce_json(&json).unwrap();
+        assert_eq!(value.to_json(&ty).unwrap(), json);
+        assert_eq!(ty.coerce_json(&json!(["Cow", json["wallets"]])), Ok(value));
+    }
+
+    #[cfg(feature = "eip712")]
+    #[test]
+    fn custom_struct_nested() {
+        use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+        let j = json!({
+            "message": {
+                "contents": "Hello, Bob!",
+                "attachedMoneyInEth": 4.2,
+                "from": {
+                    "name": "Cow",
+                    "wallets": [
+                        "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826",
+                        "0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF",
+                    ]
+                },
+                "to": [{
+                    "name": "Bob",
+                    "wallets": [
+                        "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+                        "0xB0BdaBea57B0BDABeA57b0bdABEA57b0BDabEa57",
+                        "0xB0B0b0b0b0b0B000000000000000000000000000",
+                    ]
+                }]
+            }
+        });
+
+        let ty = DynSolType::CustomStruct {
+            name: "Message".to_owned(),
+            prop_names: vec!["contents".to_string(), "from".to_string(), "to".to_string()],
+            tuple: vec![
+                DynSolType::String,
+                DynSolType::CustomStruct {
+                    name: "Person".to_owned(),
+                    prop_names: vec!["name".to_string(), "wallets".to_string()],
+                    tuple: vec![
+                        DynSolType::String,
+                        DynSolType::Array(Box::new(DynSolType::Address)),
+                    ],
+                },
+                DynSolType::Array(Box::new(DynSolType::CustomStruct {
+                    name: "Person".to_owned(),
+                    prop_names: vec!["name".to_string(), "wallets".to_string()],
+                    tuple: vec![
+                        DynSolType::String,
+                        DynSolType::Array(Box::new(DynSolType::Address)),
+                    ],
+                })),
+            ],
+        };
+        let top = j.as_object().unwrap().get("message").unwrap();
+
+        assert_eq!(
+            ty.coerce_json(top),
+            Ok(DynSolValue::CustomStruct {
+                name: "Message".to_owned(),
+                prop_names: vec!["contents".to_string(), "from".to_string(), "to".to_string()],
+                tuple: vec![
+                    DynSolValue::String("Hello, Bob!".to_string()),
+                    DynSolValue::CustomStruct {
+                        name: "Person".to_owned(),
+                        prop_names: vec!["name".to_string(), "wallets".to_string()],
+                        tuple: vec![
+                            DynSolValue::String("Cow".to_string()),
+                            vec![
+                                DynSolValue::Address(
+                                    "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826".parse().unwrap()
+                                ),
+                                DynSolValue::Address(
+                                    "0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF".parse().unwrap()
+                                ),
+                            ]
+                            .into()
+                        ]
+                    },
+                    vec![DynSolValue::CustomStruct {
+                        name: "Person".to_owned(),
+                        prop_names: vec!["name".to_string(), "wallets".to_string()],
+                        tuple: vec![
+                            DynSolValue::String("Bob".to_string()),
+                            vec![
+                                DynSolValue::Address(
+                                    "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB".parse().unwrap()
+                                ),
+                                DynSolValue::Address(
+                                    "0xB0BdaBea57B0BDABeA57b0bdABEA57b0BDabEa57".parse().unwrap()
+                                ),
+                                DynSolValue::Address(
+                                    "0xB0B0b0b0b0b0B000000000000000000000000000".parse().unwrap()
+                                ),
+                            ]
+                            .into()
+                        ]
+                    }]
+                    .into()
+                ]
+            })
+        );
+    }
+}
@@ -38,24 +38,63 @@ mod coerce;
 mod error;
 pub use error::{Error, Result};
 
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "serde")]
+pub use de::from_value;
+
+#[cfg(feature = "serde")]
+pub mod ser;
+#[cfg(feature = "serde")]
+pub use ser::to_value;
+
 mod ext;
-pub use ext::{EventExt, FunctionExt, JsonAbiExt};
+pub use ext::{
+    AbiDecodeExt, AbiDecoder, ContractObjectExt, DecodedCall, DecodedConstructor, DecodedLog,
+    DecodedRevert, EventExt, FunctionExt, JsonAbiExt,
+};
 
 mod event;
 pub use event::{DecodedEvent, DynSolEvent};
 
+mod format;
+pub use format::{EtherUnit, SolDisplay, SolFormatOptions};
+
+mod extract;
+
+mod guess;
+
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "serde_json")]
+pub use json::JsonIntFormat;
+
+mod signature_db;
+pub use signature_db::{
+    decode_log_with_db, decode_with_db, CallCandidate, EventCandidate, SignatureDb,
+};
+
 mod ty;
 pub use ty::DynSolType;
 
 mod value;
 pub use value::DynSolValue;
 
+mod value_ref;
+pub use value_ref::DynSolValueRef;
+
 mod token;
 pub use token::DynToken;
 
+mod named;
+pub use named::NamedValue;
+
 mod resolve;
 pub use resolve::{ResolveSolEvent, ResolveSolType};
 
+pub mod storage;
+pub use storage::StorageLayoutExt;
+
 #[cfg(feature = "eip712")]
 pub mod eip712;
 #[cfg(feature = "eip712")]
@@ -0,0 +1,503 @@
+use crate::{
+    extract::{parse_path, Segment},
+    ty::as_tuple,
+    DecodedEvent, DynSolType, DynSolValue, Error, ResolveSolType, Result,
+};
+use alloc::{string::String, vec::Vec};
+use alloy_json_abi::{Event, InternalType, Param};
+#[cfg(feature = "serde")]
+use alloy_primitives::hex;
+use alloy_sol_types::abi::Decoder;
+use core::iter::zip;
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// A decoded value annotated with the names from its JSON ABI parameter.
+///
+/// Every level of nesting is annotated: tuples have a component for each of
+/// their fields, named after the parameter's components, and arrays have a
+/// component for each of their elements, which are unnamed. Only the leaves
+/// hold their values; use [`to_value`](Self::to_value) to reassemble the
+/// value of a tuple or an array.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolValue, JsonAbiExt, NamedValue};
+/// use alloy_json_abi::Function;
+/// use alloy_primitives::{Address, U256};
+///
+/// let function = Function::parse("fill((address maker, uint256 amount)[] orders, bytes32 ref)")?;
+/// let order = DynSolValue::Tuple(vec![Address::with_last_byte(1).into(), U256::from(100).into()]);
+/// let args = [DynSolValue::Array(vec![order]), DynSolValue::FixedBytes(Default::default(), 32)];
+/// let data = function.abi_encode_input(&args)?;
+///
+/// let decoded = function.abi_decode_input_named(&data[4..], true)?;
+/// assert_eq!(decoded.name, "fill");
+/// let maker = decoded.get("orders[0].maker").unwrap();
+/// assert_eq!(maker.value, Some(DynSolValue::Address(Address::with_last_byte(1))));
+/// assert_eq!(decoded.to_value(), Some(DynSolValue::Tuple(args.to_vec())));
+/// # Ok::<(), alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedValue {
+    /// The name of the parameter or component. Empty if it is unnamed, and
+    /// for array elements.
+    pub name: String,
+    /// The name of the struct, from the parameter's internal type, for
+    /// structs and arrays of structs.
+    pub struct_name: Option<String>,
+    /// The type of the value.
+    pub ty: DynSolType,
+    /// The value, or `None` for tuples and arrays, whose values are held by
+    /// their components.
+    pub value: Option<DynSolValue>,
+    /// The fields of a tuple or the elements of an array, or empty for other
+    /// values.
+    pub components: Vec<NamedValue>,
+}
+
+/// Serializes tuples as objects keyed by their component names, using the
+/// component's index for unnamed ones, arrays as arrays, and other values like
+/// `DynSolValue::to_json`: integers as decimal strings, addresses as
+/// checksummed hex strings, and bytes as `0x`-prefixed hex strings.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+/// use alloy_json_abi::Function;
+/// use alloy_primitives::{Address, U256};
+/// use serde_json::json;
+///
+/// let function = Function::parse("fill((address maker, uint256 amount) order, uint256)")?;
+/// let order = DynSolValue::Tuple(vec![Address::with_last_byte(1).into(), U256::from(100).into()]);
+/// let data = function.abi_encode_input(&[order, U256::from(1).into()])?;
+///
+/// let decoded = function.abi_decode_input_named(&data[4..], true)?;
+/// assert_eq!(
+///     serde_json::to_value(&decoded)?,
+///     json!({
+///         "order": { "maker": "0x0000000000000000000000000000000000000001", "amount": "100" },
+///         "1": "1",
+///     })
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "serde")]
+impl Serialize for NamedValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.ty {
+            DynSolType::Array(_) | DynSolType::FixedArray(..) => {
+                let mut seq = serializer.serialize_seq(Some(self.components.len()))?;
+                for component in &self.components {
+                    seq.serialize_element(component)?;
+                }
+                seq.end()
+            }
+            as_tuple!(DynSolType _) => {
+                let mut map = serializer.serialize_map(Some(self.components.len()))?;
+                for (i, component) in self.components.iter().enumerate() {
+                    if component.name.is_empty() {
+                        map.serialize_entry(&i, component)?;
+                    } else {
+                        map.serialize_entry(&component.name, component)?;
+                    }
+                }
+                map.end()
+            }
+            _ => match &self.value {
+                Some(DynSolValue::Bool(b)) => serializer.serialize_bool(*b),
+                Some(DynSolValue::Int(i, _)) => serializer.collect_str(i),
+                Some(DynSolValue::Uint(u, _)) => serializer.collect_str(u),
+                Some(DynSolValue::FixedBytes(word, n)) => {
+                    serializer.serialize_str(&hex::encode_prefixed(&word[..*n]))
+                }
+                Some(DynSolValue::Address(address)) => {
+                    serializer.serialize_str(&address.to_checksum(None))
+                }
+                Some(DynSolValue::Function(function)) => {
+                    serializer.serialize_str(&hex::encode_prefixed(function))
+                }
+                Some(DynSolValue::Bytes(bytes)) => {
+                    serializer.serialize_str(&hex::encode_prefixed(bytes))
+                }
+                Some(DynSolValue::String(s)) => serializer.serialize_str(s),
+                Some(_) => Err(serde::ser::Error::custom("unexpected value for a leaf")),
+                None => Err(serde::ser::Error::custom("missing value")),
+            },
+        }
+    }
+}
+
+impl NamedValue {
+    /// Annotates a value with the names of the given parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter's type cannot be resolved, or if the
+    /// value does not match it.
+    pub fn new(param: &Param, value: DynSolValue) -> Result<Self> {
+        let ty = param.resolve()?;
+        if !ty.matches(&value) {
+            return Err(mismatch(&ty, &value));
+        }
+        Ok(Self::new_unchecked(
+            param.name.clone(),
+            struct_name(param.internal_type()),
+            &param.components,
+            ty,
+            value,
+        ))
+    }
+
+    /// ABI-decodes a single value of the given parameter's type, and
+    /// annotates it with the parameter's names.
+    ///
+    /// See [`DynSolType::abi_decode`] for more details.
+    pub fn abi_decode(param: &Param, data: &[u8], validate: bool) -> Result<Self> {
+        let ty = param.resolve()?;
+        let value = ty.abi_decode_inner(
+            &mut Decoder::new(data, validate),
+            crate::DynToken::decode_single_populate,
+        )?;
+        Ok(Self::new_unchecked(
+            param.name.clone(),
+            struct_name(param.internal_type()),
+            &param.components,
+            ty,
+            value,
+        ))
+    }
+
+    /// Annotates the values of a list of parameters, like the arguments of a
+    /// function, as the components of a tuple with the given name.
+    pub(crate) fn from_params(
+        name: &str,
+        params: &[Param],
+        values: Vec<DynSolValue>,
+    ) -> Result<Self> {
+        let types = params.iter().map(Param::resolve).collect::<Result<Vec<_>>>()?;
+        let ty = DynSolType::Tuple(types);
+        Ok(Self::new_unchecked(name.into(), None, params, ty, DynSolValue::Tuple(values)))
+    }
+
+    /// Annotates the decoded values of an event's parameters, in declaration
+    /// order, as the components of a tuple named after the event.
+    ///
+    /// Indexed parameters of non-value types are annotated as `bytes32`, as
+    /// only their hashes are stored in the topics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value does not match its parameter's type, or if
+    /// an indexed parameter of a non-value type is not a 32-byte hash.
+    pub(crate) fn from_event(event: &Event, decoded: DecodedEvent) -> Result<Self> {
+        let mut indexed = decoded.indexed.into_iter();
+        let mut body = decoded.body.into_iter();
+        let mut components = Vec::with_capacity(event.inputs.len());
+        for param in &event.inputs {
+            let value = if param.indexed { indexed.next() } else { body.next() };
+            let value = value.ok_or_else(|| Error::custom("missing decoded event value"))?;
+            let ty = param.resolve()?;
+            let hashed = param.indexed && !is_value_type(&ty);
+            let component = match value {
+                DynSolValue::FixedBytes(_, 32) if hashed => {
+                    let ty = DynSolType::FixedBytes(32);
+                    Self::new_unchecked(param.name.clone(), None, &[], ty, value)
+                }
+                _ if hashed => return Err(mismatch(&DynSolType::FixedBytes(32), &value)),
+                _ if ty.matches(&value) => {
+                    let struct_name = struct_name(param.internal_type());
+                    Self::new_unchecked(
+                        param.name.clone(),
+                        struct_name,
+                        &param.components,
+                        ty,
+                        value,
+                    )
+                }
+                _ => return Err(mismatch(&ty, &value)),
+            };
+            components.push(component);
+        }
+
+        let ty = DynSolType::Tuple(components.iter().map(|c| c.ty.clone()).collect());
+        Ok(Self { name: event.name.clone(), struct_name: None, ty, value: None, components })
+    }
+
+    /// Annotates a value, which must match the type, moving the values of
+    /// tuples and arrays into their components.
+    fn new_unchecked(
+        name: String,
+        struct_name: Option<String>,
+        params: &[Param],
+        ty: DynSolType,
+        value: DynSolValue,
+    ) -> Self {
+        let components = match (&ty, value) {
+            (
+                DynSolType::Array(inner) | DynSolType::FixedArray(inner, _),
+                DynSolValue::Array(values) | DynSolValue::FixedArray(values),
+            ) => values
+                .into_iter()
+                .map(|v| {
+                    Self::new_unchecked(
+                        String::new(),
+                        struct_name.clone(),
+                        params,
+                        (**inner).clone(),
+                        v,
+                    )
+                })
+                .collect(),
+            (as_tuple!(DynSolType types), as_tuple!(DynSolValue values)) => zip(types, values)
+                .enumerate()
+                .map(|(i, (ty, v))| match params.get(i) {
+                    Some(param) => Self::new_unchecked(
+                        param.name.clone(),
+                        self::struct_name(param.internal_type()),
+                        &param.components,
+                        ty.clone(),
+                        v,
+                    ),
+                    None => Self::new_unchecked(String::new(), None, &[], ty.clone(), v),
+                })
+                .collect(),
+            (_, value) => {
+                return Self { name, struct_name, ty, value: Some(value), components: Vec::new() }
+            }
+        };
+        Self { name, struct_name, ty, value: None, components }
+    }
+
+    /// Reassembles the value from the leaves' values.
+    ///
+    /// Returns `None` if a leaf has no value.
+    pub fn to_value(&self) -> Option<DynSolValue> {
+        let values = || self.components.iter().map(Self::to_value).collect::<Option<Vec<_>>>();
+        match &self.ty {
+            DynSolType::Array(_) => values().map(DynSolValue::Array),
+            DynSolType::FixedArray(..) => values().map(DynSolValue::FixedArray),
+            DynSolType::Tuple(_) => values().map(DynSolValue::Tuple),
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { name, prop_names, .. } => {
+                values().map(|tuple| DynSolValue::CustomStruct {
+                    name: name.clone(),
+                    prop_names: prop_names.clone(),
+                    tuple,
+                })
+            }
+            _ => self.value.clone(),
+        }
+    }
+
+    /// Returns the component at `path`, relative to this value.
+    ///
+    /// The path is a `.`-separated list of component names or indices, each
+    /// of which may be followed by any number of `[index]` suffixes, like
+    /// `orders[17].maker`.
+    pub fn get(&self, path: &str) -> Option<&Self> {
+        parse_path(path).ok()?.into_iter().try_fold(self, |value, segment| match segment {
+            Segment::Index(index) => value.components.get(index),
+            Segment::Name(name) => value.components.iter().find(|c| c.name == name),
+        })
+    }
+}
+
+/// Returns `true` if values of the type are stored directly in event topics,
+/// rather than hashed.
+const fn is_value_type(ty: &DynSolType) -> bool {
+    matches!(
+        ty,
+        DynSolType::Address
+            | DynSolType::Function
+            | DynSolType::Bool
+            | DynSolType::FixedBytes(_)
+            | DynSolType::Int(_)
+            | DynSolType::Uint(_)
+    )
+}
+
+fn mismatch(ty: &DynSolType, value: &DynSolValue) -> Error {
+    Error::TypeMismatch {
+        expected: ty.sol_type_name().into_owned(),
+        actual: value.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
+    }
+}
+
+/// Returns the struct name of an internal type, without its contract and
+/// array suffixes.
+fn struct_name(internal_type: Option<&InternalType>) -> Option<String> {
+    let (_, ty) = internal_type?.as_struct()?;
+    Some(ty.split('[').next().unwrap_or(ty).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EventExt, FunctionExt, JsonAbiExt};
+    use alloc::string::ToString;
+    use alloy_json_abi::Function;
+    use alloy_primitives::{Address, LogData, U256};
+    use serde_json::json;
+
+    fn order(i: u8) -> DynSolValue {
+        DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::with_last_byte(i)),
+            DynSolValue::Tuple(vec![U256::from(i).into(), U256::from(0).into()]),
+        ])
+    }
+
+    fn function() -> Function {
+        serde_json::from_str(
+            &json!({
+            "type": "function",
+            "name": "fill",
+            "inputs": [
+                {
+                    "name": "orders",
+                    "type": "tuple[]",
+                    "internalType": "struct Exchange.Order[]",
+                    "components": [
+                        { "name": "maker", "type": "address", "internalType": "address" },
+                        {
+                            "name": "amounts",
+                            "type": "tuple",
+                            "internalType": "struct Exchange.Amounts",
+                            "components": [
+                                { "name": "give", "type": "uint256", "internalType": "uint256" },
+                                { "name": "", "type": "uint256", "internalType": "uint256" }
+                            ]
+                        }
+                    ]
+                },
+                { "name": "", "type": "string", "internalType": "string" }
+            ],
+            "outputs": [{ "name": "filled", "type": "uint256", "internalType": "uint256" }],
+            "stateMutability": "nonpayable"
+        })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn tree() {
+        let function = function();
+        let args = [DynSolValue::Array(vec![order(1), order(2)]), DynSolValue::String("x".into())];
+        let data = function.abi_encode_input(&args).unwrap();
+        let decoded = function.abi_decode_input_named(&data[4..], true).unwrap();
+
+        assert_eq!(decoded.name, "fill");
+        let values = function.abi_decode_input(&data[4..], true).unwrap();
+        assert_eq!(decoded.value, None);
+        assert_eq!(decoded.to_value(), Some(DynSolValue::Tuple(values)));
+        assert_eq!(decoded.components.len(), 2);
+
+        let orders = decoded.get("orders").unwrap();
+        assert_eq!(orders.struct_name.as_deref(), Some("Order"));
+        assert_eq!(orders.components.len(), 2);
+        let order = decoded.get("orders[1]").unwrap();
+        assert_eq!(order.name, "");
+        assert_eq!(order.struct_name.as_deref(), Some("Order"));
+        assert_eq!(order.to_value().unwrap().abi_encode(), self::order(2).abi_encode());
+
+        let amounts = decoded.get("orders.1.amounts").unwrap();
+        assert_eq!(amounts.struct_name.as_deref(), Some("Amounts"));
+        assert_eq!(amounts.ty.sol_type_name(), "(uint256,uint256)");
+        assert_eq!(
+            decoded.get("orders[0].amounts.give").unwrap().value,
+            Some(U256::from(1).into())
+        );
+        assert_eq!(decoded.get("orders[0].maker").unwrap().struct_name, None);
+        assert_eq!(decoded.get("1").unwrap().value, Some(DynSolValue::String("x".into())));
+        assert_eq!(decoded.get(""), Some(&decoded));
+        assert_eq!(decoded.get("orders[2]"), None);
+        assert_eq!(decoded.get("orders.maker"), None);
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            json!({
+                "orders": [
+                    {
+                        "maker": "0x0000000000000000000000000000000000000001",
+                        "amounts": { "give": "1", "1": "0" },
+                    },
+                    {
+                        "maker": "0x0000000000000000000000000000000000000002",
+                        "amounts": { "give": "2", "1": "0" },
+                    },
+                ],
+                "1": "x",
+            })
+        );
+
+        let output =
+            function.abi_decode_output_named(&U256::from(5).to_be_bytes::<32>(), true).unwrap();
+        assert_eq!(output.get("filled").unwrap().value, Some(U256::from(5).into()));
+    }
+
+    #[test]
+    fn param() {
+        let param = &function().inputs[0];
+        let value = DynSolValue::Array(vec![order(3)]);
+        let named = NamedValue::abi_decode(param, &value.abi_encode(), true).unwrap();
+        assert_eq!(named.to_value().unwrap().abi_encode(), value.abi_encode());
+        assert_eq!(named, NamedValue::new(param, named.to_value().unwrap()).unwrap());
+        assert_eq!(named.name, "orders");
+        assert_eq!(named.get("0.amounts.give").unwrap().value, Some(U256::from(3).into()));
+
+        NamedValue::new(param, U256::from(1).into()).unwrap_err();
+    }
+
+    #[test]
+    fn event() {
+        let event = Event::parse(
+            "event Filled(address indexed maker, string indexed memo, (uint256 give, uint256 \
+             take) amounts)",
+        )
+        .unwrap();
+        let maker = Address::with_last_byte(1);
+        let memo = alloy_primitives::keccak256("memo");
+        let log = LogData::new_unchecked(
+            vec![event.selector(), maker.into_word(), memo],
+            DynSolValue::Tuple(vec![DynSolValue::Tuple(vec![
+                U256::from(1).into(),
+                U256::from(2).into(),
+            ])])
+            .abi_encode_params()
+            .into(),
+        );
+
+        let decoded = event.decode_log_named(&log, true).unwrap();
+        assert_eq!(decoded.name, "Filled");
+        assert_eq!(decoded.get("maker").unwrap().value, Some(DynSolValue::Address(maker)));
+        let memo_value = decoded.get("memo").unwrap();
+        assert_eq!(memo_value.ty, DynSolType::FixedBytes(32));
+        assert_eq!(memo_value.value, Some(DynSolValue::FixedBytes(memo, 32)));
+        assert_eq!(decoded.get("amounts.take").unwrap().value, Some(U256::from(2).into()));
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            json!({
+                "maker": "0x0000000000000000000000000000000000000001",
+                "memo": memo.to_string(),
+                "amounts": { "give": "1", "take": "2" },
+            })
+        );
+
+        // values which do not match their parameters are rejected
+        let decoded = DecodedEvent {
+            indexed: vec![U256::from(1).into(), DynSolValue::FixedBytes(memo, 32)],
+            body: vec![DynSolValue::Tuple(vec![U256::from(1).into(), U256::from(2).into()])],
+        };
+        NamedValue::from_event(&event, decoded).unwrap_err();
+        let decoded = DecodedEvent {
+            indexed: vec![maker.into(), DynSolValue::String("memo".into())],
+            body: vec![DynSolValue::Tuple(vec![U256::from(1).into(), U256::from(2).into()])],
+        };
+        NamedValue::from_event(&event, decoded).unwrap_err();
+    }
+}
@@ -0,0 +1,636 @@
+//! Serialization of Rust types into [`DynSolValue`]s.
+
+use crate::{ty::as_tuple, DynSolType, DynSolValue, Error, Result, Word};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{Address, Function, I256, U256};
+use serde::ser::{self, Impossible, Serialize};
+
+/// The element type of bytes-like types serialized as sequences.
+static BYTE: DynSolType = DynSolType::Uint(8);
+
+/// Serializes `value` into a [`DynSolValue`] of the given type.
+///
+/// See [`Serializer`] for the supported conversions.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{to_value, DynSolType, DynSolValue};
+/// use alloy_primitives::{Address, U256};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Transfer {
+///     to: Address,
+///     amount: u64,
+/// }
+///
+/// let ty: DynSolType = "(address,uint256)".parse()?;
+/// let transfer = Transfer { to: Address::with_last_byte(1), amount: 100 };
+/// let value = to_value(&transfer, &ty)?;
+/// assert_eq!(
+///     value,
+///     DynSolValue::Tuple(vec![Address::with_last_byte(1).into(), U256::from(100).into()])
+/// );
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+pub fn to_value<T: ?Sized + Serialize>(value: &T, ty: &DynSolType) -> Result<DynSolValue> {
+    value.serialize(Serializer::new(ty))
+}
+
+/// A [`serde::Serializer`] which produces a [`DynSolValue`] of a given type.
+///
+/// The serialized value is converted to the type as follows:
+/// - booleans are accepted for `bool`
+/// - primitive integers are accepted for any integer type that fits them
+/// - strings are coerced with [`DynSolType::coerce_str`], so they can be any type with a string
+///   representation, like [`U256`], [`Address`] and other fixed-size bytes types, which serialize
+///   as strings
+/// - bytes and sequences of bytes are accepted for bytes-like types
+/// - sequences are accepted for arrays and tuples, and structs for tuples, whose fields are
+///   assigned in order
+#[cfg_attr(
+    feature = "eip712",
+    doc = "- maps and structs are accepted for [`CustomStruct`](DynSolType::CustomStruct)s, \
+           whose fields are assigned by their property names"
+)]
+/// - unit enum variants are accepted for integer types, by index, and for `string`, by name, which
+///   maps Rust enums to Solidity enums
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<'a> {
+    ty: &'a DynSolType,
+}
+
+impl<'a> Serializer<'a> {
+    /// Creates a new serializer for the given type.
+    #[inline]
+    pub const fn new(ty: &'a DynSolType) -> Self {
+        Self { ty }
+    }
+
+    fn mismatch(&self, actual: &str) -> Error {
+        Error::TypeMismatch {
+            expected: self.ty.sol_type_name().into_owned(),
+            actual: actual.into(),
+        }
+    }
+
+    fn int(self, int: I256) -> Result<DynSolValue> {
+        match *self.ty {
+            DynSolType::Int(size) if int.bits() as usize <= size => Ok(DynSolValue::Int(int, size)),
+            DynSolType::Uint(_) if !int.is_negative() => self.uint(int.into_raw()),
+            _ => Err(self.mismatch(&int.to_string())),
+        }
+    }
+
+    fn uint(self, uint: U256) -> Result<DynSolValue> {
+        match *self.ty {
+            DynSolType::Uint(size) if uint.bit_len() <= size => Ok(DynSolValue::Uint(uint, size)),
+            DynSolType::Int(_) => match I256::try_from(uint) {
+                Ok(int) => self.int(int),
+                Err(_) => Err(self.mismatch(&uint.to_string())),
+            },
+            _ => Err(self.mismatch(&uint.to_string())),
+        }
+    }
+
+    fn bytes(self, bytes: &[u8]) -> Result<DynSolValue> {
+        match *self.ty {
+            DynSolType::FixedBytes(size) if bytes.len() == size => {
+                Ok(DynSolValue::FixedBytes(Word::right_padding_from(bytes), size))
+            }
+            DynSolType::Address if bytes.len() == 20 => {
+                Ok(DynSolValue::Address(Address::from_slice(bytes)))
+            }
+            DynSolType::Function if bytes.len() == 24 => {
+                Ok(DynSolValue::Function(Function::from_slice(bytes)))
+            }
+            DynSolType::Bytes => Ok(DynSolValue::Bytes(bytes.to_vec())),
+            _ => Err(self.mismatch(&format!("bytes{}", bytes.len()))),
+        }
+    }
+
+    const fn is_bytes(&self) -> bool {
+        matches!(
+            self.ty,
+            DynSolType::FixedBytes(_)
+                | DynSolType::Address
+                | DynSolType::Function
+                | DynSolType::Bytes
+        )
+    }
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = DynSolValue;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = Impossible<DynSolValue, Error>;
+    type SerializeMap = StructSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<DynSolValue, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<DynSolValue> {
+        match self.ty {
+            DynSolType::Bool => Ok(DynSolValue::Bool(v)),
+            _ => Err(self.mismatch("bool")),
+        }
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<DynSolValue> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<DynSolValue> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<DynSolValue> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<DynSolValue> {
+        self.int(I256::try_from(v).unwrap())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<DynSolValue> {
+        self.int(I256::try_from(v).unwrap())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<DynSolValue> {
+        self.uint(U256::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<DynSolValue> {
+        self.uint(U256::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<DynSolValue> {
+        self.uint(U256::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<DynSolValue> {
+        self.uint(U256::from(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<DynSolValue> {
+        self.uint(U256::from(v))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<DynSolValue> {
+        Err(self.mismatch("f32"))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<DynSolValue> {
+        Err(self.mismatch("f64"))
+    }
+
+    fn serialize_char(self, v: char) -> Result<DynSolValue> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<DynSolValue> {
+        self.ty.coerce_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<DynSolValue> {
+        self.bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<DynSolValue> {
+        Err(self.mismatch("None"))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<DynSolValue> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<DynSolValue> {
+        match self.ty {
+            DynSolType::Tuple(types) if types.is_empty() => Ok(DynSolValue::Tuple(Vec::new())),
+            _ => Err(self.mismatch("()")),
+        }
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<DynSolValue> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<DynSolValue> {
+        match self.ty {
+            DynSolType::String => Ok(DynSolValue::String(variant.to_string())),
+            _ => self.serialize_u32(variant_index),
+        }
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<DynSolValue> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<DynSolValue> {
+        Err(self.mismatch(&format!("{name}::{variant}")))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer<'a>> {
+        let valid = self.is_bytes()
+            || matches!(
+                self.ty,
+                DynSolType::Array(_) | DynSolType::FixedArray(..) | as_tuple!(DynSolType _)
+            );
+        if !valid {
+            return Err(self.mismatch("sequence"));
+        }
+        Ok(SeqSerializer { ty: self.ty, values: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(self.mismatch(&format!("{name}::{variant}")))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<StructSerializer<'a>> {
+        match self.ty {
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { .. } => Ok(StructSerializer::new(self.ty)),
+            _ => Err(self.mismatch("map")),
+        }
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<StructSerializer<'a>> {
+        match self.ty {
+            as_tuple!(DynSolType _) => Ok(StructSerializer::new(self.ty)),
+            _ => Err(self.mismatch(name)),
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(self.mismatch(&format!("{name}::{variant}")))
+    }
+}
+
+/// Serializes sequences into arrays, tuples or bytes.
+#[derive(Debug)]
+pub struct SeqSerializer<'a> {
+    ty: &'a DynSolType,
+    values: Vec<DynSolValue>,
+}
+
+impl SeqSerializer<'_> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let ty = match self.ty {
+            DynSolType::Array(ty) | DynSolType::FixedArray(ty, _) => ty,
+            as_tuple!(DynSolType types) => types.get(self.values.len()).ok_or_else(|| {
+                Error::EncodeLengthMismatch { expected: types.len(), actual: self.values.len() + 1 }
+            })?,
+            _ => &BYTE,
+        };
+        self.values.push(value.serialize(Serializer::new(ty))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        let len = self.values.len();
+        let length_mismatch = |expected| Err(Error::EncodeLengthMismatch { expected, actual: len });
+        match self.ty {
+            DynSolType::Array(_) => Ok(DynSolValue::Array(self.values)),
+            &DynSolType::FixedArray(_, size) if size == len => {
+                Ok(DynSolValue::FixedArray(self.values))
+            }
+            DynSolType::Tuple(types) if types.len() == len => Ok(DynSolValue::Tuple(self.values)),
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { name, prop_names, tuple } if tuple.len() == len => {
+                Ok(DynSolValue::CustomStruct {
+                    name: name.clone(),
+                    prop_names: prop_names.clone(),
+                    tuple: self.values,
+                })
+            }
+            &DynSolType::FixedArray(_, size) => length_mismatch(size),
+            as_tuple!(DynSolType types) => length_mismatch(types.len()),
+            ty => {
+                let bytes: Vec<u8> =
+                    self.values.iter().map(|v| v.as_uint().unwrap().0.to()).collect();
+                Serializer::new(ty).bytes(&bytes)
+            }
+        }
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = DynSolValue;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        self.end()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = DynSolValue;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        self.end()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = DynSolValue;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        self.end()
+    }
+}
+
+/// Serializes structs and maps into tuples.
+#[derive(Debug)]
+pub struct StructSerializer<'a> {
+    ty: &'a DynSolType,
+    values: Vec<Option<DynSolValue>>,
+    /// The index of the next positional field.
+    next: usize,
+    /// The pending map key.
+    key: Option<String>,
+}
+
+impl<'a> StructSerializer<'a> {
+    fn new(ty: &'a DynSolType) -> Self {
+        let len = match ty {
+            as_tuple!(DynSolType types) => types.len(),
+            _ => unreachable!(),
+        };
+        Self { ty, values: vec![None; len], next: 0, key: None }
+    }
+
+    #[cfg_attr(not(feature = "eip712"), allow(unused_variables))]
+    fn field<T: ?Sized + Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+        let (index, ty) = match self.ty {
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { prop_names, tuple, .. } => {
+                let index = prop_names
+                    .iter()
+                    .position(|name| name == key)
+                    .ok_or_else(|| Error::custom(format!("unknown field `{key}`")))?;
+                (index, &tuple[index])
+            }
+            DynSolType::Tuple(types) => {
+                let index = self.next;
+                self.next += 1;
+                let ty = types.get(index).ok_or(Error::EncodeLengthMismatch {
+                    expected: types.len(),
+                    actual: index + 1,
+                })?;
+                (index, ty)
+            }
+            _ => unreachable!(),
+        };
+        self.values[index] = Some(value.serialize(Serializer::new(ty))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        let Some(values) = self.values.iter().cloned().collect::<Option<Vec<_>>>() else {
+            return Err(match self.ty {
+                #[cfg(feature = "eip712")]
+                DynSolType::CustomStruct { prop_names, .. } => {
+                    let missing = self.values.iter().position(Option::is_none).unwrap();
+                    Error::custom(format!("missing field `{}`", prop_names[missing]))
+                }
+                _ => Error::EncodeLengthMismatch { expected: self.values.len(), actual: self.next },
+            });
+        };
+        Ok(match self.ty {
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { name, prop_names, .. } => DynSolValue::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: values,
+            },
+            _ => DynSolValue::Tuple(values),
+        })
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = DynSolValue;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        self.end()
+    }
+}
+
+impl ser::SerializeMap for StructSerializer<'_> {
+    type Ok = DynSolValue;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        match to_value(key, &DynSolType::String)? {
+            DynSolValue::String(key) => self.key = Some(key),
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = self.key.take().expect("serialize_value called before serialize_key");
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<DynSolValue> {
+        self.end()
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Self::custom(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    #[derive(Serialize)]
+    struct Order {
+        maker: Address,
+        side: Side,
+        amounts: Vec<u64>,
+        price: U256,
+        delta: i128,
+        data: Vec<u8>,
+        salt: B256,
+    }
+
+    #[test]
+    fn serialize_struct() {
+        let ty: DynSolType =
+            "(address,uint8,uint64[],uint256,int256,bytes,bytes32)".parse().unwrap();
+        let order = Order {
+            maker: Address::with_last_byte(1),
+            side: Side::Sell,
+            amounts: vec![2, 3],
+            price: U256::MAX,
+            delta: -1,
+            data: vec![1, 2, 3],
+            salt: B256::repeat_byte(0xff),
+        };
+        assert_eq!(
+            to_value(&order, &ty),
+            Ok(DynSolValue::Tuple(vec![
+                Address::with_last_byte(1).into(),
+                DynSolValue::Uint(U256::from(1), 8),
+                DynSolValue::Array(vec![
+                    DynSolValue::Uint(U256::from(2), 64),
+                    DynSolValue::Uint(U256::from(3), 64),
+                ]),
+                U256::MAX.into(),
+                I256::MINUS_ONE.into(),
+                vec![1, 2, 3].into(),
+                DynSolValue::FixedBytes(B256::repeat_byte(0xff), 32),
+            ]))
+        );
+
+        assert_eq!(
+            to_value(&Side::Buy, &DynSolType::Uint(8)),
+            Ok(DynSolValue::Uint(U256::ZERO, 8))
+        );
+        assert_eq!(
+            to_value(&Side::Buy, &DynSolType::String),
+            Ok(DynSolValue::String("Buy".into()))
+        );
+    }
+
+    #[test]
+    fn serialize_primitives() {
+        assert_eq!(
+            to_value(&255u64, &DynSolType::Uint(8)),
+            Ok(DynSolValue::Uint(U256::from(255), 8))
+        );
+        to_value(&256u64, &DynSolType::Uint(8)).unwrap_err();
+        to_value(&-1i64, &DynSolType::Uint(256)).unwrap_err();
+        assert_eq!(
+            to_value(&-128i8, &DynSolType::Int(8)),
+            Ok(DynSolValue::Int(I256::try_from(-128).unwrap(), 8))
+        );
+        assert_eq!(to_value("0x1234", &DynSolType::Bytes), Ok(vec![0x12, 0x34].into()));
+        assert_eq!(
+            to_value(&[0x12u8, 0x34], &DynSolType::FixedBytes(2)),
+            Ok(DynSolValue::FixedBytes(Word::right_padding_from(&[0x12, 0x34]), 2))
+        );
+        assert_eq!(
+            to_value(&(true, "a"), &"(bool,string)".parse().unwrap()),
+            Ok(DynSolValue::Tuple(vec![true.into(), DynSolValue::String("a".into())]))
+        );
+
+        // length mismatches
+        to_value(&[1u8, 2, 3], &DynSolType::FixedBytes(2)).unwrap_err();
+        to_value(&(1u8, 2u8), &"(uint8)".parse().unwrap()).unwrap_err();
+        to_value(&[1u8], &"uint8[2]".parse().unwrap()).unwrap_err();
+        to_value(&1.5f64, &DynSolType::Uint(256)).unwrap_err();
+    }
+
+    #[cfg(feature = "eip712")]
+    #[test]
+    fn serialize_custom_struct() {
+        use alloc::{borrow::ToOwned, collections::BTreeMap};
+
+        #[derive(Serialize)]
+        struct Person {
+            wallet: Address,
+            name: String,
+        }
+
+        let ty = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".into(), "wallet".into()],
+            tuple: vec![DynSolType::String, DynSolType::Address],
+        };
+        let expected = DynSolValue::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".into(), "wallet".into()],
+            tuple: vec![DynSolValue::String("Bob".into()), Address::with_last_byte(2).into()],
+        };
+        let person = Person { wallet: Address::with_last_byte(2), name: "Bob".into() };
+        assert_eq!(to_value(&person, &ty), Ok(expected.clone()));
+
+        let map = BTreeMap::from([
+            ("wallet", "0x0000000000000000000000000000000000000002"),
+            ("name", "Bob"),
+        ]);
+        assert_eq!(to_value(&map, &ty), Ok(expected));
+
+        to_value(&BTreeMap::from([("name", "Bob")]), &ty).unwrap_err();
+        to_value(&BTreeMap::from([("age", "1")]), &ty).unwrap_err();
+    }
+}
@@ -0,0 +1,638 @@
+//! An offline database of function, error and event signatures.
+
+use crate::{DynSolValue, Error, EventExt, JsonAbiExt, Result};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_json_abi::{Event, Function, JsonAbi};
+use alloy_primitives::{LogData, Selector, B256};
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+/// The magic bytes at the start of the [compact serialization].
+///
+/// [compact serialization]: SignatureDb::to_bytes
+const MAGIC: [u8; 5] = *b"sigdb";
+
+/// The version of the [compact serialization].
+///
+/// [compact serialization]: SignatureDb::to_bytes
+const VERSION: u8 = 1;
+
+/// An offline database of signatures, mapping 4-byte selectors to function
+/// and error signatures, and 32-byte topics to event signatures.
+///
+/// Signatures are stored in their canonical form, like
+/// `transfer(address,uint256)`, and each selector or topic can have multiple
+/// candidate signatures, as selectors are easily made to collide.
+///
+/// The database can be filled from [`JsonAbi`]s and from local dumps of
+/// public signature databases, like [4byte.directory] and [openchain], and
+/// stored in a [compact binary format](SignatureDb::to_bytes).
+///
+/// [4byte.directory]: https://www.4byte.directory
+/// [openchain]: https://openchain.xyz/signatures
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{decode_with_db, DynSolValue, SignatureDb};
+/// use alloy_primitives::{hex, Address, U256};
+///
+/// let mut db = SignatureDb::new();
+/// db.extend_csv(
+///     "0xa9059cbb,transfer(address,uint256)\n\
+///      0xa9059cbb,many_msg_babbage(bytes1)\n",
+/// );
+/// assert_eq!(db.functions(hex!("a9059cbb").into()).len(), 2);
+///
+/// let bytes = db.to_bytes();
+/// assert_eq!(SignatureDb::from_bytes(&bytes)?, db);
+///
+/// let calldata = hex!(
+///     "a9059cbb"
+///     "0000000000000000000000001111111111111111111111111111111111111111"
+///     "00000000000000000000000000000000000000000000000000000000000003e8"
+/// );
+/// let candidates = decode_with_db(&db, &calldata);
+/// assert_eq!(candidates[0].function.signature(), "transfer(address,uint256)");
+/// assert!(candidates[0].canonical);
+/// assert_eq!(candidates[0].args[1], DynSolValue::Uint(U256::from(1000), 256));
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignatureDb {
+    functions: BTreeMap<Selector, Vec<String>>,
+    events: BTreeMap<B256, Vec<String>>,
+}
+
+impl SignatureDb {
+    /// Creates a new, empty database.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new database with the signatures of the given ABI's
+    /// functions, errors and events.
+    pub fn from_abi(abi: &JsonAbi) -> Self {
+        let mut db = Self::new();
+        db.extend_abi(abi);
+        db
+    }
+
+    /// Returns the total number of signatures.
+    pub fn len(&self) -> usize {
+        self.functions.values().chain(self.events.values()).map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the database contains no signatures.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.events.is_empty()
+    }
+
+    /// Returns the function and error signatures with the given selector.
+    #[inline]
+    pub fn functions(&self, selector: Selector) -> &[String] {
+        self.functions.get(&selector).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the event signatures with the given topic.
+    #[inline]
+    pub fn events(&self, topic: B256) -> &[String] {
+        self.events.get(&topic).map_or(&[], Vec::as_slice)
+    }
+
+    /// Inserts a function or error signature, like `transfer(address,uint256)`
+    /// or `function transfer(address to, uint256 amount)`.
+    ///
+    /// Returns `false` if the signature is already present.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the signature cannot be parsed.
+    pub fn insert_function(&mut self, signature: &str) -> Result<bool> {
+        let function = Function::parse(signature)?;
+        Ok(insert(&mut self.functions, function.selector(), function.signature()))
+    }
+
+    /// Inserts an event signature, like `Transfer(address,address,uint256)`.
+    ///
+    /// Returns `false` if the signature is already present.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the signature cannot be parsed.
+    pub fn insert_event(&mut self, signature: &str) -> Result<bool> {
+        let event = Event::parse(signature)?;
+        Ok(insert(&mut self.events, event.selector(), event.signature()))
+    }
+
+    /// Inserts the signatures of the given ABI's functions, errors and events.
+    pub fn extend_abi(&mut self, abi: &JsonAbi) {
+        for function in abi.functions() {
+            insert(&mut self.functions, function.selector(), function.signature());
+        }
+        for error in abi.errors() {
+            insert(&mut self.functions, error.selector(), error.signature());
+        }
+        for event in abi.events() {
+            insert(&mut self.events, event.selector(), event.signature());
+        }
+    }
+
+    /// Inserts the signatures of a CSV dump, and returns the number of new
+    /// signatures.
+    ///
+    /// Each line is either a signature, which is treated as a function
+    /// signature, or a hex selector or topic followed by a comma and the
+    /// signature. Signatures which cannot be parsed or do not match their
+    /// selector are skipped, as are header lines.
+    pub fn extend_csv(&mut self, csv: &str) -> usize {
+        csv.lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                match line.split_once(',') {
+                    Some((key, signature)) if key.starts_with("0x") => {
+                        Some((Some(key), signature.trim_matches('"')))
+                    }
+                    _ if line.contains('(') => Some((None, line.trim_matches('"'))),
+                    _ => None,
+                }
+            })
+            .filter(|&(key, signature)| self.insert_entry(key, signature))
+            .count()
+    }
+
+    /// Inserts the signatures of a JSON dump, and returns the number of new
+    /// signatures.
+    ///
+    /// The following formats are supported:
+    /// - [4byte.directory] API responses, or arrays of their `results`;
+    /// - [openchain] API responses;
+    /// - objects mapping selectors or topics to signatures;
+    /// - arrays of function signatures.
+    ///
+    /// ```json
+    /// {"results": [{"text_signature": "transfer(address,uint256)", "hex_signature": "0xa9059cbb"}]}
+    /// {"result": {"function": {"0xa9059cbb": [{"name": "transfer(address,uint256)"}]}, "event": {}}}
+    /// {"0xa9059cbb": ["transfer(address,uint256)"], "0x70a08231": "balanceOf(address)"}
+    /// ["transfer(address,uint256)", "balanceOf(address)"]
+    /// ```
+    ///
+    /// Signatures which cannot be parsed or do not match their selector are
+    /// skipped.
+    ///
+    /// [4byte.directory]: https://www.4byte.directory/docs
+    /// [openchain]: https://docs.openchain.xyz
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input is not valid JSON, or
+    /// if it is not in one of the supported formats.
+    #[cfg(feature = "serde_json")]
+    pub fn extend_json(&mut self, json: &str) -> Result<usize> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| Error::custom(format!("invalid signature dump: {e}")))?;
+        self.extend_json_value(&value)
+            .ok_or_else(|| Error::custom("unsupported signature dump format"))
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn extend_json_value(&mut self, value: &Value) -> Option<usize> {
+        match value {
+            Value::Object(map) => {
+                if let Some(results) = map.get("results") {
+                    return self.extend_json_value(results);
+                }
+                if let Some(result) = map.get("result") {
+                    let result = result.as_object()?;
+                    let groups = ["function", "event"].iter().filter_map(|k| result.get(*k));
+                    return groups.map(|group| self.extend_json_value(group)).sum();
+                }
+                let entries = map.iter().flat_map(|(key, value)| {
+                    names(value).into_iter().map(move |name| (Some(key.as_str()), name))
+                });
+                Some(entries.filter(|&(key, name)| self.insert_entry(key, name)).count())
+            }
+            Value::Array(items) => {
+                let entries = items.iter().filter_map(|item| match item {
+                    Value::String(name) => Some((None, name.as_str())),
+                    Value::Object(item) => {
+                        let key = item.get("hex_signature").and_then(Value::as_str);
+                        Some((key, item.get("text_signature")?.as_str()?))
+                    }
+                    _ => None,
+                });
+                Some(entries.filter(|&(key, name)| self.insert_entry(key, name)).count())
+            }
+            _ => None,
+        }
+    }
+
+    /// Inserts a signature with an optional hex selector or topic, which
+    /// determines whether it is an event. Returns `true` if it was inserted.
+    fn insert_entry(&mut self, key: Option<&str>, signature: &str) -> bool {
+        let key = key.map(|key| key.trim().trim_matches('"'));
+        match key.map(str::len) {
+            None => self.insert_function(signature).unwrap_or(false),
+            Some(10) => Function::parse(signature).ok().map_or(false, |function| {
+                let selector = function.selector();
+                key.and_then(|key| key.parse::<Selector>().ok()) == Some(selector)
+                    && insert(&mut self.functions, selector, function.signature())
+            }),
+            Some(66) => Event::parse(signature).ok().map_or(false, |event| {
+                let topic = event.selector();
+                key.and_then(|key| key.parse::<B256>().ok()) == Some(topic)
+                    && insert(&mut self.events, topic, event.signature())
+            }),
+            Some(_) => false,
+        }
+    }
+
+    /// Serializes the database into a compact binary format.
+    ///
+    /// The format consists of the magic bytes `sigdb`, a version byte, and
+    /// the function and event sections. Each section is a big-endian `u32`
+    /// count of entries, followed by the entries, each consisting of the
+    /// selector or topic, a big-endian `u16` length and the UTF-8 signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn section<K: AsRef<[u8]>>(out: &mut Vec<u8>, map: &BTreeMap<K, Vec<String>>) {
+            let len = map.values().map(Vec::len).sum::<usize>();
+            out.extend_from_slice(&(len as u32).to_be_bytes());
+            for (key, signature) in
+                map.iter().flat_map(|(k, sigs)| sigs.iter().map(move |s| (k, s)))
+            {
+                out.extend_from_slice(key.as_ref());
+                out.extend_from_slice(&(signature.len() as u16).to_be_bytes());
+                out.extend_from_slice(signature.as_bytes());
+            }
+        }
+
+        let mut out = Vec::with_capacity(MAGIC.len() + 1 + 8 + self.len() * 40);
+        out.extend_from_slice(&MAGIC);
+        out.push(VERSION);
+        section(&mut out, &self.functions);
+        section(&mut out, &self.events);
+        out
+    }
+
+    /// Deserializes a database from the format produced by
+    /// [`to_bytes`](Self::to_bytes).
+    ///
+    /// The selectors and topics are not recomputed from the signatures.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data is not in the expected
+    /// format.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let invalid = || Error::custom("invalid signature database");
+        let rest = data.strip_prefix(&MAGIC[..]).ok_or_else(invalid)?;
+        let (&version, mut rest) = rest.split_first().ok_or_else(invalid)?;
+        if version != VERSION {
+            return Err(Error::custom(format!(
+                "unsupported signature database version: {version}"
+            )));
+        }
+
+        let mut take = |n: usize| -> Result<&[u8]> {
+            if rest.len() < n {
+                return Err(invalid());
+            }
+            let (taken, remaining) = rest.split_at(n);
+            rest = remaining;
+            Ok(taken)
+        };
+        let mut db = Self::new();
+        for key_len in [4, 32] {
+            let count = u32::from_be_bytes(take(4)?.try_into().unwrap());
+            for _ in 0..count {
+                let key = take(key_len)?;
+                let len = u16::from_be_bytes(take(2)?.try_into().unwrap()) as usize;
+                let signature = core::str::from_utf8(take(len)?).map_err(|_| invalid())?.into();
+                if key_len == 4 {
+                    insert(&mut db.functions, Selector::from_slice(key), signature);
+                } else {
+                    insert(&mut db.events, B256::from_slice(key), signature);
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return Err(invalid());
+        }
+        Ok(db)
+    }
+}
+
+/// A function or error signature that successfully decoded calldata. See
+/// [`decode_with_db`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallCandidate {
+    /// The function, parsed from the signature. Its parameters are unnamed.
+    pub function: Function,
+    /// The decoded arguments.
+    pub args: Vec<DynSolValue>,
+    /// Whether the arguments re-encode to exactly the same data.
+    pub canonical: bool,
+}
+
+/// Decodes calldata or revert data with all of the candidate signatures for
+/// its selector in the given database.
+///
+/// Only the candidates which successfully decode the data are returned.
+/// Those whose arguments re-encode to exactly the same data, and which are
+/// therefore more likely to be correct, come first. Otherwise, the order of
+/// the database is preserved.
+pub fn decode_with_db(db: &SignatureDb, data: &[u8]) -> Vec<CallCandidate> {
+    if data.len() < 4 {
+        return Vec::new();
+    }
+    let (selector, input) = data.split_at(4);
+    let mut candidates: Vec<_> = db
+        .functions(Selector::from_slice(selector))
+        .iter()
+        .filter_map(|signature| {
+            let function = Function::parse(signature).ok()?;
+            let args = function.abi_decode_input(input, false).ok()?;
+            let canonical = DynSolValue::encode_seq(&args) == input;
+            Some(CallCandidate { function, args, canonical })
+        })
+        .collect();
+    // stable, so the database order is preserved
+    candidates.sort_by_key(|candidate| !candidate.canonical);
+    candidates
+}
+
+/// An event signature that successfully decoded a log. See
+/// [`decode_log_with_db`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventCandidate {
+    /// The event, parsed from the signature. Its parameters are unnamed, and
+    /// marked as indexed according to the layout that decoded the log.
+    pub event: Event,
+    /// The decoded indexed values, in order.
+    pub indexed: Vec<DynSolValue>,
+    /// The decoded un-indexed values, in order.
+    pub body: Vec<DynSolValue>,
+    /// Whether the values re-encode to exactly the same topics and data.
+    pub canonical: bool,
+}
+
+/// Decodes a log with all of the candidate signatures for its first topic in
+/// the given database.
+///
+/// Signatures do not record which parameters are indexed, so every layout
+/// with as many indexed parameters as the log has topics is tried, starting
+/// with the leading parameters.
+///
+/// Only the candidates which successfully decode the log are returned.
+/// Those whose values re-encode to exactly the same topics and data, and
+/// which are therefore more likely to be correct, come first. Otherwise, the
+/// order of the database is preserved.
+pub fn decode_log_with_db(db: &SignatureDb, log: &LogData) -> Vec<EventCandidate> {
+    let Some((&topic, topics)) = log.topics().split_first() else { return Vec::new() };
+    let mut candidates = Vec::new();
+    for signature in db.events(topic) {
+        let Ok(event) = Event::parse(signature) else { continue };
+        // at most 3 parameters can be indexed in a non-anonymous event
+        if topics.len() > 3 || topics.len() > event.inputs.len() {
+            continue;
+        }
+        for_each_combination(event.inputs.len(), topics.len(), |indexed| {
+            let mut event = event.clone();
+            for &i in indexed {
+                event.inputs[i].indexed = true;
+            }
+            let Ok(decoded) = event.decode_log(log, true) else { return };
+            let canonical = decoded
+                .indexed
+                .iter()
+                .zip(topics)
+                .all(|(value, topic)| value.as_word() == Some(*topic))
+                && DynSolValue::encode_seq(&decoded.body) == log.data[..];
+            candidates.push(EventCandidate {
+                event,
+                indexed: decoded.indexed,
+                body: decoded.body,
+                canonical,
+            });
+        });
+    }
+    // stable, so the database order is preserved
+    candidates.sort_by_key(|candidate| !candidate.canonical);
+    candidates
+}
+
+/// Calls `f` with every combination of `k` out of `n` indices, in
+/// lexicographic order.
+fn for_each_combination(n: usize, k: usize, mut f: impl FnMut(&[usize])) {
+    let mut indices: Vec<usize> = (0..k).collect();
+    loop {
+        f(&indices);
+        let Some(i) = (0..k).rev().find(|&i| indices[i] != i + n - k) else { return };
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+    }
+}
+
+fn insert<K: Ord>(map: &mut BTreeMap<K, Vec<String>>, key: K, signature: String) -> bool {
+    // cannot be serialized
+    if signature.len() > u16::MAX as usize {
+        return false;
+    }
+    let signatures = map.entry(key).or_default();
+    let new = !signatures.contains(&signature);
+    if new {
+        signatures.push(signature);
+    }
+    new
+}
+
+/// Returns the signatures in a JSON dump entry.
+#[cfg(feature = "serde_json")]
+fn names(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(name) => vec![name.as_str()],
+        Value::Array(items) => items.iter().flat_map(names).collect(),
+        Value::Object(item) => {
+            let name = item.get("name").or_else(|| item.get("text_signature"));
+            name.and_then(Value::as_str).into_iter().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, keccak256, Address, U256};
+
+    const TRANSFER: Selector = Selector::new(hex!("a9059cbb"));
+
+    fn transfer_topic() -> B256 {
+        keccak256("Transfer(address,address,uint256)")
+    }
+
+    #[test]
+    fn insert_and_lookup() {
+        let mut db = SignatureDb::new();
+        assert!(db.insert_function("function transfer(address to, uint256 amount)").unwrap());
+        assert!(!db.insert_function("transfer(address,uint256)").unwrap());
+        assert!(db.insert_event("Transfer(address indexed, address indexed, uint256)").unwrap());
+        db.insert_function("transfer(address").unwrap_err();
+        assert_eq!(db.functions(TRANSFER), ["transfer(address,uint256)"]);
+        assert_eq!(db.events(transfer_topic()), ["Transfer(address,address,uint256)"]);
+        assert_eq!(db.len(), 2);
+
+        let abi = JsonAbi::parse([
+            "function transfer(address to, uint256 amount)",
+            "error Unauthorized(address caller)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ])
+        .unwrap();
+        let from_abi = SignatureDb::from_abi(&abi);
+        assert_eq!(from_abi.len(), 3);
+        assert_eq!(
+            from_abi.functions(keccak256("Unauthorized(address)")[..4].try_into().unwrap()),
+            ["Unauthorized(address)"]
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let mut db = SignatureDb::new();
+        let csv = "\
+            selector,signature\n\
+            0xa9059cbb,transfer(address,uint256)\n\
+            0xa9059cbb,\"many_msg_babbage(bytes1)\"\n\
+            0xdeadbeef,transfer(address,uint256)\n\
+            0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef,Transfer(address,address,uint256)\n\
+            approve(address,uint256)\n\
+            not a signature\n";
+        assert_eq!(db.extend_csv(csv), 4);
+        assert_eq!(db.functions(TRANSFER).len(), 2);
+        assert_eq!(db.events(transfer_topic()).len(), 1);
+        assert_eq!(db.extend_csv(csv), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn json() {
+        let mut db = SignatureDb::new();
+        let four_byte = r#"{
+            "count": 2,
+            "results": [
+                {"id": 1, "text_signature": "transfer(address,uint256)", "hex_signature": "0xa9059cbb"},
+                {"id": 2, "text_signature": "many_msg_babbage(bytes1)", "hex_signature": "0xa9059cbb"}
+            ]
+        }"#;
+        assert_eq!(db.extend_json(four_byte).unwrap(), 2);
+
+        let openchain = r#"{
+            "ok": true,
+            "result": {
+                "event": {
+                    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef": [
+                        {"name": "Transfer(address,address,uint256)", "filtered": false}
+                    ]
+                },
+                "function": {
+                    "0xa9059cbb": [{"name": "transfer(address,uint256)", "filtered": false}],
+                    "0x095ea7b3": [{"name": "approve(address,uint256)", "filtered": false}]
+                }
+            }
+        }"#;
+        assert_eq!(db.extend_json(openchain).unwrap(), 2);
+
+        let map = r#"{"0x70a08231": "balanceOf(address)", "0x18160ddd": ["totalSupply()"]}"#;
+        assert_eq!(db.extend_json(map).unwrap(), 2);
+        assert_eq!(db.extend_json(r#"["name()", "symbol()", 1]"#).unwrap(), 2);
+        assert_eq!(db.len(), 8);
+
+        db.extend_json("{").unwrap_err();
+        db.extend_json("42").unwrap_err();
+    }
+
+    #[test]
+    fn bytes() {
+        let mut db = SignatureDb::new();
+        db.extend_csv("transfer(address,uint256)\nmany_msg_babbage(bytes1)\nname()");
+        db.insert_event("Transfer(address,address,uint256)").unwrap();
+
+        let bytes = db.to_bytes();
+        assert!(bytes.starts_with(b"sigdb\x01"));
+        assert_eq!(SignatureDb::from_bytes(&bytes).unwrap(), db);
+        assert_eq!(
+            SignatureDb::from_bytes(&SignatureDb::new().to_bytes()).unwrap(),
+            SignatureDb::new()
+        );
+
+        SignatureDb::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+        SignatureDb::from_bytes(&[&bytes[..], &[0]].concat()).unwrap_err();
+        SignatureDb::from_bytes(b"sigdb\x02").unwrap_err();
+        SignatureDb::from_bytes(b"").unwrap_err();
+    }
+
+    #[test]
+    fn decode() {
+        let mut db = SignatureDb::new();
+        db.extend_csv("many_msg_babbage(bytes1)\ntransfer(address,uint256)\ntransfer(address)");
+
+        let args = [
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+            DynSolValue::Uint(U256::from(1), 256),
+        ];
+        let calldata = [&TRANSFER[..], &DynSolValue::encode_seq(&args)].concat();
+        let candidates = decode_with_db(&db, &calldata);
+        // `bytes1` does not validate the padding, so it decodes too
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].function.signature(), "transfer(address,uint256)");
+        assert!(candidates[0].canonical);
+        assert_eq!(candidates[0].args, args);
+        assert_eq!(candidates[1].function.name, "many_msg_babbage");
+        assert!(!candidates[1].canonical);
+
+        assert_eq!(decode_with_db(&db, &hex!("deadbeef")), []);
+        assert_eq!(decode_with_db(&db, &hex!("a905")), []);
+    }
+
+    #[test]
+    fn decode_log() {
+        let mut db = SignatureDb::new();
+        db.insert_event("Transfer(address,address,uint256)").unwrap();
+
+        let from = Address::repeat_byte(0x11);
+        let to = Address::repeat_byte(0x22);
+        let topics = vec![transfer_topic(), from.into_word(), to.into_word()];
+        let data = U256::MAX.to_be_bytes_vec().into();
+        let log = LogData::new_unchecked(topics.clone(), data);
+        let candidates = decode_log_with_db(&db, &log);
+        assert_eq!(candidates.len(), 3);
+        let transfer = &candidates[0];
+        assert!(transfer.canonical);
+        assert_eq!(
+            transfer.event.inputs.iter().map(|p| p.indexed).collect::<Vec<_>>(),
+            [true, true, false]
+        );
+        assert_eq!(transfer.indexed, [DynSolValue::Address(from), DynSolValue::Address(to)]);
+        assert_eq!(transfer.body, [DynSolValue::Uint(U256::MAX, 256)]);
+        // the value decodes as an address, but does not re-encode to the same data
+        assert!(!candidates[1].canonical);
+        assert!(!candidates[2].canonical);
+
+        // ERC-721 transfers index all three parameters
+        let mut topics = topics;
+        topics.push(U256::from(1000).into());
+        let log = LogData::new_unchecked(topics, Default::default());
+        let candidates = decode_log_with_db(&db, &log);
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates[0].canonical);
+        assert_eq!(candidates[0].body, []);
+
+        let unknown = LogData::new_unchecked(vec![B256::ZERO], Default::default());
+        assert_eq!(decode_log_with_db(&db, &unknown), []);
+        assert_eq!(decode_log_with_db(&db, &LogData::default()), []);
+    }
+}
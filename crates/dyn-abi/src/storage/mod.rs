@@ -0,0 +1,669 @@
+//! Contract storage inspection, driven by the compiler's storage layout.
+//!
+//! [`StorageLayoutExt`] resolves paths to state variables, such as
+//! `balances[0xabc].amounts[3].value`, to their [location](StorageLocation) in
+//! storage, and decodes the raw storage words at that location into a
+//! [`DynSolValue`].
+//!
+//! This module also provides the [ERC-7201](erc7201_slot) namespaced storage
+//! root computation and the [EIP-1967](eip1967) proxy slots.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_dyn_abi::{storage::StorageLayoutExt, DynSolValue};
+//! use alloy_json_abi::artifact::StorageLayout;
+//! use alloy_primitives::{B256, U256};
+//!
+//! let layout: StorageLayout = serde_json::from_str(
+//!     r#"{
+//!         "storage": [
+//!             { "astId": 1, "contract": "C.sol:C", "label": "a", "offset": 0, "slot": "0", "type": "t_uint128" },
+//!             { "astId": 2, "contract": "C.sol:C", "label": "b", "offset": 16, "slot": "0", "type": "t_uint128" }
+//!         ],
+//!         "types": {
+//!             "t_uint128": { "encoding": "inplace", "label": "uint128", "numberOfBytes": "16" }
+//!         }
+//!     }"#,
+//! )
+//! .unwrap();
+//!
+//! let b = layout.resolve("b")?;
+//! assert_eq!((b.slot, b.offset, b.size), (B256::ZERO, 16, 16));
+//!
+//! // `b = 2`, `a = 1`, packed into slot 0
+//! let word = B256::from(U256::from(2) << 128 | U256::from(1));
+//! let storage = |slot: B256| if slot.is_zero() { word } else { B256::ZERO };
+//! assert_eq!(layout.read_path("b", storage)?, DynSolValue::Uint(U256::from(2), 128));
+//! # Ok::<_, alloy_dyn_abi::Error>(())
+//! ```
+
+use crate::{DynSolType, DynSolValue, Error, Result};
+use alloc::{string::String, vec::Vec};
+use alloy_json_abi::artifact::{StorageEncoding, StorageLayout, StorageType};
+use alloy_primitives::{keccak256, Address, Function, B256, I256, U256};
+
+mod namespaced;
+pub use namespaced::{eip1967, erc7201_slot};
+
+mod path;
+use path::Segment;
+
+/// The maximum length of dynamic arrays, `bytes` and `string` values which
+/// will be decoded from storage.
+const MAX_LENGTH: usize = 1 << 24;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for alloy_json_abi::artifact::StorageLayout {}
+}
+use sealed::Sealed;
+
+/// The location of a value in contract storage.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StorageLocation {
+    /// The slot in which the value starts.
+    pub slot: B256,
+    /// The byte offset of the value within its slot, counted from the least
+    /// significant (rightmost) byte, as in the storage layout.
+    pub offset: usize,
+    /// The number of bytes occupied by the value. Values of more than 32 bytes
+    /// span multiple consecutive slots. For `bytes`, `string`, dynamic arrays
+    /// and mappings, this is the size of the slot at [`slot`](Self::slot).
+    pub size: usize,
+    /// The identifier of the value's type in the storage layout, such as
+    /// `t_uint256`.
+    pub ty: String,
+}
+
+impl StorageLocation {
+    /// Returns the bytes of a value of at most 32 bytes within the given slot
+    /// word, in big-endian order.
+    #[inline]
+    pub fn extract<'a>(&self, word: &'a B256) -> &'a [u8] {
+        let end = 32usize.saturating_sub(self.offset);
+        &word[end.saturating_sub(self.size)..end]
+    }
+}
+
+/// Provides storage path resolution and decoding for the [`StorageLayout`]
+/// type.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`StorageLayout`].
+pub trait StorageLayoutExt: Sealed {
+    /// Resolves a path to a state variable to its location in storage.
+    ///
+    /// A path starts with the name of a state variable, followed by any number
+    /// of struct member accesses (`.member`), array indices (`[3]`) and
+    /// mapping keys (`[0xabc]`, `[true]`, `["key"]`). Mapping keys are parsed
+    /// according to the mapping's key type, as in
+    /// [`DynSolType::coerce_str`], except that addresses may be shorter than 20
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is invalid, refers to a member or variable
+    /// which does not exist, or indexes a fixed-size array out of bounds.
+    fn resolve(&self, path: &str) -> Result<StorageLocation>;
+
+    /// Decodes the value at the given location, reading storage words from the
+    /// given function.
+    ///
+    /// Structs are decoded as [tuples](DynSolValue::Tuple), and enums and
+    /// user-defined value types as unsigned integers of the same size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value contains a mapping, or if its storage is
+    /// malformed, such as a `string` which is not valid UTF-8.
+    fn decode_storage<F>(&self, location: &StorageLocation, storage: F) -> Result<DynSolValue>
+    where
+        F: FnMut(B256) -> B256;
+
+    /// Resolves a path and decodes the value at its location.
+    ///
+    /// See [`resolve`](Self::resolve) and
+    /// [`decode_storage`](Self::decode_storage).
+    #[inline]
+    fn read_path<F>(&self, path: &str, storage: F) -> Result<DynSolValue>
+    where
+        F: FnMut(B256) -> B256,
+    {
+        self.decode_storage(&self.resolve(path)?, storage)
+    }
+}
+
+impl StorageLayoutExt for StorageLayout {
+    fn resolve(&self, path: &str) -> Result<StorageLocation> {
+        let segments = path::parse(path)?;
+        let Some((&Segment::Field(label), segments)) = segments.split_first() else {
+            unreachable!("paths start with a field")
+        };
+        let entry = self
+            .entry(label)
+            .ok_or_else(|| Error::custom(format!("unknown state variable {label:?}")))?;
+        let mut slot = entry.slot;
+        let mut offset = entry.offset as usize;
+        let mut id = entry.ty.as_str();
+
+        for segment in segments {
+            let ty = get_type(self, id)?;
+            match (*segment, ty.encoding) {
+                (Segment::Field(name), StorageEncoding::Inplace) if ty.members.is_some() => {
+                    let members = ty.members.as_deref().unwrap_or_default();
+                    let member = members.iter().find(|m| m.label == name).ok_or_else(|| {
+                        Error::custom(format!("{} has no member {name:?}", ty.label))
+                    })?;
+                    slot = slot.wrapping_add(member.slot);
+                    offset = member.offset as usize;
+                    id = &member.ty;
+                }
+                (Segment::Index(key), StorageEncoding::Mapping) => {
+                    let (Some(key_id), Some(value_id)) = (&ty.key, &ty.value) else {
+                        return Err(Error::custom(format!("malformed mapping type {id:?}")));
+                    };
+                    let mut preimage = mapping_key(self, key_id, key)?;
+                    preimage.extend_from_slice(&slot.to_be_bytes::<32>());
+                    slot = keccak256(preimage).into();
+                    offset = 0;
+                    id = value_id;
+                }
+                (Segment::Index(index), StorageEncoding::DynamicArray) => {
+                    let index = parse_index(index)?;
+                    let base_id = array_base(ty, id)?;
+                    let base = keccak256(slot.to_be_bytes::<32>()).into();
+                    (slot, offset) = element(self, base, base_id, index)?;
+                    id = base_id;
+                }
+                (Segment::Index(index), StorageEncoding::Inplace) if ty.base.is_some() => {
+                    let index = parse_index(index)?;
+                    let len = fixed_array_len(id)?;
+                    if index >= U256::from(len) {
+                        return Err(Error::custom(format!(
+                            "index {index} out of bounds for {}",
+                            ty.label
+                        )));
+                    }
+                    let base_id = array_base(ty, id)?;
+                    (slot, offset) = element(self, slot, base_id, index)?;
+                    id = base_id;
+                }
+                (Segment::Field(name), _) => {
+                    return Err(Error::custom(format!(
+                        "cannot access member {name:?} of {}",
+                        ty.label
+                    )))
+                }
+                (Segment::Index(_), _) => {
+                    return Err(Error::custom(format!("cannot index into {}", ty.label)))
+                }
+            }
+        }
+
+        let size = byte_size(get_type(self, id)?)?;
+        Ok(StorageLocation { slot: slot.into(), offset, size, ty: id.into() })
+    }
+
+    fn decode_storage<F>(&self, location: &StorageLocation, mut storage: F) -> Result<DynSolValue>
+    where
+        F: FnMut(B256) -> B256,
+    {
+        let slot = U256::from_be_bytes(location.slot.0);
+        decode(self, slot, location.offset, &location.ty, &mut storage)
+    }
+}
+
+fn get_type<'a>(layout: &'a StorageLayout, id: &str) -> Result<&'a StorageType> {
+    layout.ty(id).ok_or_else(|| Error::custom(format!("unknown storage type {id:?}")))
+}
+
+fn byte_size(ty: &StorageType) -> Result<usize> {
+    usize::try_from(ty.number_of_bytes)
+        .ok()
+        .filter(|&size| size > 0)
+        .ok_or_else(|| Error::custom(format!("invalid size of {}", ty.label)))
+}
+
+fn array_base<'a>(ty: &'a StorageType, id: &str) -> Result<&'a str> {
+    ty.base.as_deref().ok_or_else(|| Error::custom(format!("malformed array type {id:?}")))
+}
+
+fn parse_index(index: &str) -> Result<U256> {
+    index.parse().map_err(|_| Error::custom(format!("invalid array index {index:?}")))
+}
+
+/// Parses the length of a fixed-size array from its type identifier, such as
+/// `t_array(t_uint256)3_storage`.
+fn fixed_array_len(id: &str) -> Result<usize> {
+    id.rfind(')')
+        .map(|i| &id[i + 1..])
+        .and_then(|rest| rest.split('_').next())
+        .and_then(|len| len.parse().ok())
+        .ok_or_else(|| Error::custom(format!("malformed array type {id:?}")))
+}
+
+/// Returns the slot and offset of the element at the given index of an array
+/// starting at the given slot.
+fn element(
+    layout: &StorageLayout,
+    base: U256,
+    base_id: &str,
+    index: U256,
+) -> Result<(U256, usize)> {
+    let size = byte_size(get_type(layout, base_id)?)?;
+    Ok(if size < 32 {
+        let per_slot = U256::from(32 / size);
+        let offset = (index % per_slot).to::<usize>() * size;
+        (base.wrapping_add(index / per_slot), offset)
+    } else {
+        let slots = U256::from((size + 31) / 32);
+        (base.wrapping_add(index.wrapping_mul(slots)), 0)
+    })
+}
+
+/// Encodes a mapping key as it is hashed together with the mapping's slot.
+fn mapping_key(layout: &StorageLayout, key_id: &str, key: &str) -> Result<Vec<u8>> {
+    let ty = value_type(get_type(layout, key_id)?, key_id)?;
+    let value = match ty {
+        DynSolType::String => {
+            let key = key.strip_prefix('"').and_then(|k| k.strip_suffix('"')).unwrap_or(key);
+            DynSolValue::String(key.into())
+        }
+        // allow short addresses such as `0xabc`
+        DynSolType::Address => match key.strip_prefix("0x") {
+            Some(digits) if digits.len() < 40 && digits.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                DynSolValue::Address(Address::from_slice(&hex::decode(format!("{digits:0>40}"))?))
+            }
+            _ => ty.coerce_str(key)?,
+        },
+        ty => ty.coerce_str(key)?,
+    };
+    Ok(match value {
+        DynSolValue::String(s) => s.into_bytes(),
+        DynSolValue::Bytes(b) => b,
+        value => value.abi_encode(),
+    })
+}
+
+/// Returns the Solidity type of a value type, or of `bytes` and `string`.
+fn value_type(ty: &StorageType, id: &str) -> Result<DynSolType> {
+    let label = ty.label.as_str();
+    let bits = || byte_size(ty).map(|size| size * 8);
+    if label.starts_with("contract ") || label.starts_with("address") {
+        Ok(DynSolType::Address)
+    } else if label.starts_with("enum ") || id.starts_with("t_userDefinedValueType") {
+        bits().map(DynSolType::Uint)
+    } else if label.starts_with("function ") {
+        // internal function pointers are 8 byte code offsets
+        if label.contains(" external") {
+            Ok(DynSolType::Function)
+        } else {
+            bits().map(DynSolType::Uint)
+        }
+    } else {
+        match label.parse() {
+            Ok(
+                ty @ (DynSolType::Bool
+                | DynSolType::Int(_)
+                | DynSolType::Uint(_)
+                | DynSolType::FixedBytes(_)
+                | DynSolType::Address
+                | DynSolType::Bytes
+                | DynSolType::String),
+            ) => Ok(ty),
+            _ => Err(Error::custom(format!("unsupported storage type {label:?}"))),
+        }
+    }
+}
+
+fn decode<F>(
+    layout: &StorageLayout,
+    slot: U256,
+    offset: usize,
+    id: &str,
+    storage: &mut F,
+) -> Result<DynSolValue>
+where
+    F: FnMut(B256) -> B256,
+{
+    let ty = get_type(layout, id)?;
+    match ty.encoding {
+        StorageEncoding::Inplace => {
+            if let Some(members) = &ty.members {
+                members
+                    .iter()
+                    .map(|member| {
+                        let slot = slot.wrapping_add(member.slot);
+                        decode(layout, slot, member.offset as usize, &member.ty, storage)
+                    })
+                    .collect::<Result<_>>()
+                    .map(DynSolValue::Tuple)
+            } else if ty.base.is_some() {
+                let len = fixed_array_len(id)?;
+                decode_elements(layout, slot, array_base(ty, id)?, len, storage)
+                    .map(DynSolValue::FixedArray)
+            } else {
+                let size = byte_size(ty)?;
+                if offset + size > 32 {
+                    return Err(Error::custom(format!("invalid offset of {}", ty.label)));
+                }
+                let word = storage(slot.into());
+                let location = StorageLocation { slot: slot.into(), offset, size, ty: id.into() };
+                decode_word(&value_type(ty, id)?, location.extract(&word))
+            }
+        }
+        StorageEncoding::Bytes => {
+            let bytes = decode_bytes(slot, storage)?;
+            match value_type(ty, id)? {
+                DynSolType::String => String::from_utf8(bytes)
+                    .map(DynSolValue::String)
+                    .map_err(|_| Error::custom("invalid UTF-8 in storage string")),
+                _ => Ok(DynSolValue::Bytes(bytes)),
+            }
+        }
+        StorageEncoding::DynamicArray => {
+            let len = U256::from_be_bytes(storage(slot.into()).0);
+            let len = checked_length(len)?;
+            let base = keccak256(slot.to_be_bytes::<32>()).into();
+            decode_elements(layout, base, array_base(ty, id)?, len, storage).map(DynSolValue::Array)
+        }
+        StorageEncoding::Mapping => {
+            Err(Error::custom(format!("cannot decode {} from storage", ty.label)))
+        }
+    }
+}
+
+fn decode_elements<F>(
+    layout: &StorageLayout,
+    base: U256,
+    base_id: &str,
+    len: usize,
+    storage: &mut F,
+) -> Result<Vec<DynSolValue>>
+where
+    F: FnMut(B256) -> B256,
+{
+    (0..len)
+        .map(|index| {
+            let (slot, offset) = element(layout, base, base_id, U256::from(index))?;
+            decode(layout, slot, offset, base_id, storage)
+        })
+        .collect()
+}
+
+/// Decodes a `bytes` or `string` value, which is stored in its slot if it is
+/// shorter than 32 bytes, and in consecutive slots starting at
+/// `keccak256(slot)` otherwise.
+fn decode_bytes<F>(slot: U256, storage: &mut F) -> Result<Vec<u8>>
+where
+    F: FnMut(B256) -> B256,
+{
+    let word = storage(slot.into());
+    if word[31] & 1 == 0 {
+        let len = (word[31] / 2) as usize;
+        if len > 31 {
+            return Err(Error::custom(format!("invalid short storage bytes length {len}")));
+        }
+        if word[len..31].iter().any(|&b| b != 0) {
+            return Err(Error::custom("malformed short storage bytes"));
+        }
+        return Ok(word[..len].to_vec());
+    }
+
+    let len = checked_length(U256::from_be_bytes(word.0) >> 1)?;
+    if len < 32 {
+        return Err(Error::custom("malformed long storage bytes"));
+    }
+    let base = U256::from_be_bytes(keccak256(slot.to_be_bytes::<32>()).0);
+    let mut bytes = Vec::with_capacity(len);
+    let mut i = U256::ZERO;
+    while bytes.len() < len {
+        let word = storage(base.wrapping_add(i).into());
+        let remaining = len - bytes.len();
+        bytes.extend_from_slice(&word[..remaining.min(32)]);
+        i += U256::from(1);
+    }
+    Ok(bytes)
+}
+
+fn checked_length(len: U256) -> Result<usize> {
+    usize::try_from(len)
+        .ok()
+        .filter(|&len| len <= MAX_LENGTH)
+        .ok_or_else(|| Error::custom(format!("storage length {len} is too large")))
+}
+
+/// Decodes a value type from its big-endian bytes within a slot.
+fn decode_word(ty: &DynSolType, bytes: &[u8]) -> Result<DynSolValue> {
+    let mut word = B256::ZERO;
+    Ok(match *ty {
+        DynSolType::Bool => DynSolValue::Bool(bytes.iter().any(|&b| b != 0)),
+        DynSolType::Uint(bits) => DynSolValue::Uint(U256::from_be_slice(bytes), bits),
+        DynSolType::Int(bits) => {
+            if bytes.first().map_or(false, |&b| b & 0x80 != 0) {
+                word = B256::repeat_byte(0xff);
+            }
+            word[32 - bytes.len()..].copy_from_slice(bytes);
+            DynSolValue::Int(I256::from_raw(U256::from_be_bytes(word.0)), bits)
+        }
+        DynSolType::Address if bytes.len() == 20 => {
+            DynSolValue::Address(Address::from_slice(bytes))
+        }
+        DynSolType::Function if bytes.len() == 24 => {
+            DynSolValue::Function(Function::from_slice(bytes))
+        }
+        DynSolType::FixedBytes(size) if bytes.len() == size => {
+            word[..size].copy_from_slice(bytes);
+            DynSolValue::FixedBytes(word, size)
+        }
+        _ => return Err(Error::custom(format!("cannot decode {ty} from {} bytes", bytes.len()))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloy_primitives::{address, hex};
+
+    // contract C {
+    //     uint128 a;                                  // slot 0, offset 0
+    //     int64 b;                                    // slot 0, offset 16
+    //     bool c;                                     // slot 0, offset 24
+    //     struct Amount { uint8 kind; uint256 value; }
+    //     struct Account { uint256 balance; Amount[] amounts; }
+    //     mapping(address => Account) balances;       // slot 1
+    //     string name;                                // slot 2
+    //     uint16[20] small;                           // slots 3-4
+    //     bytes data;                                 // slot 5
+    //     mapping(string => bytes4) selectors;        // slot 6
+    // }
+    const LAYOUT: &str = r#"{
+        "storage": [
+            { "astId": 1, "contract": "C.sol:C", "label": "a", "offset": 0, "slot": "0", "type": "t_uint128" },
+            { "astId": 2, "contract": "C.sol:C", "label": "b", "offset": 16, "slot": "0", "type": "t_int64" },
+            { "astId": 3, "contract": "C.sol:C", "label": "c", "offset": 24, "slot": "0", "type": "t_bool" },
+            { "astId": 4, "contract": "C.sol:C", "label": "balances", "offset": 0, "slot": "1", "type": "t_mapping(t_address,t_struct(Account)10_storage)" },
+            { "astId": 5, "contract": "C.sol:C", "label": "name", "offset": 0, "slot": "2", "type": "t_string_storage" },
+            { "astId": 6, "contract": "C.sol:C", "label": "small", "offset": 0, "slot": "3", "type": "t_array(t_uint16)20_storage" },
+            { "astId": 7, "contract": "C.sol:C", "label": "data", "offset": 0, "slot": "5", "type": "t_bytes_storage" },
+            { "astId": 8, "contract": "C.sol:C", "label": "selectors", "offset": 0, "slot": "6", "type": "t_mapping(t_string_memory_ptr,t_bytes4)" }
+        ],
+        "types": {
+            "t_address": { "encoding": "inplace", "label": "address", "numberOfBytes": "20" },
+            "t_array(t_struct(Amount)7_storage)dyn_storage": { "base": "t_struct(Amount)7_storage", "encoding": "dynamic_array", "label": "struct C.Amount[]", "numberOfBytes": "32" },
+            "t_array(t_uint16)20_storage": { "base": "t_uint16", "encoding": "inplace", "label": "uint16[20]", "numberOfBytes": "64" },
+            "t_bool": { "encoding": "inplace", "label": "bool", "numberOfBytes": "1" },
+            "t_bytes4": { "encoding": "inplace", "label": "bytes4", "numberOfBytes": "4" },
+            "t_bytes_storage": { "encoding": "bytes", "label": "bytes", "numberOfBytes": "32" },
+            "t_int64": { "encoding": "inplace", "label": "int64", "numberOfBytes": "8" },
+            "t_mapping(t_address,t_struct(Account)10_storage)": { "encoding": "mapping", "key": "t_address", "label": "mapping(address => struct C.Account)", "numberOfBytes": "32", "value": "t_struct(Account)10_storage" },
+            "t_mapping(t_string_memory_ptr,t_bytes4)": { "encoding": "mapping", "key": "t_string_memory_ptr", "label": "mapping(string => bytes4)", "numberOfBytes": "32", "value": "t_bytes4" },
+            "t_string_memory_ptr": { "encoding": "bytes", "label": "string", "numberOfBytes": "32" },
+            "t_string_storage": { "encoding": "bytes", "label": "string", "numberOfBytes": "32" },
+            "t_struct(Account)10_storage": {
+                "encoding": "inplace", "label": "struct C.Account", "numberOfBytes": "64",
+                "members": [
+                    { "astId": 8, "contract": "C.sol:C", "label": "balance", "offset": 0, "slot": "0", "type": "t_uint256" },
+                    { "astId": 9, "contract": "C.sol:C", "label": "amounts", "offset": 0, "slot": "1", "type": "t_array(t_struct(Amount)7_storage)dyn_storage" }
+                ]
+            },
+            "t_struct(Amount)7_storage": {
+                "encoding": "inplace", "label": "struct C.Amount", "numberOfBytes": "64",
+                "members": [
+                    { "astId": 5, "contract": "C.sol:C", "label": "kind", "offset": 0, "slot": "0", "type": "t_uint8" },
+                    { "astId": 6, "contract": "C.sol:C", "label": "value", "offset": 0, "slot": "1", "type": "t_uint256" }
+                ]
+            },
+            "t_uint128": { "encoding": "inplace", "label": "uint128", "numberOfBytes": "16" },
+            "t_uint16": { "encoding": "inplace", "label": "uint16", "numberOfBytes": "2" },
+            "t_uint256": { "encoding": "inplace", "label": "uint256", "numberOfBytes": "32" },
+            "t_uint8": { "encoding": "inplace", "label": "uint8", "numberOfBytes": "1" }
+        }
+    }"#;
+
+    const HOLDER: Address = address!("0000000000000000000000000000000000000abc");
+
+    fn layout() -> StorageLayout {
+        serde_json::from_str(LAYOUT).unwrap()
+    }
+
+    fn slot(n: u64) -> B256 {
+        B256::from(U256::from(n))
+    }
+
+    fn word(n: U256) -> B256 {
+        B256::from(n)
+    }
+
+    /// `keccak256(slot) + i`
+    fn dynamic(slot: B256, i: u64) -> B256 {
+        word(U256::from_be_bytes(keccak256(slot).0) + U256::from(i))
+    }
+
+    fn account_slot() -> B256 {
+        keccak256([&HOLDER.into_word()[..], &slot(1)[..]].concat())
+    }
+
+    #[test]
+    fn resolve() {
+        let layout = layout();
+        let location = |path| {
+            let l = layout.resolve(path).unwrap();
+            (l.slot, l.offset, l.size)
+        };
+
+        assert_eq!(location("a"), (slot(0), 0, 16));
+        assert_eq!(location("b"), (slot(0), 16, 8));
+        assert_eq!(location("c"), (slot(0), 24, 1));
+        assert_eq!(location("name"), (slot(2), 0, 32));
+        assert_eq!(location("small"), (slot(3), 0, 64));
+        assert_eq!(location("small[0]"), (slot(3), 0, 2));
+        assert_eq!(location("small[17]"), (slot(4), 2, 2));
+
+        let account = account_slot();
+        assert_eq!(location("balances[0xabc]"), (account, 0, 64));
+        assert_eq!(
+            location("balances[0x0000000000000000000000000000000000000abc]"),
+            (account, 0, 64)
+        );
+        assert_eq!(location("balances[0xabc].balance"), (account, 0, 32));
+        let amounts = word(U256::from_be_bytes(account.0) + U256::from(1));
+        assert_eq!(location("balances[0xabc].amounts"), (amounts, 0, 32));
+        // each `Amount` occupies 2 slots
+        assert_eq!(location("balances[0xabc].amounts[3].kind"), (dynamic(amounts, 6), 0, 1));
+        assert_eq!(location("balances[0xabc].amounts[3].value"), (dynamic(amounts, 7), 0, 32));
+
+        let key = keccak256([&b"transfer"[..], &slot(6)[..]].concat());
+        assert_eq!(location(r#"selectors["transfer"]"#), (key, 0, 4));
+        assert_eq!(location("selectors[transfer]"), (key, 0, 4));
+
+        for path in [
+            "missing",
+            "a.b",
+            "a[0]",
+            "small[20]",
+            "small[x]",
+            "balances[0xabc].missing",
+            "balances[notanaddress]",
+            "balances.balance",
+            "balances[",
+        ] {
+            assert!(layout.resolve(path).is_err(), "{path}");
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let layout = layout();
+        let mut storage = BTreeMap::new();
+
+        // a = 5, b = -2, c = true
+        let packed = U256::from(5) | (U256::from(u64::MAX - 1) << 128) | (U256::from(1) << 192);
+        storage.insert(slot(0), word(packed));
+        // name = "short"
+        let mut name = B256::ZERO;
+        name[..5].copy_from_slice(b"short");
+        name[31] = 5 * 2;
+        storage.insert(slot(2), name);
+        // small[1] = 7, small[17] = 9
+        storage.insert(slot(3), word(U256::from(7) << 16));
+        storage.insert(slot(4), word(U256::from(9) << 16));
+        // data = 40 bytes
+        let data: Vec<u8> = (0..40).collect();
+        storage.insert(slot(5), word(U256::from(40 * 2 + 1)));
+        storage.insert(dynamic(slot(5), 0), B256::from_slice(&data[..32]));
+        storage.insert(dynamic(slot(5), 1), B256::right_padding_from(&data[32..]));
+        // balances[0xabc] = Account(100, [Amount(1, 2)])
+        let account = account_slot();
+        let amounts = word(U256::from_be_bytes(account.0) + U256::from(1));
+        storage.insert(account, word(U256::from(100)));
+        storage.insert(amounts, word(U256::from(1)));
+        storage.insert(dynamic(amounts, 0), word(U256::from(1)));
+        storage.insert(dynamic(amounts, 1), word(U256::from(2)));
+
+        let read = |path| {
+            layout.read_path(path, |slot| storage.get(&slot).copied().unwrap_or_default()).unwrap()
+        };
+
+        assert_eq!(read("a"), DynSolValue::Uint(U256::from(5), 128));
+        assert_eq!(read("b"), DynSolValue::Int(I256::try_from(-2).unwrap(), 64));
+        assert_eq!(read("c"), DynSolValue::Bool(true));
+        assert_eq!(read("name"), DynSolValue::String("short".into()));
+        assert_eq!(read("data"), DynSolValue::Bytes(data));
+        assert_eq!(read("small[1]"), DynSolValue::Uint(U256::from(7), 16));
+        let DynSolValue::FixedArray(small) = read("small") else { panic!() };
+        assert_eq!(small.len(), 20);
+        assert_eq!(small[17], DynSolValue::Uint(U256::from(9), 16));
+        assert_eq!(read("selectors[transfer]"), DynSolValue::FixedBytes(B256::ZERO, 4));
+
+        let amount = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 8),
+            DynSolValue::Uint(U256::from(2), 256),
+        ]);
+        assert_eq!(read("balances[0xabc].amounts[0]"), amount);
+        assert_eq!(
+            read("balances[0xabc]"),
+            DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(100), 256),
+                DynSolValue::Array(vec![amount]),
+            ])
+        );
+
+        let mut storage = |_| B256::ZERO;
+        assert!(layout.read_path("balances", &mut storage).is_err());
+        // a huge length
+        assert!(layout.read_path("data", |_| B256::repeat_byte(0xff)).is_err());
+        // a short length over 31 bytes
+        assert!(layout.read_path("data", |_| word(U256::from(64))).is_err());
+        assert!(layout.read_path("name", |_| word(U256::from(254))).is_err());
+        // invalid UTF-8
+        assert!(layout
+            .read_path("name", |_| hex!(
+                "ff00000000000000000000000000000000000000000000000000000000000002"
+            )
+            .into())
+            .is_err());
+    }
+}
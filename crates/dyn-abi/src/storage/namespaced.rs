@@ -0,0 +1,54 @@
+use alloy_primitives::{keccak256, B256, U256};
+
+/// Computes the root slot of an [ERC-7201] namespaced storage layout:
+/// `keccak256(keccak256(namespace) - 1) & ~0xff`.
+///
+/// [ERC-7201]: https://eips.ethereum.org/EIPS/eip-7201
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::storage::erc7201_slot;
+/// use alloy_primitives::b256;
+///
+/// assert_eq!(
+///     erc7201_slot("example.main"),
+///     b256!("183a6125c38840424c4a85fa12bab2ab606c4b6d0e7cc73c0c06ba5300eab500"),
+/// );
+/// ```
+pub fn erc7201_slot(namespace: &str) -> B256 {
+    let hash = U256::from_be_bytes(keccak256(namespace.as_bytes()).0).wrapping_sub(U256::from(1));
+    let mut slot = keccak256(hash.to_be_bytes::<32>());
+    slot[31] = 0;
+    slot
+}
+
+/// The standard proxy storage slots defined in [EIP-1967].
+///
+/// Each slot is `keccak256(<label>) - 1`. See [`alloy_sol_types::proxy`] for
+/// proxy detection and the other well-known proxy slots.
+///
+/// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+pub mod eip1967 {
+    #[doc(inline)]
+    pub use alloy_sol_types::proxy::{
+        EIP1967_ADMIN_SLOT as ADMIN_SLOT, EIP1967_BEACON_SLOT as BEACON_SLOT,
+        EIP1967_IMPLEMENTATION_SLOT as IMPLEMENTATION_SLOT, EIP1967_ROLLBACK_SLOT as ROLLBACK_SLOT,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erc7201() {
+        // OpenZeppelin `OwnableUpgradeable`
+        assert_eq!(
+            erc7201_slot("openzeppelin.storage.Ownable"),
+            alloy_primitives::b256!(
+                "9016d09d72d40fdae2fd8ceac6b6234c7706214fd39c1cd1e609a0528c199300"
+            )
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Error, Result};
+use alloc::vec::Vec;
+
+/// A segment of a storage path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    /// `.name`, or the leading state variable name.
+    Field(&'a str),
+    /// `[key]`, with the surrounding brackets removed.
+    Index(&'a str),
+}
+
+/// Parses a storage path such as `balances[0xabc].amounts[3].value`.
+///
+/// The first segment is always a [`Field`](Segment::Field). Keys may be quoted
+/// with `"`, in which case they can contain `]`.
+pub(crate) fn parse(path: &str) -> Result<Vec<Segment<'_>>> {
+    let invalid = |reason: &str| Error::custom(format!("invalid storage path {path:?}: {reason}"));
+
+    let mut segments = Vec::new();
+    let mut rest = path.trim();
+    let mut first = true;
+    while !rest.is_empty() || first {
+        if first || rest.starts_with('.') {
+            if !first {
+                rest = &rest[1..];
+            }
+            first = false;
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            let name = rest[..end].trim();
+            if !parser::is_valid_identifier(name) {
+                return Err(invalid("expected an identifier"));
+            }
+            segments.push(Segment::Field(name));
+            rest = &rest[end..];
+        } else if let Some(stripped) = rest.strip_prefix('[') {
+            let end = if let Some(quoted) = stripped.strip_prefix('"') {
+                let close = quoted.find('"').ok_or_else(|| invalid("unterminated string"))?;
+                close + 2
+            } else {
+                stripped.find(']').ok_or_else(|| invalid("unterminated index"))?
+            };
+            let (key, after) = stripped.split_at(end);
+            let after = after.strip_prefix(']').ok_or_else(|| invalid("unterminated index"))?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(invalid("empty index"));
+            }
+            segments.push(Segment::Index(key));
+            rest = after;
+        } else {
+            return Err(invalid("expected `.` or `[`"));
+        }
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_paths() {
+        use Segment::*;
+
+        assert_eq!(parse("owner").unwrap(), [Field("owner")]);
+        assert_eq!(
+            parse("balances[0xabc].amounts[3].value").unwrap(),
+            [Field("balances"), Index("0xabc"), Field("amounts"), Index("3"), Field("value")]
+        );
+        assert_eq!(
+            parse(r#"names["a]b"][ 1 ]"#).unwrap(),
+            [Field("names"), Index("\"a]b\""), Index("1")]
+        );
+        assert_eq!(parse("grid[1][2]").unwrap(), [Field("grid"), Index("1"), Index("2")]);
+
+        for path in ["", ".a", "a.", "a[", "a[]", "a[1", "a]", "1a", "a..b", r#"a["b]"#, "a[1]b"] {
+            assert!(parse(path).is_err(), "{path:?}");
+        }
+    }
+}
@@ -0,0 +1,406 @@
+use crate::{Decoder, DynSolType, DynSolValue, DynToken, Error, Result, Word};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloy_primitives::{Address, Function, I256, U256};
+
+/// A dynamic Solidity value which borrows its bytes and strings.
+///
+/// This is the borrowed counterpart of [`DynSolValue`], returned by
+/// [`DynSolType::abi_decode_ref`] and its variants, which borrow `bytes` and
+/// `string` values from the input data instead of allocating them. Strings
+/// are only allocated if they are not valid UTF-8, in which case they are
+/// decoded lossily, like [`DynSolType::abi_decode`] does.
+///
+/// Arrays and tuples still allocate their list of values.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolType, DynSolValue, DynSolValueRef};
+///
+/// let ty: DynSolType = "(bytes,string[])".parse()?;
+/// let value = DynSolValue::Tuple(vec![
+///     DynSolValue::Bytes(vec![1, 2, 3]),
+///     DynSolValue::Array(vec![DynSolValue::String("hello".into())]),
+/// ]);
+/// let data = value.abi_encode_params();
+///
+/// let decoded = ty.abi_decode_params_ref(&data)?;
+/// let fields = decoded.as_tuple().unwrap();
+/// assert_eq!(fields[0].as_bytes(), Some(&[1, 2, 3][..]));
+/// assert_eq!(fields[1].as_array().unwrap()[0].as_str(), Some("hello"));
+/// assert_eq!(decoded.to_owned(), value);
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynSolValueRef<'a> {
+    /// A boolean.
+    Bool(bool),
+    /// A signed integer.
+    Int(I256, usize),
+    /// An unsigned integer.
+    Uint(U256, usize),
+    /// A fixed-length byte string.
+    FixedBytes(Word, usize),
+    /// An address.
+    Address(Address),
+    /// A function pointer.
+    Function(Function),
+
+    /// A dynamic-length byte array.
+    Bytes(&'a [u8]),
+    /// A string.
+    String(Cow<'a, str>),
+
+    /// A dynamically-sized array of values.
+    Array(Vec<DynSolValueRef<'a>>),
+    /// A fixed-size array of values.
+    FixedArray(Vec<DynSolValueRef<'a>>),
+    /// A tuple of values.
+    Tuple(Vec<DynSolValueRef<'a>>),
+
+    /// A named struct, treated as a tuple with a name parameter.
+    #[cfg(feature = "eip712")]
+    CustomStruct {
+        /// The name of the struct.
+        name: &'a str,
+        /// The struct's prop names, in declaration order.
+        prop_names: &'a [String],
+        /// The inner types.
+        tuple: Vec<DynSolValueRef<'a>>,
+    },
+}
+
+impl From<DynSolValueRef<'_>> for DynSolValue {
+    #[inline]
+    fn from(value: DynSolValueRef<'_>) -> Self {
+        value.into_owned()
+    }
+}
+
+impl<'a> DynSolValueRef<'a> {
+    /// Converts this value into an owned [`DynSolValue`], copying its bytes
+    /// and strings.
+    pub fn to_owned(&self) -> DynSolValue {
+        self.clone().into_owned()
+    }
+
+    /// Converts this value into an owned [`DynSolValue`], copying its bytes
+    /// and strings, and reusing its lossily decoded strings.
+    pub fn into_owned(self) -> DynSolValue {
+        fn many(values: Vec<DynSolValueRef<'_>>) -> Vec<DynSolValue> {
+            values.into_iter().map(DynSolValueRef::into_owned).collect()
+        }
+
+        match self {
+            Self::Bool(b) => DynSolValue::Bool(b),
+            Self::Int(i, size) => DynSolValue::Int(i, size),
+            Self::Uint(u, size) => DynSolValue::Uint(u, size),
+            Self::FixedBytes(w, size) => DynSolValue::FixedBytes(w, size),
+            Self::Address(a) => DynSolValue::Address(a),
+            Self::Function(f) => DynSolValue::Function(f),
+            Self::Bytes(b) => DynSolValue::Bytes(b.to_vec()),
+            Self::String(s) => DynSolValue::String(s.into_owned()),
+            Self::Array(values) => DynSolValue::Array(many(values)),
+            Self::FixedArray(values) => DynSolValue::FixedArray(many(values)),
+            Self::Tuple(values) => DynSolValue::Tuple(many(values)),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => DynSolValue::CustomStruct {
+                name: name.into(),
+                prop_names: prop_names.to_vec(),
+                tuple: many(tuple),
+            },
+        }
+    }
+
+    /// Fallible cast to a single word. Will succeed for any single-word type.
+    #[inline]
+    pub fn as_word(&self) -> Option<Word> {
+        match *self {
+            Self::Bool(b) => Some(Word::with_last_byte(b as u8)),
+            Self::Int(i, _) => Some(i.into()),
+            Self::Uint(u, _) => Some(u.into()),
+            Self::FixedBytes(w, _) => Some(w),
+            Self::Address(a) => Some(a.into_word()),
+            Self::Function(f) => Some(f.into_word()),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_address(&self) -> Option<Address> {
+        match self {
+            Self::Address(a) => Some(*a),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant. The bytes are borrowed
+    /// from the decoded data.
+    #[inline]
+    pub const fn as_bytes(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Bytes(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_fixed_bytes(&self) -> Option<(&[u8], usize)> {
+        match self {
+            Self::FixedBytes(w, size) => Some((w.as_slice(), *size)),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_int(&self) -> Option<(I256, usize)> {
+        match self {
+            Self::Int(w, size) => Some((*w, *size)),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_uint(&self) -> Option<(U256, usize)> {
+        match self {
+            Self::Uint(u, size) => Some((*u, *size)),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub fn as_tuple(&self) -> Option<&[Self]> {
+        match self {
+            Self::Tuple(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            Self::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub fn as_fixed_array(&self) -> Option<&[Self]> {
+        match self {
+            Self::FixedArray(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn as_custom_struct(&self) -> Option<(&str, &[String], &[Self])> {
+        match self {
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => Some((name, prop_names, tuple)),
+            _ => None,
+        }
+    }
+}
+
+impl DynSolType {
+    /// Decodes a [`DynSolValueRef`] from a byte slice, borrowing its bytes and
+    /// strings. Fails if the value does not match this type.
+    ///
+    /// This is the borrowed version of [`abi_decode`](Self::abi_decode).
+    #[inline]
+    pub fn abi_decode_ref<'a>(&'a self, data: &'a [u8]) -> Result<DynSolValueRef<'a>> {
+        self.abi_decode_ref_inner(&mut Decoder::new(data, false), DynToken::decode_single_populate)
+    }
+
+    /// Decodes a [`DynSolValueRef`] from a byte slice, borrowing its bytes and
+    /// strings. Fails if the value does not match this type.
+    ///
+    /// This is the borrowed version of
+    /// [`abi_decode_params`](Self::abi_decode_params).
+    #[inline]
+    pub fn abi_decode_params_ref<'a>(&'a self, data: &'a [u8]) -> Result<DynSolValueRef<'a>> {
+        match self {
+            Self::Tuple(_) => self.abi_decode_sequence_ref(data),
+            _ => self.abi_decode_ref(data),
+        }
+    }
+
+    /// Decodes a [`DynSolValueRef`] from a byte slice, borrowing its bytes and
+    /// strings. Fails if the value does not match this type.
+    ///
+    /// This is the borrowed version of
+    /// [`abi_decode_sequence`](Self::abi_decode_sequence).
+    #[inline]
+    pub fn abi_decode_sequence_ref<'a>(&'a self, data: &'a [u8]) -> Result<DynSolValueRef<'a>> {
+        self.abi_decode_ref_inner(
+            &mut Decoder::new(data, false),
+            DynToken::decode_sequence_populate,
+        )
+    }
+
+    fn abi_decode_ref_inner<'a, F>(
+        &'a self,
+        decoder: &mut Decoder<'a>,
+        f: F,
+    ) -> Result<DynSolValueRef<'a>>
+    where
+        F: FnOnce(&mut DynToken<'a>, &mut Decoder<'a>) -> Result<()>,
+    {
+        if self.is_zst() {
+            return Ok(match self {
+                Self::Array(_) => DynSolValueRef::Array(vec![]),
+                Self::FixedArray(..) => DynSolValueRef::FixedArray(vec![]),
+                _ => DynSolValueRef::Tuple(vec![]),
+            });
+        }
+
+        let mut token = self.empty_dyn_token();
+        f(&mut token, decoder)?;
+        self.detokenize_ref(token)
+    }
+
+    /// Converts a token into a [`DynSolValueRef`], borrowing its packed
+    /// sequences.
+    fn detokenize_ref<'a>(&'a self, token: DynToken<'a>) -> Result<DynSolValueRef<'a>> {
+        fn many<'a>(
+            types: impl Iterator<Item = &'a DynSolType>,
+            tokens: Cow<'a, [DynToken<'a>]>,
+        ) -> Result<Vec<DynSolValueRef<'a>>> {
+            types.zip(tokens.into_owned()).map(|(ty, token)| ty.detokenize_ref(token)).collect()
+        }
+
+        match (self, token) {
+            (Self::Bytes, DynToken::PackedSeq(buf)) => Ok(DynSolValueRef::Bytes(buf)),
+            (Self::String, DynToken::PackedSeq(buf)) => {
+                Ok(DynSolValueRef::String(String::from_utf8_lossy(buf)))
+            }
+            (Self::Array(t), DynToken::DynSeq { contents, .. }) => {
+                many(core::iter::repeat(&**t).take(contents.len()), contents)
+                    .map(DynSolValueRef::Array)
+            }
+            (Self::FixedArray(t, size), DynToken::FixedSeq(tokens, _)) => {
+                if *size != tokens.len() {
+                    return Err(Error::custom("array length mismatch on dynamic detokenization"));
+                }
+                many(core::iter::repeat(&**t).take(*size), tokens).map(DynSolValueRef::FixedArray)
+            }
+            (Self::Tuple(types), DynToken::FixedSeq(tokens, _)) => {
+                if types.len() != tokens.len() {
+                    return Err(Error::custom("tuple length mismatch on dynamic detokenization"));
+                }
+                many(types.iter(), tokens).map(DynSolValueRef::Tuple)
+            }
+            #[cfg(feature = "eip712")]
+            (Self::CustomStruct { name, tuple, prop_names }, DynToken::FixedSeq(tokens, len)) => {
+                if len != tokens.len() || len != tuple.len() {
+                    return Err(Error::custom("custom length mismatch on dynamic detokenization"));
+                }
+                many(tuple.iter(), tokens).map(|tuple| DynSolValueRef::CustomStruct {
+                    name,
+                    prop_names,
+                    tuple,
+                })
+            }
+            (_, token @ DynToken::Word(_)) => Ok(match self.detokenize(token)? {
+                DynSolValue::Bool(b) => DynSolValueRef::Bool(b),
+                DynSolValue::Int(i, size) => DynSolValueRef::Int(i, size),
+                DynSolValue::Uint(u, size) => DynSolValueRef::Uint(u, size),
+                DynSolValue::FixedBytes(w, size) => DynSolValueRef::FixedBytes(w, size),
+                DynSolValue::Address(a) => DynSolValueRef::Address(a),
+                DynSolValue::Function(f) => DynSolValueRef::Function(f),
+                _ => unreachable!("words decode to single-word values"),
+            }),
+            _ => Err(Error::custom("mismatched types on dynamic detokenization")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn borrowed() {
+        let ty: DynSolType =
+            "(uint8,address,bytes,string,(bool,bytes)[],string[2])".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(7), 8),
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+            DynSolValue::Bytes(vec![0xde, 0xad]),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![DynSolValue::Bool(true), DynSolValue::Bytes(vec![1; 40])]),
+                DynSolValue::Tuple(vec![DynSolValue::Bool(false), DynSolValue::Bytes(vec![])]),
+            ]),
+            DynSolValue::FixedArray(vec![
+                DynSolValue::String("a".into()),
+                DynSolValue::String("b".into()),
+            ]),
+        ]);
+        let data = value.abi_encode_params();
+
+        let decoded = ty.abi_decode_params_ref(&data).unwrap();
+        assert_eq!(decoded.to_owned(), ty.abi_decode_params(&data).unwrap());
+        assert_eq!(decoded.clone().into_owned(), value);
+
+        let fields = decoded.as_tuple().unwrap();
+        let in_data = |bytes: &[u8]| data.as_ptr_range().contains(&bytes.as_ptr());
+        assert!(in_data(fields[2].as_bytes().unwrap()));
+        assert!(
+            matches!(&fields[3], DynSolValueRef::String(Cow::Borrowed(s)) if in_data(s.as_bytes()))
+        );
+        let inner = fields[4].as_array().unwrap()[0].as_tuple().unwrap();
+        assert!(in_data(inner[1].as_bytes().unwrap()));
+
+        let ty = DynSolType::Bytes;
+        let data = DynSolValue::Bytes(vec![2; 3]).abi_encode();
+        assert_eq!(ty.abi_decode_ref(&data).unwrap(), DynSolValueRef::Bytes(&[2; 3]));
+    }
+
+    #[test]
+    fn lossy_and_empty() {
+        let ty = DynSolType::String;
+        let data = DynSolValue::Bytes(vec![b'a', 0xff]).abi_encode();
+        let decoded = ty.abi_decode_ref(&data).unwrap();
+        assert!(matches!(decoded, DynSolValueRef::String(Cow::Owned(_))));
+        assert_eq!(decoded.into_owned(), ty.abi_decode(&data).unwrap());
+
+        let ty = DynSolType::Tuple(vec![
+            DynSolType::FixedArray(Box::new(DynSolType::Uint(256)), 0),
+            DynSolType::Tuple(vec![]),
+        ]);
+        let decoded = ty.abi_decode_params_ref(&[]).unwrap();
+        assert_eq!(decoded.into_owned(), ty.abi_decode_params(&[]).unwrap());
+
+        let ty: DynSolType = "(uint256,string)".parse().unwrap();
+        ty.abi_decode_params_ref(&[0; 32]).unwrap_err();
+    }
+}
@@ -1,8 +1,12 @@
-use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
+use crate::{
+    human_readable::{self, parse_struct, Structs},
+    AbiItem, Bytecode, Constructor, Error, Event, Fallback, Function, LinkError, LinkReferences,
+    Receive,
+};
 use alloc::{collections::btree_map, string::String, vec::Vec};
-use alloy_primitives::Bytes;
+use alloy_primitives::{bytecode::CompilerMetadata, Address, Bytes};
 use btree_map::BTreeMap;
-use core::{fmt, iter, iter::Flatten};
+use core::{borrow::Borrow, fmt, iter, iter::Flatten};
 use serde::{
     de::{MapAccess, SeqAccess, Visitor},
     ser::SerializeSeq,
@@ -90,16 +94,76 @@ impl JsonAbi {
     /// assert_eq!(abi.len(), 9);
     /// # Ok::<(), alloy_sol_type_parser::Error>(())
     /// ```
+    ///
+    /// State mutability, tuple component names, and struct definitions are
+    /// supported. Structs may be referenced by name in any item, and may be
+    /// defined before or after their use:
+    ///
+    /// ```
+    /// # use alloy_json_abi::{InternalType, JsonAbi, StateMutability};
+    /// let abi = JsonAbi::parse([
+    ///     "struct Person { string name; uint16 age; }",
+    ///     "function getPerson(uint id) external view returns (Person)",
+    ///     "function addPeople(Person[] people) payable",
+    /// ])?;
+    /// let get_person = &abi.function("getPerson").unwrap()[0];
+    /// assert_eq!(get_person.state_mutability, StateMutability::View);
+    /// assert_eq!(get_person.outputs[0].ty, "tuple");
+    /// assert_eq!(get_person.outputs[0].components[1].name, "age");
+    /// assert_eq!(abi.function("addPeople").unwrap()[0].signature(), "addPeople((string,uint16)[])");
+    /// assert_eq!(
+    ///     abi.function("addPeople").unwrap()[0].inputs[0].internal_type,
+    ///     Some(InternalType::Struct { contract: None, ty: "Person[]".into() }),
+    /// );
+    /// # Ok::<(), alloy_sol_type_parser::Error>(())
+    /// ```
     pub fn parse<'a, I: IntoIterator<Item = &'a str>>(strings: I) -> parser::Result<Self> {
-        let mut abi = Self::new();
+        let mut structs = Structs::default();
+        let mut items = Vec::new();
         for string in strings {
-            let item = AbiItem::parse(string)?;
+            match parse_struct(string) {
+                Some(def) => def.and_then(|(name, fields)| structs.insert(name, fields))?,
+                None => items.push(AbiItem::parse(string)?),
+            }
+        }
+
+        let mut abi = Self::new();
+        for mut item in items {
+            structs.resolve_item(&mut item)?;
             abi.insert_item(item)
                 .map_err(|s| parser::Error::_new("duplicate JSON ABI field: ", &s))?;
         }
         Ok(abi)
     }
 
+    /// Formats this ABI as a [Human-Readable ABI], which can be parsed back
+    /// with [`parse`](Self::parse).
+    ///
+    /// Struct parameters are formatted by name, and their definitions are
+    /// emitted first. Other tuples are formatted inline with their component
+    /// names. Internal types other than struct names, and the contract
+    /// qualifiers of struct names, are not part of the format.
+    ///
+    /// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::JsonAbi;
+    /// let strings = [
+    ///     "struct Person { string name; uint16 age; }",
+    ///     "function getPerson(uint256 id) view returns (Person person)",
+    ///     "event PersonAdded(uint256 indexed id, tuple(string name, uint16 age) person)",
+    /// ];
+    /// let abi = JsonAbi::parse(strings)?;
+    /// assert_eq!(abi.to_human_readable(), strings);
+    /// assert_eq!(JsonAbi::parse(abi.to_human_readable().iter().map(String::as_str))?, abi);
+    /// # Ok::<(), alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn to_human_readable(&self) -> Vec<String> {
+        human_readable::format(self)
+    }
+
     /// Parse a JSON string into an ABI object.
     ///
     /// This is a convenience wrapper around [`serde_json::from_str`].
@@ -496,6 +560,9 @@ impl<'de> Visitor<'de> for JsonAbiVisitor {
 ///
 /// Can be deserialized from both an ABI array, and a JSON object with the `abi`
 /// field with optionally the bytecode fields.
+///
+/// The bytecode may be [unlinked](Bytecode::Unlinked), in which case it has to
+/// be [linked](Self::link_all) before it can be deployed.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractObject {
@@ -504,10 +571,90 @@ pub struct ContractObject {
     pub abi: Option<JsonAbi>,
     /// The contract bytecode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bytecode: Option<Bytes>,
+    pub bytecode: Option<Bytecode>,
     /// The contract deployed bytecode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deployed_bytecode: Option<Bytes>,
+    pub deployed_bytecode: Option<Bytecode>,
+}
+
+impl ContractObject {
+    /// Links the given library into both the bytecode and the deployed
+    /// bytecode.
+    ///
+    /// Returns `true` if any placeholders were replaced. See
+    /// [`UnlinkedBytecode::link`](crate::UnlinkedBytecode::link) for more
+    /// details.
+    pub fn link(&mut self, library: &str, address: Address) -> bool {
+        let mut linked = false;
+        for bytecode in [&mut self.bytecode, &mut self.deployed_bytecode].into_iter().flatten() {
+            linked |= bytecode.link(library, address);
+        }
+        linked
+    }
+
+    /// Links all the given libraries into both the bytecode and the deployed
+    /// bytecode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the missing libraries if either bytecode is
+    /// not fully linked afterwards.
+    pub fn link_all<I, K, A>(&mut self, libraries: I) -> Result<(), LinkError>
+    where
+        I: IntoIterator<Item = (K, A)>,
+        K: AsRef<str>,
+        A: Borrow<Address>,
+    {
+        let libraries: Vec<_> = libraries.into_iter().collect();
+        let mut missing = Vec::new();
+        for bytecode in [&mut self.bytecode, &mut self.deployed_bytecode].into_iter().flatten() {
+            for (library, address) in &libraries {
+                bytecode.link(library.as_ref(), *address.borrow());
+            }
+            for library in bytecode.missing_libraries() {
+                if !missing.contains(&library) {
+                    missing.push(library);
+                }
+            }
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(LinkError::new(missing))
+        }
+    }
+
+    /// Decodes the [compiler metadata] appended to the deployed bytecode, if
+    /// it is present and linked.
+    ///
+    /// [compiler metadata]: CompilerMetadata
+    #[inline]
+    pub fn metadata(&self) -> Option<CompilerMetadata> {
+        self.deployed_bytecode.as_ref()?.as_bytes()?.metadata()
+    }
+
+    /// Returns the deployed bytecode without the [compiler metadata] appended
+    /// to it, if it is present and linked.
+    ///
+    /// [compiler metadata]: CompilerMetadata
+    #[inline]
+    pub fn deployed_bytecode_without_metadata(&self) -> Option<Bytes> {
+        self.deployed_bytecode.as_ref()?.as_bytes().map(Bytes::strip_metadata)
+    }
+
+    /// Returns `true` if the given runtime code matches the deployed bytecode,
+    /// ignoring any [compiler metadata] appended to either.
+    ///
+    /// Returns `false` if the deployed bytecode is not present or not linked.
+    ///
+    /// [compiler metadata]: CompilerMetadata
+    #[inline]
+    pub fn matches_deployed_bytecode(&self, code: &[u8]) -> bool {
+        self.deployed_bytecode
+            .as_ref()
+            .and_then(Bytecode::as_bytes)
+            .map_or(false, |deployed| deployed.eq_ignoring_metadata(code))
+    }
 }
 
 impl<'de> Deserialize<'de> for ContractObject {
@@ -539,32 +686,6 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
     }
 
     fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum Bytecode {
-            Bytes(Bytes),
-            Object { object: Bytes },
-            Unlinked(String),
-            UnlinkedObject { object: String },
-        }
-
-        impl Bytecode {
-            fn ensure_bytes<E: serde::de::Error>(self) -> Result<Bytes, E> {
-                match self {
-                    Bytecode::Bytes(bytes) | Bytecode::Object { object: bytes } => Ok(bytes),
-                    Bytecode::Unlinked(unlinked)
-                    | Bytecode::UnlinkedObject { object: unlinked } => {
-                        if let Some((_, unlinked)) = unlinked.split_once("__$") {
-                            if let Some((addr, _)) = unlinked.split_once("$__") {
-                                return Err(E::custom(format!("expected bytecode, found unlinked bytecode with placeholder: {addr}")));
-                            }
-                        }
-                        Err(E::custom("invalid contract bytecode"))
-                    }
-                }
-            }
-        }
-
         /// Represents nested bytecode objects of the `evm` value.
         #[derive(Deserialize)]
         struct EvmObj {
@@ -576,6 +697,8 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
         let mut abi = None;
         let mut bytecode = None;
         let mut deployed_bytecode = None;
+        let mut link_references = None;
+        let mut deployed_link_references = None;
 
         while let Some(key) = map.next_key::<&str>()? {
             match key {
@@ -583,18 +706,25 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
                 "evm" => {
                     let evm = map.next_value::<EvmObj>()?;
                     if let Some(bytes) = evm.bytecode {
-                        set_if_none!(@serde bytecode, bytes.ensure_bytes()?);
+                        set_if_none!(@serde bytecode, bytes);
                     }
                     if let Some(bytes) = evm.deployed_bytecode {
-                        set_if_none!(@serde deployed_bytecode, bytes.ensure_bytes()?);
+                        set_if_none!(@serde deployed_bytecode, bytes);
                     }
                 }
                 "bytecode" | "bin" => {
-                    set_if_none!(@serde bytecode, map.next_value::<Bytecode>()?.ensure_bytes()?);
+                    set_if_none!(@serde bytecode, map.next_value::<Bytecode>()?);
                 }
                 "deployedBytecode" | "deployedbytecode" | "deployed_bytecode" | "runtimeBin"
                 | "runtimebin" | "runtime " => {
-                    set_if_none!(@serde deployed_bytecode, map.next_value::<Bytecode>()?.ensure_bytes()?);
+                    set_if_none!(@serde deployed_bytecode, map.next_value::<Bytecode>()?);
+                }
+                // Hardhat artifacts
+                "linkReferences" => {
+                    set_if_none!(@serde link_references, map.next_value::<LinkReferences>()?);
+                }
+                "deployedLinkReferences" => {
+                    set_if_none!(@serde deployed_link_references, map.next_value::<LinkReferences>()?);
                 }
                 _ => {
                     map.next_value::<serde::de::IgnoredAny>()?;
@@ -602,6 +732,16 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
             }
         }
 
+        for (bytecode, references) in
+            [(&mut bytecode, link_references), (&mut deployed_bytecode, deployed_link_references)]
+        {
+            if let (Some(Bytecode::Unlinked(unlinked)), Some(references)) = (bytecode, references) {
+                if unlinked.link_references.is_empty() {
+                    unlinked.link_references = references;
+                }
+            }
+        }
+
         Ok(ContractObject { abi, bytecode, deployed_bytecode })
     }
 }
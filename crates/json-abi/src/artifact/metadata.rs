@@ -0,0 +1,183 @@
+use super::{DevDoc, UserDoc};
+use crate::JsonAbi;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::{bytecode::Version, Address, B256};
+use serde::{Deserialize, Serialize};
+
+/// The contract metadata JSON file emitted by the Solidity compiler, whose
+/// hash is embedded in the bytecode.
+///
+/// See the [Solidity documentation] for more details.
+///
+/// [Solidity documentation]: https://docs.soliditylang.org/en/latest/metadata.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractMetadata {
+    /// The compiler which produced the contract.
+    #[serde(default)]
+    pub compiler: MetadataCompiler,
+    /// The source language, such as `Solidity` or `Yul`.
+    #[serde(default)]
+    pub language: String,
+    /// The contract's ABI and NatSpec documentation.
+    #[serde(default)]
+    pub output: MetadataOutput,
+    /// The compiler settings.
+    #[serde(default)]
+    pub settings: MetadataSettings,
+    /// The source files, keyed by their names.
+    #[serde(default)]
+    pub sources: BTreeMap<String, MetadataSource>,
+    /// The version of the metadata format. Always `1`.
+    #[serde(default)]
+    pub version: u32,
+}
+
+impl ContractMetadata {
+    /// Parses the version of the compiler which produced the contract.
+    #[inline]
+    pub fn compiler_version(&self) -> Option<Version> {
+        self.compiler.version.parse().ok()
+    }
+
+    /// Returns the source file name and the name of the compiled contract.
+    #[inline]
+    pub fn compilation_target(&self) -> Option<(&str, &str)> {
+        self.settings.compilation_target.iter().next().map(|(file, name)| (&file[..], &name[..]))
+    }
+}
+
+/// The compiler in [`ContractMetadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MetadataCompiler {
+    /// The full compiler version, such as `0.8.20+commit.a1b79de6`.
+    pub version: String,
+}
+
+/// The output in [`ContractMetadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataOutput {
+    /// The contract ABI.
+    #[serde(default)]
+    pub abi: JsonAbi,
+    /// The user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<UserDoc>,
+    /// The developer documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<DevDoc>,
+}
+
+/// The compiler settings in [`ContractMetadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataSettings {
+    /// Maps the source file name to the name of the compiled contract.
+    #[serde(default)]
+    pub compilation_target: BTreeMap<String, String>,
+    /// The target EVM version, such as `shanghai`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
+    /// The addresses of the linked libraries, keyed by their fully qualified
+    /// names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub libraries: BTreeMap<String, Address>,
+    /// The metadata settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<MetadataSettingsMetadata>,
+    /// The optimizer settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimizer: Option<OptimizerSettings>,
+    /// The import remappings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remappings: Vec<String>,
+    /// Whether the contract was compiled through the IR pipeline.
+    #[serde(default, rename = "viaIR", skip_serializing_if = "Option::is_none")]
+    pub via_ir: Option<bool>,
+}
+
+/// The metadata settings in [`MetadataSettings`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataSettingsMetadata {
+    /// The hash method of the metadata file appended to the bytecode, such as
+    /// `ipfs` or `none`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_hash: Option<String>,
+    /// Whether the metadata file contains the source contents instead of
+    /// their URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_literal_content: Option<bool>,
+}
+
+/// The optimizer settings in [`MetadataSettings`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OptimizerSettings {
+    /// Whether the optimizer was enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The number of optimizer runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<u64>,
+}
+
+/// A source file in [`ContractMetadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MetadataSource {
+    /// The `keccak256` hash of the source contents.
+    pub keccak256: B256,
+    /// The SPDX license identifier of the source file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// The URLs of the source contents.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+    /// The source contents, if [literal content] was requested.
+    ///
+    /// [literal content]: MetadataSettingsMetadata::use_literal_content
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "compiler": { "version": "0.8.20+commit.a1b79de6" },
+            "language": "Solidity",
+            "output": {
+                "abi": [],
+                "devdoc": { "kind": "dev", "methods": {}, "version": 1 },
+                "userdoc": { "kind": "user", "methods": {}, "version": 1 }
+            },
+            "settings": {
+                "compilationTarget": { "src/Counter.sol": "Counter" },
+                "evmVersion": "shanghai",
+                "libraries": {},
+                "metadata": { "bytecodeHash": "ipfs" },
+                "optimizer": { "enabled": true, "runs": 200 },
+                "remappings": ["forge-std/=lib/forge-std/src/"]
+            },
+            "sources": {
+                "src/Counter.sol": {
+                    "keccak256": "0x09277f949d59a9521708c870dc39c2c434ad8f86a5472efda6a732ef728c0053",
+                    "license": "UNLICENSED",
+                    "urls": ["bzz-raw://6d7b9d5a6f5e0b0d3e1f0d2b3c4a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e1f2a"]
+                }
+            },
+            "version": 1
+        }"#;
+        let metadata: ContractMetadata = serde_json::from_str(json).unwrap();
+        let version = metadata.compiler_version().unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (0, 8, 20));
+        assert_eq!(version.suffix, "+commit.a1b79de6");
+        assert_eq!(metadata.compilation_target(), Some(("src/Counter.sol", "Counter")));
+        assert_eq!(metadata.settings.optimizer.unwrap().runs, Some(200));
+        assert_eq!(metadata.sources["src/Counter.sol"].license.as_deref(), Some("UNLICENSED"));
+
+        let roundtrip = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(serde_json::from_str::<ContractMetadata>(&roundtrip).unwrap(), metadata);
+    }
+}
@@ -0,0 +1,604 @@
+//! Solidity compiler artifacts.
+//!
+//! [`ContractArtifact`] models the compiler output for a single contract, and
+//! can be deserialized from:
+//! - a contract in the solc [standard JSON output], where the bytecode and related fields are
+//!   nested in the `evm` object;
+//! - a [Foundry] artifact, in `out/<file>/<contract>.json`;
+//! - a [Hardhat] artifact, in `artifacts/<file>/<contract>.json`.
+//!
+//! The whole standard JSON output can be deserialized as a [`CompilerOutput`].
+//!
+//! [standard JSON output]: https://docs.soliditylang.org/en/latest/using-the-compiler.html#output-description
+//! [Foundry]: https://book.getfoundry.sh/
+//! [Hardhat]: https://hardhat.org/hardhat-runner/docs/advanced/artifacts
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_json_abi::artifact::ContractArtifact;
+//!
+//! let json = r#"{
+//!     "abi": [],
+//!     "evm": {
+//!         "bytecode": { "object": "6080604052", "sourceMap": "", "linkReferences": {} },
+//!         "methodIdentifiers": { "increment()": "d09de08a" }
+//!     }
+//! }"#;
+//! let artifact: ContractArtifact = serde_json::from_str(json)?;
+//! assert_eq!(artifact.method_identifiers["increment()"], [0xd0, 0x9d, 0xe0, 0x8a]);
+//! assert!(artifact.bytecode.unwrap().object.is_linked());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{Bytecode, ContractObject, JsonAbi, LinkReferences};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::Selector;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+mod metadata;
+pub use metadata::{
+    ContractMetadata, MetadataCompiler, MetadataOutput, MetadataSettings, MetadataSettingsMetadata,
+    MetadataSource, OptimizerSettings,
+};
+
+mod natspec;
+pub use natspec::{DevDoc, MethodDoc, StateVariableDoc, UserDoc, UserDocNotice};
+
+mod storage;
+pub use storage::{StorageEncoding, StorageEntry, StorageLayout, StorageType};
+
+/// The compiler output for a single contract.
+///
+/// See the [module-level documentation](self) for the supported formats.
+/// Serializes in the Foundry artifact format.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", from = "ArtifactRepr")]
+pub struct ContractArtifact {
+    /// The contract ABI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abi: Option<JsonAbi>,
+    /// The creation bytecode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytecode: Option<BytecodeObject>,
+    /// The runtime bytecode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployed_bytecode: Option<BytecodeObject>,
+    /// The function selectors, keyed by the function signatures.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub method_identifiers: BTreeMap<String, Selector>,
+    /// The storage layout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_layout: Option<StorageLayout>,
+    /// The NatSpec user documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<UserDoc>,
+    /// The NatSpec developer documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<DevDoc>,
+    /// The gas estimates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_estimates: Option<GasEstimates>,
+    /// The contract metadata.
+    ///
+    /// If the artifact only contains the metadata as a JSON string, this is
+    /// only populated when the `serde_json` feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ContractMetadata>,
+    /// The contract metadata as the JSON string whose hash is embedded in the
+    /// bytecode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_metadata: Option<String>,
+    /// The contract name. Only present in Hardhat artifacts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_name: Option<String>,
+    /// The source file name. Only present in Hardhat artifacts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
+}
+
+impl From<ContractArtifact> for ContractObject {
+    #[inline]
+    fn from(artifact: ContractArtifact) -> Self {
+        Self {
+            abi: artifact.abi,
+            bytecode: artifact.bytecode.map(|bytecode| bytecode.object),
+            deployed_bytecode: artifact.deployed_bytecode.map(|bytecode| bytecode.object),
+        }
+    }
+}
+
+impl ContractArtifact {
+    /// Returns a [`ContractObject`] with the ABI and bytecode of this
+    /// artifact.
+    #[inline]
+    pub fn to_contract_object(&self) -> ContractObject {
+        ContractObject {
+            abi: self.abi.clone(),
+            bytecode: self.bytecode.as_ref().map(|bytecode| bytecode.object.clone()),
+            deployed_bytecode: self
+                .deployed_bytecode
+                .as_ref()
+                .map(|bytecode| bytecode.object.clone()),
+        }
+    }
+}
+
+/// The bytecode output of a contract, for either the creation or the runtime
+/// bytecode.
+///
+/// Can be deserialized from both a hex string and a JSON object with the
+/// `object` field.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BytecodeObject {
+    /// The bytecode, including its link references.
+    pub object: Bytecode,
+    /// The source map.
+    pub source_map: Option<String>,
+    /// The locations of the immutable variables, keyed by the IDs of their
+    /// declarations' AST nodes. Only present in the runtime bytecode.
+    pub immutable_references: BTreeMap<String, Vec<ImmutableReference>>,
+}
+
+impl From<Bytecode> for BytecodeObject {
+    #[inline]
+    fn from(object: Bytecode) -> Self {
+        Self { object, ..Default::default() }
+    }
+}
+
+impl Serialize for BytecodeObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Repr<'a> {
+            object: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            source_map: Option<&'a String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            link_references: Option<&'a LinkReferences>,
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            immutable_references: &'a BTreeMap<String, Vec<ImmutableReference>>,
+        }
+
+        let link_references = match &self.object {
+            Bytecode::Unlinked(unlinked) if !unlinked.link_references.is_empty() => {
+                Some(&unlinked.link_references)
+            }
+            _ => None,
+        };
+        Repr {
+            object: self.object.to_string(),
+            source_map: self.source_map.as_ref(),
+            link_references,
+            immutable_references: &self.immutable_references,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BytecodeObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            #[serde(rename_all = "camelCase")]
+            Object {
+                object: String,
+                #[serde(default)]
+                source_map: Option<String>,
+                #[serde(default)]
+                link_references: LinkReferences,
+                #[serde(default)]
+                immutable_references: BTreeMap<String, Vec<ImmutableReference>>,
+            },
+            String(String),
+        }
+
+        let (object, source_map, link_references, immutable_references) =
+            match Repr::deserialize(deserializer)? {
+                Repr::Object { object, source_map, link_references, immutable_references } => {
+                    (object, source_map, link_references, immutable_references)
+                }
+                Repr::String(object) => (object, None, LinkReferences::new(), BTreeMap::new()),
+            };
+        let object = Bytecode::from_parts(object, link_references)
+            .ok_or_else(|| serde::de::Error::custom("invalid contract bytecode"))?;
+        Ok(Self { object, source_map, immutable_references })
+    }
+}
+
+/// The location of an immutable variable in the runtime bytecode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ImmutableReference {
+    /// The byte offset of the value.
+    pub start: usize,
+    /// The length of the value in bytes. This is always 32.
+    pub length: usize,
+}
+
+/// The gas estimates of a contract.
+///
+/// Estimates are decimal strings, or `infinite` if they are unbounded.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GasEstimates {
+    /// The estimates for the deployment of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creation: Option<CreationGasEstimates>,
+    /// The estimates of the external functions, keyed by their signatures.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub external: BTreeMap<String, String>,
+    /// The estimates of the internal functions, keyed by their signatures.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub internal: BTreeMap<String, String>,
+}
+
+/// The gas estimates for the deployment of a contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreationGasEstimates {
+    /// The cost of storing the runtime code.
+    pub code_deposit_cost: String,
+    /// The cost of executing the creation code.
+    pub execution_cost: String,
+    /// The total cost.
+    pub total_cost: String,
+}
+
+/// The solc [standard JSON output], as produced by `solc --standard-json`.
+///
+/// Only the contracts and the diagnostics are deserialized.
+///
+/// [standard JSON output]: https://docs.soliditylang.org/en/latest/using-the-compiler.html#output-description
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompilerOutput {
+    /// The compiled contracts, keyed by their source file names and then by
+    /// their names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contracts: BTreeMap<String, BTreeMap<String, ContractArtifact>>,
+    /// The errors and warnings emitted by the compiler.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<CompilerError>,
+}
+
+impl CompilerOutput {
+    /// Returns the contract with the given name, and optionally source file
+    /// name.
+    ///
+    /// If no source file name is given and multiple files contain a contract
+    /// with the given name, the first one is returned.
+    pub fn contract(&self, file: Option<&str>, name: &str) -> Option<&ContractArtifact> {
+        match file {
+            Some(file) => self.contracts.get(file)?.get(name),
+            None => self.contracts.values().find_map(|contracts| contracts.get(name)),
+        }
+    }
+
+    /// Returns `true` if the compiler emitted any errors.
+    #[inline]
+    pub fn has_errors(&self) -> bool {
+        self.errors.iter().any(CompilerError::is_error)
+    }
+}
+
+/// An error or warning in the [`CompilerOutput`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerError {
+    /// The severity: `error`, `warning` or `info`.
+    pub severity: String,
+    /// The error type, such as `TypeError`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The compiler component, such as `general`.
+    #[serde(default)]
+    pub component: String,
+    /// The error message.
+    pub message: String,
+    /// The error message, formatted with the source location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_message: Option<String>,
+    /// The unique error code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+}
+
+impl CompilerError {
+    /// Returns `true` if this is an error, rather than a warning or info.
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.severity == "error"
+    }
+}
+
+/// Accepts all the field names and layouts of the supported formats.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactRepr {
+    #[serde(default)]
+    abi: Option<JsonAbi>,
+    #[serde(default, alias = "bin")]
+    bytecode: Option<BytecodeObject>,
+    #[serde(default, alias = "runtimeBin", alias = "deployed_bytecode")]
+    deployed_bytecode: Option<BytecodeObject>,
+    #[serde(default)]
+    method_identifiers: BTreeMap<String, Selector>,
+    #[serde(default)]
+    storage_layout: Option<StorageLayout>,
+    #[serde(default)]
+    userdoc: Option<UserDoc>,
+    #[serde(default)]
+    devdoc: Option<DevDoc>,
+    #[serde(default)]
+    gas_estimates: Option<GasEstimates>,
+    #[serde(default)]
+    metadata: Option<MetadataRepr>,
+    #[serde(default)]
+    raw_metadata: Option<String>,
+    #[serde(default)]
+    contract_name: Option<String>,
+    #[serde(default)]
+    source_name: Option<String>,
+    // solc standard JSON
+    #[serde(default)]
+    evm: Option<EvmRepr>,
+    // Hardhat
+    #[serde(default)]
+    link_references: LinkReferences,
+    #[serde(default)]
+    deployed_link_references: LinkReferences,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EvmRepr {
+    #[serde(default)]
+    bytecode: Option<BytecodeObject>,
+    #[serde(default)]
+    deployed_bytecode: Option<BytecodeObject>,
+    #[serde(default)]
+    method_identifiers: BTreeMap<String, Selector>,
+    #[serde(default)]
+    gas_estimates: Option<GasEstimates>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MetadataRepr {
+    Json(String),
+    Object(Box<ContractMetadata>),
+}
+
+impl From<ArtifactRepr> for ContractArtifact {
+    fn from(repr: ArtifactRepr) -> Self {
+        let ArtifactRepr {
+            abi,
+            mut bytecode,
+            mut deployed_bytecode,
+            mut method_identifiers,
+            storage_layout,
+            userdoc,
+            devdoc,
+            mut gas_estimates,
+            metadata,
+            mut raw_metadata,
+            contract_name,
+            source_name,
+            evm,
+            link_references,
+            deployed_link_references,
+        } = repr;
+
+        if let Some(evm) = evm {
+            bytecode = bytecode.or(evm.bytecode);
+            deployed_bytecode = deployed_bytecode.or(evm.deployed_bytecode);
+            if method_identifiers.is_empty() {
+                method_identifiers = evm.method_identifiers;
+            }
+            gas_estimates = gas_estimates.or(evm.gas_estimates);
+        }
+
+        for (bytecode, references) in
+            [(&mut bytecode, link_references), (&mut deployed_bytecode, deployed_link_references)]
+        {
+            if let Some(BytecodeObject { object: Bytecode::Unlinked(unlinked), .. }) = bytecode {
+                if unlinked.link_references.is_empty() {
+                    unlinked.link_references = references;
+                }
+            }
+        }
+
+        let metadata = match metadata {
+            Some(MetadataRepr::Object(metadata)) => Some(*metadata),
+            Some(MetadataRepr::Json(json)) => {
+                #[cfg(feature = "serde_json")]
+                let metadata = serde_json::from_str(&json).ok();
+                #[cfg(not(feature = "serde_json"))]
+                let metadata = None;
+                raw_metadata = raw_metadata.or(Some(json));
+                metadata
+            }
+            None => {
+                #[cfg(feature = "serde_json")]
+                let metadata = raw_metadata.as_deref().and_then(|s| serde_json::from_str(s).ok());
+                #[cfg(not(feature = "serde_json"))]
+                let metadata = None;
+                metadata
+            }
+        };
+
+        Self {
+            abi,
+            bytecode,
+            deployed_bytecode,
+            method_identifiers,
+            storage_layout,
+            userdoc,
+            devdoc,
+            gas_estimates,
+            metadata,
+            raw_metadata,
+            contract_name,
+            source_name,
+        }
+    }
+}
+
+/// Deserializes `null` as the default value.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{hex, Address};
+
+    const METADATA: &str = r#"{"compiler":{"version":"0.8.20+commit.a1b79de6"},"language":"Solidity","output":{"abi":[],"devdoc":{"kind":"dev","methods":{},"version":1},"userdoc":{"kind":"user","methods":{},"version":1}},"settings":{"compilationTarget":{"src/Counter.sol":"Counter"},"evmVersion":"shanghai","libraries":{},"metadata":{"bytecodeHash":"ipfs"},"optimizer":{"enabled":false,"runs":200},"remappings":[]},"sources":{"src/Counter.sol":{"keccak256":"0x09277f949d59a9521708c870dc39c2c434ad8f86a5472efda6a732ef728c0053","license":"UNLICENSED","urls":[]}},"version":1}"#;
+
+    #[test]
+    fn solc() {
+        let json = format!(
+            r#"{{
+                "contracts": {{
+                    "src/Counter.sol": {{
+                        "Counter": {{
+                            "abi": [{{"type":"function","name":"increment","inputs":[],"outputs":[],"stateMutability":"nonpayable"}}],
+                            "metadata": {metadata},
+                            "userdoc": {{ "kind": "user", "methods": {{}}, "notice": "A counter", "version": 1 }},
+                            "devdoc": {{ "kind": "dev", "methods": {{}}, "version": 1 }},
+                            "storageLayout": {{ "storage": [], "types": null }},
+                            "evm": {{
+                                "bytecode": {{
+                                    "functionDebugData": {{}},
+                                    "generatedSources": [],
+                                    "linkReferences": {{}},
+                                    "object": "6080604052",
+                                    "opcodes": "PUSH1 0x80 PUSH1 0x40 MSTORE",
+                                    "sourceMap": "57:1:0:-:0;;"
+                                }},
+                                "deployedBytecode": {{
+                                    "immutableReferences": {{ "5": [{{ "length": 32, "start": 1 }}] }},
+                                    "linkReferences": {{}},
+                                    "object": "7f00000000000000000000000000000000000000000000000000000000000000005f",
+                                    "sourceMap": "57:1:0:-:0;;"
+                                }},
+                                "gasEstimates": {{
+                                    "creation": {{ "codeDepositCost": "1000", "executionCost": "infinite", "totalCost": "infinite" }},
+                                    "external": {{ "increment()": "24329" }}
+                                }},
+                                "methodIdentifiers": {{ "increment()": "d09de08a" }}
+                            }}
+                        }}
+                    }}
+                }},
+                "errors": [
+                    {{
+                        "component": "general",
+                        "errorCode": "1878",
+                        "formattedMessage": "Warning: SPDX license identifier not provided in source file.",
+                        "message": "SPDX license identifier not provided in source file.",
+                        "severity": "warning",
+                        "type": "Warning"
+                    }}
+                ],
+                "sources": {{ "src/Counter.sol": {{ "id": 0 }} }}
+            }}"#,
+            metadata = serde_json::to_string(METADATA).unwrap(),
+        );
+        let output: CompilerOutput = serde_json::from_str(&json).unwrap();
+        assert!(!output.has_errors());
+        assert_eq!(output.errors[0].error_code.as_deref(), Some("1878"));
+
+        let counter = output.contract(None, "Counter").unwrap();
+        assert_eq!(output.contract(Some("src/Counter.sol"), "Counter"), Some(counter));
+        assert_eq!(output.contract(Some("src/Other.sol"), "Counter"), None);
+
+        assert_eq!(counter.abi.as_ref().unwrap().len(), 1);
+        let bytecode = counter.bytecode.as_ref().unwrap();
+        assert_eq!(bytecode.object.as_bytes().unwrap()[..], hex!("6080604052"));
+        assert_eq!(bytecode.source_map.as_deref(), Some("57:1:0:-:0;;"));
+        let deployed = counter.deployed_bytecode.as_ref().unwrap();
+        assert_eq!(
+            deployed.immutable_references["5"],
+            [ImmutableReference { start: 1, length: 32 }]
+        );
+        assert_eq!(counter.method_identifiers["increment()"], hex!("d09de08a"));
+        let gas = counter.gas_estimates.as_ref().unwrap();
+        assert_eq!(gas.creation.as_ref().unwrap().execution_cost, "infinite");
+        assert_eq!(gas.external["increment()"], "24329");
+        assert_eq!(counter.userdoc.as_ref().unwrap().notice.as_deref(), Some("A counter"));
+        assert_eq!(counter.storage_layout, Some(StorageLayout::default()));
+        assert_eq!(counter.raw_metadata.as_deref(), Some(METADATA));
+        // `rawMetadata` is only parsed with the `serde_json` feature
+        #[cfg(feature = "serde_json")]
+        assert_eq!(
+            counter.metadata.as_ref().unwrap().compilation_target(),
+            Some(("src/Counter.sol", "Counter"))
+        );
+        #[cfg(not(feature = "serde_json"))]
+        assert_eq!(counter.metadata, None);
+
+        // Round trip through the Foundry format
+        let foundry = serde_json::to_string(counter).unwrap();
+        assert_eq!(&serde_json::from_str::<ContractArtifact>(&foundry).unwrap(), counter);
+    }
+
+    #[test]
+    fn foundry() {
+        let json = format!(
+            r#"{{
+                "abi": [],
+                "bytecode": {{ "object": "0x6080604052", "sourceMap": "57:1:0:-:0;;", "linkReferences": {{}} }},
+                "deployedBytecode": {{ "object": "0x6080", "sourceMap": "", "linkReferences": {{}} }},
+                "methodIdentifiers": {{ "increment()": "d09de08a" }},
+                "rawMetadata": {raw},
+                "metadata": {METADATA},
+                "id": 22
+            }}"#,
+            raw = serde_json::to_string(METADATA).unwrap(),
+        );
+        let artifact: ContractArtifact = serde_json::from_str(&json).unwrap();
+        assert_eq!(artifact.method_identifiers.len(), 1);
+        assert_eq!(artifact.raw_metadata.as_deref(), Some(METADATA));
+        assert_eq!(artifact.metadata.as_ref().unwrap().compiler.version, "0.8.20+commit.a1b79de6");
+
+        let object = artifact.to_contract_object();
+        assert_eq!(object.deployed_bytecode.unwrap().as_bytes().unwrap()[..], hex!("6080"));
+        assert_eq!(ContractObject::from(artifact.clone()), artifact.to_contract_object());
+    }
+
+    #[test]
+    fn hardhat() {
+        let placeholder = "__$7233c33f2e1e35848c685b0eb24649959e$__";
+        let json = format!(
+            r#"{{
+                "_format": "hh-sol-artifact-1",
+                "contractName": "Counter",
+                "sourceName": "contracts/Counter.sol",
+                "abi": [],
+                "bytecode": "0x73{placeholder}",
+                "deployedBytecode": "0x6080",
+                "linkReferences": {{ "contracts/Lib.sol": {{ "Lib": [{{ "start": 1, "length": 20 }}] }} }},
+                "deployedLinkReferences": {{}}
+            }}"#
+        );
+        let mut artifact: ContractArtifact = serde_json::from_str(&json).unwrap();
+        assert_eq!(artifact.contract_name.as_deref(), Some("Counter"));
+        assert_eq!(artifact.source_name.as_deref(), Some("contracts/Counter.sol"));
+
+        let bytecode = &mut artifact.bytecode.as_mut().unwrap().object;
+        assert_eq!(bytecode.missing_libraries(), ["contracts/Lib.sol:Lib"]);
+        let linked = bytecode.link_all([("Lib", Address::ZERO)]).unwrap();
+        assert_eq!(linked[..], [&[0x73][..], &[0; 20]].concat());
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The user documentation of a contract, as emitted by the Solidity compiler
+/// in the `userdoc` output.
+///
+/// See the [NatSpec documentation] for more details.
+///
+/// [NatSpec documentation]: https://docs.soliditylang.org/en/latest/natspec-format.html#user-documentation
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserDoc {
+    /// The version of the documentation format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// The kind of documentation. Always `user`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The `@notice` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// The documentation of the functions, keyed by their signatures, and of
+    /// the constructor, keyed by `constructor`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, UserDocNotice>,
+    /// The documentation of the events, keyed by their signatures.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, UserDocNotice>,
+    /// The documentation of the errors, keyed by their signatures. There may
+    /// be multiple errors with the same signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<UserDocNotice>>,
+}
+
+/// A `@notice` in a [`UserDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct UserDocNotice {
+    /// The notice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for UserDocNotice {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Old compiler versions emit the constructor notice as a plain string.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Object {
+                #[serde(default)]
+                notice: Option<String>,
+            },
+            String(String),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Object { notice } => Self { notice },
+            Repr::String(notice) => Self { notice: Some(notice) },
+        })
+    }
+}
+
+/// The developer documentation of a contract, as emitted by the Solidity
+/// compiler in the `devdoc` output.
+///
+/// See the [NatSpec documentation] for more details.
+///
+/// [NatSpec documentation]: https://docs.soliditylang.org/en/latest/natspec-format.html#developer-documentation
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDoc {
+    /// The version of the documentation format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// The kind of documentation. Always `dev`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The `@author` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The `@dev` details of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@title` of the contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The documentation of the functions, keyed by their signatures, and of
+    /// the constructor, keyed by `constructor`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, MethodDoc>,
+    /// The documentation of the events, keyed by their signatures.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, MethodDoc>,
+    /// The documentation of the errors, keyed by their signatures. There may
+    /// be multiple errors with the same signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<MethodDoc>>,
+    /// The documentation of the public state variables, keyed by their names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub state_variables: BTreeMap<String, StateVariableDoc>,
+}
+
+/// The developer documentation of a function, event or error in a
+/// [`DevDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MethodDoc {
+    /// The `@dev` details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@param` descriptions, keyed by the parameter names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    /// The `@return` descriptions, keyed by the return value names, or
+    /// `_<index>` for unnamed return values.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub returns: BTreeMap<String, String>,
+}
+
+/// The developer documentation of a public state variable in a [`DevDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StateVariableDoc {
+    /// The `@dev` details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@return` description of the getter.
+    #[serde(default, rename = "return", skip_serializing_if = "Option::is_none")]
+    pub return_: Option<String>,
+    /// The `@return` descriptions of the getter, keyed by the return value
+    /// names, or `_<index>` for unnamed return values.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub returns: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn userdoc() {
+        let json = r#"{
+            "kind": "user",
+            "methods": {
+                "constructor": "Deploys the token",
+                "transfer(address,uint256)": { "notice": "Transfers tokens" }
+            },
+            "errors": {
+                "Unauthorized()": [{ "notice": "Caller is not the owner" }]
+            },
+            "notice": "A token",
+            "version": 1
+        }"#;
+        let doc: UserDoc = serde_json::from_str(json).unwrap();
+        assert_eq!(doc.notice.as_deref(), Some("A token"));
+        assert_eq!(doc.methods["constructor"].notice.as_deref(), Some("Deploys the token"));
+        assert_eq!(
+            doc.methods["transfer(address,uint256)"].notice.as_deref(),
+            Some("Transfers tokens")
+        );
+        assert_eq!(doc.errors["Unauthorized()"].len(), 1);
+
+        let roundtrip = serde_json::to_string(&doc).unwrap();
+        assert_eq!(serde_json::from_str::<UserDoc>(&roundtrip).unwrap(), doc);
+    }
+
+    #[test]
+    fn devdoc() {
+        let json = r#"{
+            "author": "Alice",
+            "kind": "dev",
+            "methods": {
+                "transfer(address,uint256)": {
+                    "details": "Reverts on insufficient balance",
+                    "params": { "amount": "The amount", "to": "The recipient" },
+                    "returns": { "_0": "Whether the transfer succeeded" }
+                }
+            },
+            "stateVariables": {
+                "owner": { "details": "The owner", "return": "The owner address" }
+            },
+            "title": "Token",
+            "version": 1
+        }"#;
+        let doc: DevDoc = serde_json::from_str(json).unwrap();
+        assert_eq!(doc.title.as_deref(), Some("Token"));
+        let transfer = &doc.methods["transfer(address,uint256)"];
+        assert_eq!(transfer.params["to"], "The recipient");
+        assert_eq!(transfer.returns["_0"], "Whether the transfer succeeded");
+        assert_eq!(doc.state_variables["owner"].return_.as_deref(), Some("The owner address"));
+
+        let roundtrip = serde_json::to_string(&doc).unwrap();
+        assert_eq!(serde_json::from_str::<DevDoc>(&roundtrip).unwrap(), doc);
+    }
+}
@@ -0,0 +1,154 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::U256;
+use serde::{Deserialize, Serialize};
+
+/// The storage layout of a contract, as emitted by the Solidity compiler in
+/// the `storageLayout` output.
+///
+/// See the [Solidity documentation] for more details.
+///
+/// [Solidity documentation]: https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html#json-output
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StorageLayout {
+    /// The state variables of the contract, in declaration order.
+    #[serde(default)]
+    pub storage: Vec<StorageEntry>,
+    /// The types referenced by the state variables, keyed by their
+    /// identifiers.
+    #[serde(default, deserialize_with = "super::null_as_default")]
+    pub types: BTreeMap<String, StorageType>,
+}
+
+impl StorageLayout {
+    /// Returns the state variable with the given label, if any.
+    #[inline]
+    pub fn entry(&self, label: &str) -> Option<&StorageEntry> {
+        self.storage.iter().find(|entry| entry.label == label)
+    }
+
+    /// Returns the type with the given identifier, if any.
+    #[inline]
+    pub fn ty(&self, id: &str) -> Option<&StorageType> {
+        self.types.get(id)
+    }
+}
+
+/// A state variable, or a struct member, in a [`StorageLayout`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageEntry {
+    /// The ID of the declaration's AST node.
+    pub ast_id: u64,
+    /// The fully qualified name of the contract which declares the variable,
+    /// such as `src/Token.sol:Token`.
+    pub contract: String,
+    /// The name of the variable.
+    pub label: String,
+    /// The byte offset of the variable within its slot.
+    pub offset: u64,
+    /// The slot of the variable, or the slot relative to the start of the
+    /// struct for members.
+    #[serde(with = "alloy_primitives::serde_helpers::decimal_u256")]
+    pub slot: U256,
+    /// The identifier of the variable's type in [`StorageLayout::types`].
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// A type in a [`StorageLayout`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageType {
+    /// How the data is encoded in storage.
+    pub encoding: StorageEncoding,
+    /// The canonical type name, such as `mapping(address => uint256)`.
+    pub label: String,
+    /// The number of bytes used by the type. For dynamic types, this is the
+    /// size of the slot which holds the length or base slot.
+    #[serde(with = "alloy_primitives::serde_helpers::decimal_u256")]
+    pub number_of_bytes: U256,
+    /// The element type of arrays.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// The key type of mappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The value type of mappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The members of structs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<StorageEntry>>,
+}
+
+/// How a [`StorageType`] is encoded in storage.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageEncoding {
+    /// The data is laid out contiguously in storage.
+    #[default]
+    Inplace,
+    /// Keccak-256 hash-based method, for mappings.
+    Mapping,
+    /// Keccak-256 hash-based method, for dynamic arrays.
+    DynamicArray,
+    /// Single slot or Keccak-256 hash-based method, depending on the data
+    /// size, for `bytes` and `string`.
+    Bytes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "storage": [
+                {
+                    "astId": 3,
+                    "contract": "src/Token.sol:Token",
+                    "label": "balances",
+                    "offset": 0,
+                    "slot": "0",
+                    "type": "t_mapping(t_address,t_uint256)"
+                }
+            ],
+            "types": {
+                "t_address": {
+                    "encoding": "inplace",
+                    "label": "address",
+                    "numberOfBytes": "20"
+                },
+                "t_mapping(t_address,t_uint256)": {
+                    "encoding": "mapping",
+                    "key": "t_address",
+                    "label": "mapping(address => uint256)",
+                    "numberOfBytes": "32",
+                    "value": "t_uint256"
+                },
+                "t_uint256": {
+                    "encoding": "inplace",
+                    "label": "uint256",
+                    "numberOfBytes": "32"
+                }
+            }
+        }"#;
+        let layout: StorageLayout = serde_json::from_str(json).unwrap();
+        let balances = layout.entry("balances").unwrap();
+        assert_eq!(balances.slot, U256::ZERO);
+        let ty = layout.ty(&balances.ty).unwrap();
+        assert_eq!(ty.encoding, StorageEncoding::Mapping);
+        assert_eq!(ty.key.as_deref(), Some("t_address"));
+        assert_eq!(ty.number_of_bytes, U256::from(32));
+
+        let roundtrip = serde_json::to_string(&layout).unwrap();
+        assert!(roundtrip.contains(r#""slot":"0""#), "{roundtrip}");
+        assert_eq!(serde_json::from_str::<StorageLayout>(&roundtrip).unwrap(), layout);
+
+        let empty: StorageLayout = serde_json::from_str(r#"{"storage":[],"types":null}"#).unwrap();
+        assert_eq!(empty, StorageLayout::default());
+    }
+}
@@ -0,0 +1,459 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{hex, keccak256, Address, Bytes};
+use core::{borrow::Borrow, fmt};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The length of a library placeholder in hex characters.
+const PLACEHOLDER_LEN: usize = 40;
+
+/// Library link references, as emitted by the Solidity compiler.
+///
+/// Maps source file names to library names to the offsets of their
+/// placeholders in the bytecode.
+pub type LinkReferences = BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>;
+
+/// The location of a library placeholder in unlinked bytecode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LinkReference {
+    /// The byte offset of the placeholder.
+    pub start: usize,
+    /// The length of the placeholder in bytes. This is always 20.
+    pub length: usize,
+}
+
+/// Contract bytecode, which may still contain placeholders for the addresses
+/// of the libraries it links against.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Bytecode {
+    /// Fully linked bytecode.
+    Linked(Bytes),
+    /// Bytecode with library placeholders.
+    Unlinked(UnlinkedBytecode),
+}
+
+impl Default for Bytecode {
+    #[inline]
+    fn default() -> Self {
+        Self::Linked(Bytes::new())
+    }
+}
+
+impl From<Bytes> for Bytecode {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Self::Linked(bytes)
+    }
+}
+
+impl From<UnlinkedBytecode> for Bytecode {
+    #[inline]
+    fn from(unlinked: UnlinkedBytecode) -> Self {
+        Self::Unlinked(unlinked)
+    }
+}
+
+impl fmt::Display for Bytecode {
+    /// Formats the bytecode as a `0x`-prefixed hex string, including any
+    /// library placeholders.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linked(bytes) => bytes.fmt(f),
+            Self::Unlinked(unlinked) => write!(f, "0x{}", unlinked.object),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytecode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Object {
+                object: String,
+                #[serde(default, rename = "linkReferences")]
+                link_references: LinkReferences,
+            },
+            String(String),
+        }
+
+        let (object, link_references) = match Repr::deserialize(deserializer)? {
+            Repr::Object { object, link_references } => (object, link_references),
+            Repr::String(object) => (object, LinkReferences::new()),
+        };
+        Self::from_parts(object, link_references)
+            .ok_or_else(|| serde::de::Error::custom("invalid contract bytecode"))
+    }
+}
+
+impl Bytecode {
+    /// Creates new bytecode from its hex representation, which may contain
+    /// library placeholders, and its link references.
+    ///
+    /// Returns `None` if the bytecode is not valid hex outside of
+    /// placeholders, or if a link reference is out of bounds.
+    pub fn from_parts(object: String, link_references: LinkReferences) -> Option<Self> {
+        if let Ok(bytes) = hex::decode(&object) {
+            return Some(Self::Linked(bytes.into()));
+        }
+        let object = match object.strip_prefix("0x") {
+            Some(stripped) => stripped.to_owned(),
+            None => object,
+        };
+        let unlinked = UnlinkedBytecode { object, link_references };
+        unlinked.is_valid().then_some(Self::Unlinked(unlinked))
+    }
+
+    /// Returns the linked bytecode, if it is fully linked.
+    #[inline]
+    pub const fn as_bytes(&self) -> Option<&Bytes> {
+        match self {
+            Self::Linked(bytes) => Some(bytes),
+            Self::Unlinked(_) => None,
+        }
+    }
+
+    /// Converts into the linked bytecode, if it is fully linked.
+    #[inline]
+    pub fn into_bytes(self) -> Option<Bytes> {
+        match self {
+            Self::Linked(bytes) => Some(bytes),
+            Self::Unlinked(_) => None,
+        }
+    }
+
+    /// Returns `true` if the bytecode is fully linked.
+    #[inline]
+    pub const fn is_linked(&self) -> bool {
+        matches!(self, Self::Linked(_))
+    }
+
+    /// Returns the libraries which still have to be linked.
+    ///
+    /// See [`UnlinkedBytecode::missing_libraries`] for more details.
+    #[inline]
+    pub fn missing_libraries(&self) -> Vec<String> {
+        match self {
+            Self::Linked(_) => Vec::new(),
+            Self::Unlinked(unlinked) => unlinked.missing_libraries(),
+        }
+    }
+
+    /// Links the given library into the bytecode, converting it into
+    /// [`Linked`](Self::Linked) bytecode if no placeholders remain.
+    ///
+    /// See [`UnlinkedBytecode::link`] for more details.
+    pub fn link(&mut self, library: &str, address: Address) -> bool {
+        let Self::Unlinked(unlinked) = self else { return false };
+        let linked = unlinked.link(library, address);
+        if let Some(bytes) = unlinked.to_bytes() {
+            *self = Self::Linked(bytes);
+        }
+        linked
+    }
+
+    /// Links all the given libraries into the bytecode, and returns the
+    /// fully linked bytecode.
+    ///
+    /// Libraries which are not referenced by the bytecode are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the [missing libraries] if any placeholders
+    /// remain after linking. The given libraries are linked nonetheless.
+    ///
+    /// [missing libraries]: UnlinkedBytecode::missing_libraries
+    pub fn link_all<I, K, A>(&mut self, libraries: I) -> Result<Bytes, LinkError>
+    where
+        I: IntoIterator<Item = (K, A)>,
+        K: AsRef<str>,
+        A: Borrow<Address>,
+    {
+        for (library, address) in libraries {
+            if self.is_linked() {
+                break;
+            }
+            self.link(library.as_ref(), *address.borrow());
+        }
+        match self {
+            Self::Linked(bytes) => Ok(bytes.clone()),
+            Self::Unlinked(unlinked) => Err(LinkError::new(unlinked.missing_libraries())),
+        }
+    }
+}
+
+/// Bytecode with placeholders for the addresses of the libraries it links
+/// against.
+///
+/// Placeholders are 40 characters long and either of the form
+/// `__$<hash>$__`, where `<hash>` is the hex encoding of the first 17 bytes
+/// of the `keccak256` hash of the fully qualified library name, or, before
+/// Solidity `0.5.0`, the fully qualified library name truncated or padded
+/// with `_` to 36 characters and surrounded by `__`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlinkedBytecode {
+    /// The hex-encoded bytecode, without the `0x` prefix.
+    pub object: String,
+    /// The locations of the library placeholders. May be empty if the
+    /// compiler output did not include them.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub link_references: LinkReferences,
+}
+
+impl UnlinkedBytecode {
+    /// Links the given library into the bytecode.
+    ///
+    /// The library is identified either by its name, or by its fully qualified
+    /// name (`<file>:<name>`). Placeholders which are not listed in the
+    /// [link references](Self::link_references) can only be linked by the
+    /// fully qualified name.
+    ///
+    /// Returns `true` if any placeholders were replaced.
+    pub fn link(&mut self, library: &str, address: Address) -> bool {
+        let Self { object, link_references } = self;
+        let address = hex::encode(address);
+        let mut linked = false;
+
+        link_references.retain(|file, libraries| {
+            libraries.retain(|name, references| {
+                let fully_qualified =
+                    library.strip_prefix(file.as_str()).and_then(|s| s.strip_prefix(':'));
+                if library != name && fully_qualified != Some(name) {
+                    return true;
+                }
+                for reference in references.iter() {
+                    let start = reference.start * 2;
+                    if object.get(start..start + PLACEHOLDER_LEN).is_some() {
+                        object.replace_range(start..start + PLACEHOLDER_LEN, &address);
+                    }
+                }
+                linked = true;
+                false
+            });
+            !libraries.is_empty()
+        });
+
+        if library.contains(':') {
+            let hash = keccak256(library.as_bytes());
+            let placeholders =
+                [format!("__${}$__", hex::encode(&hash[..17])), format!("__{library:_<36.36}__")];
+            for placeholder in placeholders {
+                if object.contains(&placeholder) {
+                    *object = object.replace(&placeholder, &address);
+                    linked = true;
+                }
+            }
+        }
+
+        linked
+    }
+
+    /// Returns the libraries which still have to be linked.
+    ///
+    /// These are the fully qualified names of the libraries in the
+    /// [link references](Self::link_references), or, if those are empty, the
+    /// remaining placeholders.
+    pub fn missing_libraries(&self) -> Vec<String> {
+        let mut missing: Vec<String> = self
+            .link_references
+            .iter()
+            .flat_map(|(file, libraries)| {
+                libraries.keys().map(move |name| {
+                    if file.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{file}:{name}")
+                    }
+                })
+            })
+            .collect();
+        if missing.is_empty() {
+            for placeholder in placeholders(&self.object) {
+                if !missing.iter().any(|p| p == placeholder) {
+                    missing.push(placeholder.to_string());
+                }
+            }
+        }
+        missing
+    }
+
+    /// Decodes the bytecode, if no placeholders remain.
+    #[inline]
+    pub fn to_bytes(&self) -> Option<Bytes> {
+        hex::decode(&self.object).ok().map(Into::into)
+    }
+
+    fn is_valid(&self) -> bool {
+        let object = self.object.as_bytes();
+        if object.len() % 2 != 0 {
+            return false;
+        }
+        let mut i = 0;
+        while i < object.len() {
+            if object[i] == b'_' {
+                i += PLACEHOLDER_LEN;
+                if i > object.len() {
+                    return false;
+                }
+            } else {
+                if !object[i..i + 2].iter().all(u8::is_ascii_hexdigit) {
+                    return false;
+                }
+                i += 2;
+            }
+        }
+        self.link_references.values().flat_map(BTreeMap::values).flatten().all(|reference| {
+            reference.length == PLACEHOLDER_LEN / 2
+                && (reference.start + reference.length) * 2 <= object.len()
+        })
+    }
+}
+
+/// Returns an iterator over the placeholders in the hex-encoded bytecode.
+fn placeholders(object: &str) -> impl Iterator<Item = &str> {
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        while i < object.len() {
+            if object.as_bytes()[i] == b'_' {
+                let placeholder = object.get(i..i + PLACEHOLDER_LEN)?;
+                i += PLACEHOLDER_LEN;
+                return Some(placeholder);
+            }
+            i += 2;
+        }
+        None
+    })
+}
+
+/// Error returned when linking bytecode leaves some library placeholders
+/// unresolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkError {
+    missing: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinkError {}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("missing libraries: ")?;
+        for (i, library) in self.missing.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(library)?;
+        }
+        Ok(())
+    }
+}
+
+impl LinkError {
+    #[inline]
+    pub(crate) const fn new(missing: Vec<String>) -> Self {
+        Self { missing }
+    }
+
+    /// Returns the libraries which are still missing, as returned by
+    /// [`UnlinkedBytecode::missing_libraries`].
+    #[inline]
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const LIB: Address = address!("00000000000000000000000000000000000000aa");
+
+    fn unlinked(object: &str, link_references: LinkReferences) -> Bytecode {
+        Bytecode::from_parts(object.to_string(), link_references).unwrap()
+    }
+
+    fn hash_placeholder(library: &str) -> String {
+        format!("__${}$__", hex::encode(&keccak256(library)[..17]))
+    }
+
+    #[test]
+    fn linked() {
+        let bytecode = unlinked("0x6080", LinkReferences::new());
+        assert_eq!(bytecode, Bytecode::Linked(Bytes::from_static(&[0x60, 0x80])));
+        assert_eq!(bytecode.to_string(), "0x6080");
+        assert!(bytecode.missing_libraries().is_empty());
+    }
+
+    #[test]
+    fn link_references() {
+        let object =
+            format!("73{}3014{}", hash_placeholder("a.sol:A"), hash_placeholder("b.sol:B"));
+        let mut references = LinkReferences::new();
+        references
+            .entry("a.sol".into())
+            .or_default()
+            .insert("A".into(), vec![LinkReference { start: 1, length: 20 }]);
+        references
+            .entry("b.sol".into())
+            .or_default()
+            .insert("B".into(), vec![LinkReference { start: 23, length: 20 }]);
+        let mut bytecode = unlinked(&object, references);
+        assert_eq!(bytecode.missing_libraries(), ["a.sol:A", "b.sol:B"]);
+
+        assert!(!bytecode.link("C", LIB));
+        assert!(bytecode.link("A", LIB));
+        assert!(!bytecode.is_linked());
+        let err = bytecode.clone().link_all([("c.sol:C", LIB)]).unwrap_err();
+        assert_eq!(err.missing(), ["b.sol:B"]);
+        assert_eq!(err.to_string(), "missing libraries: b.sol:B");
+
+        let bytes = bytecode.link_all([("b.sol:B", LIB)]).unwrap();
+        assert_eq!(bytes, [&[0x73][..], &LIB[..], &[0x30, 0x14], &LIB[..]].concat());
+        assert!(bytecode.is_linked());
+    }
+
+    #[test]
+    fn placeholders_only() {
+        let legacy = format!("__{:_<36}__", "lib.sol:Legacy");
+        let object = format!("73{}73{}", hash_placeholder("lib.sol:Lib"), legacy);
+        let mut bytecode = unlinked(&object, LinkReferences::new());
+        let missing = bytecode.missing_libraries();
+        assert_eq!(missing, [hash_placeholder("lib.sol:Lib"), legacy]);
+        assert_eq!(bytecode.to_string(), format!("0x{object}"));
+
+        // only fully qualified names can be matched against placeholders
+        assert!(!bytecode.link("Lib", LIB));
+        assert!(bytecode.link("lib.sol:Lib", LIB));
+        assert!(bytecode.link("lib.sol:Legacy", LIB));
+        assert_eq!(
+            bytecode.into_bytes().unwrap(),
+            [&[0x73][..], &LIB[..], &[0x73], &LIB[..]].concat()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let placeholder = hash_placeholder("a.sol:A");
+        for object in ["0x6", "0xzz", "__$", &format!("{placeholder}0")] {
+            assert_eq!(
+                Bytecode::from_parts(object.into(), LinkReferences::new()),
+                None,
+                "{object}"
+            );
+        }
+
+        let mut references = LinkReferences::new();
+        references
+            .entry("a.sol".into())
+            .or_default()
+            .insert("A".into(), vec![LinkReference { start: 1, length: 20 }]);
+        assert_eq!(Bytecode::from_parts(placeholder, references), None);
+    }
+}
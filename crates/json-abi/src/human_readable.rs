@@ -0,0 +1,431 @@
+//! [Human-Readable ABI] struct definitions and formatting.
+//!
+//! [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+
+use crate::{AbiItem, EventParam, InternalType, JsonAbi, Param, StateMutability};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+/// Parses a struct definition: `struct $name { $($type $name;)* }`.
+///
+/// Returns `None` if the string is not a struct definition.
+pub(crate) fn parse_struct(s: &str) -> Option<parser::Result<(String, Vec<Param>)>> {
+    let rest = s.trim().strip_prefix("struct")?;
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let parse = || {
+        let (name, body) = rest
+            .split_once('{')
+            .ok_or_else(|| parser::Error::new("expected `{` after the struct name"))?;
+        let name = name.trim();
+        if !parser::is_valid_identifier(name) {
+            return Err(parser::Error::_new("invalid struct name: ", &name));
+        }
+        let body = body
+            .trim_end()
+            .strip_suffix('}')
+            .ok_or_else(|| parser::Error::new("expected `}` at the end of the struct"))?;
+        let fields = body
+            .split(';')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(Param::parse)
+            .collect::<parser::Result<_>>()?;
+        Ok((name.into(), fields))
+    };
+    Some(parse())
+}
+
+/// Struct definitions, used to resolve struct names in parameter types.
+#[derive(Debug, Default)]
+pub(crate) struct Structs(BTreeMap<String, Vec<Param>>);
+
+impl Structs {
+    pub(crate) fn insert(&mut self, name: String, fields: Vec<Param>) -> parser::Result<()> {
+        if self.0.contains_key(&name) {
+            return Err(parser::Error::_new("duplicate struct definition: ", &name));
+        }
+        self.0.insert(name, fields);
+        Ok(())
+    }
+
+    /// Replaces the struct names in the item's parameter types with tuples of
+    /// their fields, and sets the parameters' internal types.
+    pub(crate) fn resolve_item(&self, item: &mut AbiItem<'_>) -> parser::Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        if let Some(params) = item.event_inputs_mut() {
+            for p in params {
+                self.resolve(&mut p.ty, &mut p.components, &mut p.internal_type, 0)?;
+            }
+            return Ok(());
+        }
+        if let Some(params) = item.inputs_mut() {
+            self.resolve_params(params)?;
+        }
+        if let Some(params) = item.outputs_mut() {
+            self.resolve_params(params)?;
+        }
+        Ok(())
+    }
+
+    fn resolve_params(&self, params: &mut [Param]) -> parser::Result<()> {
+        params
+            .iter_mut()
+            .try_for_each(|p| self.resolve(&mut p.ty, &mut p.components, &mut p.internal_type, 0))
+    }
+
+    /// `depth` is the number of structs being expanded, used to reject
+    /// recursive definitions.
+    fn resolve(
+        &self,
+        ty: &mut String,
+        components: &mut Vec<Param>,
+        internal_type: &mut Option<InternalType>,
+        depth: usize,
+    ) -> parser::Result<()> {
+        if !components.is_empty() {
+            return components.iter_mut().try_for_each(|p| {
+                self.resolve(&mut p.ty, &mut p.components, &mut p.internal_type, depth)
+            });
+        }
+
+        let name_len = ty.find('[').unwrap_or(ty.len());
+        let Some(fields) = self.0.get(&ty[..name_len]) else { return Ok(()) };
+        if depth >= self.0.len() {
+            return Err(parser::Error::_new("recursive struct: ", &&ty[..name_len]));
+        }
+        *components = fields.clone();
+        *internal_type = Some(InternalType::Struct { contract: None, ty: ty.clone() });
+        ty.replace_range(..name_len, "tuple");
+        components.iter_mut().try_for_each(|p| {
+            self.resolve(&mut p.ty, &mut p.components, &mut p.internal_type, depth + 1)
+        })
+    }
+}
+
+/// Formats an ABI as human-readable strings.
+pub(crate) fn format(abi: &JsonAbi) -> Vec<String> {
+    let mut f = Formatter::default();
+    let params = abi.constructor.iter().flat_map(|c| &c.inputs);
+    let params = params.chain(abi.functions().flat_map(|f| f.inputs.iter().chain(&f.outputs)));
+    let params = params.chain(abi.errors().flat_map(|e| &e.inputs));
+    for p in params {
+        f.visit(&p.components, p.internal_type.as_ref());
+    }
+    for p in abi.events().flat_map(|e| &e.inputs) {
+        f.visit(&p.components, p.internal_type.as_ref());
+    }
+
+    let mut out = Vec::with_capacity(f.structs.len() + abi.len());
+    for (name, fields) in &f.structs {
+        if f.conflicting.contains(name) {
+            continue;
+        }
+        let mut s = format!("struct {name} {{ ");
+        for field in *fields {
+            f.param(field, &mut s);
+            s.push_str("; ");
+        }
+        s.push('}');
+        out.push(s);
+    }
+    out.extend(abi.items().map(|item| f.item(&item)));
+    out
+}
+
+#[derive(Default)]
+struct Formatter<'a> {
+    structs: BTreeMap<&'a str, &'a [Param]>,
+    /// Names defined by several structs with different fields, which are
+    /// formatted as tuples instead.
+    conflicting: BTreeSet<&'a str>,
+}
+
+impl<'a> Formatter<'a> {
+    fn visit(&mut self, components: &'a [Param], internal_type: Option<&'a InternalType>) {
+        if let Some(name) = struct_name(components, internal_type) {
+            match self.structs.get(name) {
+                None => {
+                    self.structs.insert(name, components);
+                }
+                Some(&fields) if fields != components => {
+                    self.conflicting.insert(name);
+                }
+                Some(_) => {}
+            }
+        }
+        for p in components {
+            self.visit(&p.components, p.internal_type.as_ref());
+        }
+    }
+
+    fn item(&self, item: &AbiItem<'_>) -> String {
+        let mut s = String::new();
+        match item {
+            AbiItem::Constructor(c) => {
+                s.push_str("constructor");
+                self.params(&c.inputs, &mut s);
+                push_state_mutability(c.state_mutability, &mut s);
+            }
+            AbiItem::Fallback(f) => {
+                s.push_str("fallback() external");
+                push_state_mutability(f.state_mutability, &mut s);
+            }
+            AbiItem::Receive(_) => s.push_str("receive() external payable"),
+            AbiItem::Function(f) => {
+                s.push_str("function ");
+                s.push_str(&f.name);
+                self.params(&f.inputs, &mut s);
+                push_state_mutability(f.state_mutability, &mut s);
+                if !f.outputs.is_empty() {
+                    s.push_str(" returns ");
+                    self.params(&f.outputs, &mut s);
+                }
+            }
+            AbiItem::Event(e) => {
+                s.push_str("event ");
+                s.push_str(&e.name);
+                s.push('(');
+                for (i, p) in e.inputs.iter().enumerate() {
+                    if i > 0 {
+                        s.push_str(", ");
+                    }
+                    self.event_param(p, &mut s);
+                }
+                s.push(')');
+                if e.anonymous {
+                    s.push_str(" anonymous");
+                }
+            }
+            AbiItem::Error(e) => {
+                s.push_str("error ");
+                s.push_str(&e.name);
+                self.params(&e.inputs, &mut s);
+            }
+        }
+        s
+    }
+
+    fn params(&self, params: &[Param], s: &mut String) {
+        s.push('(');
+        for (i, p) in params.iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            self.param(p, s);
+        }
+        s.push(')');
+    }
+
+    fn param(&self, p: &Param, s: &mut String) {
+        self.ty(&p.ty, &p.components, p.internal_type.as_ref(), s);
+        push_name(&p.name, s);
+    }
+
+    fn event_param(&self, p: &EventParam, s: &mut String) {
+        self.ty(&p.ty, &p.components, p.internal_type.as_ref(), s);
+        if p.indexed {
+            s.push_str(" indexed");
+        }
+        push_name(&p.name, s);
+    }
+
+    fn ty(
+        &self,
+        ty: &str,
+        components: &[Param],
+        internal_type: Option<&InternalType>,
+        s: &mut String,
+    ) {
+        let suffix = match ty.strip_prefix("tuple") {
+            Some(suffix) if suffix.is_empty() || suffix.starts_with('[') => suffix,
+            _ => {
+                s.push_str(ty);
+                return;
+            }
+        };
+        match struct_name(components, internal_type) {
+            Some(name) if !self.conflicting.contains(name) => s.push_str(name),
+            _ => {
+                s.push_str("tuple");
+                self.params(components, s);
+            }
+        }
+        s.push_str(suffix);
+    }
+}
+
+/// Returns the name of the struct described by a tuple parameter, without its
+/// contract qualifier.
+fn struct_name<'a>(
+    components: &[Param],
+    internal_type: Option<&'a InternalType>,
+) -> Option<&'a str> {
+    if components.is_empty() {
+        return None;
+    }
+    let (_, ty) = internal_type?.as_struct()?;
+    Some(ty.split('[').next().unwrap_or(ty))
+}
+
+fn push_name(name: &str, s: &mut String) {
+    if !name.is_empty() {
+        s.push(' ');
+        s.push_str(name);
+    }
+}
+
+fn push_state_mutability(state_mutability: StateMutability, s: &mut String) {
+    if let Some(state_mutability) = state_mutability.as_str() {
+        s.push(' ');
+        s.push_str(state_mutability);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(strings: &[&str]) -> JsonAbi {
+        let abi = JsonAbi::parse(strings.iter().copied()).unwrap();
+        let formatted = abi.to_human_readable();
+        assert_eq!(formatted, strings);
+        assert_eq!(JsonAbi::parse(formatted.iter().map(String::as_str)).unwrap(), abi);
+        abi
+    }
+
+    #[test]
+    fn items() {
+        let abi = roundtrip(&[
+            "constructor(string symbol, string name) payable",
+            "fallback() external payable",
+            "receive() external payable",
+            "function balanceOf(address owner) view returns (uint256 balance)",
+            "function deposit() payable",
+            "function hash(bytes data) pure returns (bytes32)",
+            "function transfer(address to, uint256 value) returns (bool)",
+            "event Anon(uint256 indexed a, bool b) anonymous",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "error InsufficientBalance(address owner, uint256 balance)",
+        ]);
+        assert_eq!(abi.len(), 10);
+        assert_eq!(abi.constructor.unwrap().state_mutability, StateMutability::Payable);
+        assert_eq!(abi.fallback.unwrap().state_mutability, StateMutability::Payable);
+        assert!(abi.events["Anon"][0].anonymous);
+        assert_eq!(abi.functions["hash"][0].state_mutability, StateMutability::Pure);
+    }
+
+    #[test]
+    fn modifiers() {
+        let abi = JsonAbi::parse([
+            "function a() external view returns (uint256)",
+            "function b() public constant returns (uint256)",
+            "function c() external",
+            "fallback() external",
+        ])
+        .unwrap();
+        assert_eq!(abi.functions["a"][0].state_mutability, StateMutability::View);
+        assert_eq!(abi.functions["b"][0].state_mutability, StateMutability::View);
+        assert_eq!(abi.functions["c"][0].state_mutability, StateMutability::NonPayable);
+        assert_eq!(abi.fallback.unwrap().state_mutability, StateMutability::NonPayable);
+        assert_eq!(
+            abi.to_human_readable(),
+            [
+                "fallback() external",
+                "function a() view returns (uint256)",
+                "function b() view returns (uint256)",
+                "function c()",
+            ]
+        );
+
+        for invalid in [
+            "function a() internal",
+            "function a() view pure",
+            "event A() view",
+            "error A() payable",
+            "constructor() view",
+            "receive() external view",
+        ] {
+            JsonAbi::parse([invalid]).unwrap_err();
+        }
+        // receive functions are always payable
+        let abi = JsonAbi::parse(["receive() external"]).unwrap();
+        assert_eq!(abi.receive.unwrap().state_mutability, StateMutability::Payable);
+        JsonAbi::parse(["fallback(uint256)"]).unwrap_err();
+    }
+
+    #[test]
+    fn structs() {
+        let abi = roundtrip(&[
+            "struct Order { address maker; Side side; uint256[2] amounts; }",
+            "struct Side { bool buy; }",
+            "function fill(Order order, Order[] others) returns (tuple(bool ok, Side side)[] results)",
+            "event Filled(Order indexed order)",
+        ]);
+        let fill = &abi.functions["fill"][0];
+        assert_eq!(
+            fill.signature(),
+            "fill((address,(bool),uint256[2]),(address,(bool),uint256[2])[])"
+        );
+        let order = &fill.inputs[0];
+        assert_eq!(order.ty, "tuple");
+        assert_eq!(
+            order.internal_type,
+            Some(InternalType::Struct { contract: None, ty: "Order".into() })
+        );
+        assert_eq!(order.components[1].components[0].name, "buy");
+        assert_eq!(
+            fill.inputs[1].internal_type,
+            Some(InternalType::Struct { contract: None, ty: "Order[]".into() })
+        );
+        assert_eq!(fill.outputs[0].ty, "tuple[]");
+        assert_eq!(fill.outputs[0].components[1].ty, "tuple");
+
+        // unknown types are kept as-is
+        let abi = JsonAbi::parse(["struct A { uint a; }", "function f(B b)"]).unwrap();
+        assert_eq!(abi.functions["f"][0].inputs[0].ty, "B");
+
+        JsonAbi::parse(["struct A { B b; }", "struct B { A a; }", "function f(A a)"]).unwrap_err();
+        JsonAbi::parse(["struct A { uint a; }", "struct A { uint b; }"]).unwrap_err();
+        JsonAbi::parse(["struct A uint a; }"]).unwrap_err();
+        JsonAbi::parse(["struct A { uint a;"]).unwrap_err();
+        AbiItem::parse("struct A { uint a; }").unwrap_err();
+    }
+
+    #[test]
+    fn json_structs() {
+        let json = r#"[{
+            "type": "function",
+            "name": "f",
+            "inputs": [
+                {
+                    "name": "a",
+                    "type": "tuple",
+                    "internalType": "struct C.S",
+                    "components": [{ "name": "x", "type": "uint256", "internalType": "uint256" }]
+                },
+                {
+                    "name": "b",
+                    "type": "tuple[]",
+                    "internalType": "struct D.S[]",
+                    "components": [{ "name": "y", "type": "bool", "internalType": "bool" }]
+                }
+            ],
+            "outputs": [],
+            "stateMutability": "view"
+        }]"#;
+        let abi: JsonAbi = serde_json::from_str(json).unwrap();
+        // `C.S` and `D.S` differ, so they are formatted as tuples
+        let formatted = abi.to_human_readable();
+        assert_eq!(formatted, ["function f(tuple(uint256 x) a, tuple(bool y)[] b) view"]);
+
+        let parsed = JsonAbi::parse(formatted.iter().map(String::as_str)).unwrap();
+        assert_eq!(parsed.functions["f"][0].selector(), abi.functions["f"][0].selector());
+    }
+}
@@ -160,12 +160,17 @@ impl AbiItem<'_> {
         let copy = input;
         match parser::utils::parse_item(&mut input)? {
             "constructor" => Constructor::parse(copy).map(Into::into),
+            "fallback" => Fallback::parse(copy).map(Into::into),
+            "receive" => Receive::parse(copy).map(Into::into),
             "function" => Function::parse(input).map(Into::into),
             "error" => Error::parse(input).map(Into::into),
             "event" => Event::parse(input).map(Into::into),
+            "struct" => Err(parser::Error::new(
+                "struct definitions are not ABI items; use `JsonAbi::parse` to resolve them",
+            )),
             keyword => Err(parser::Error::new(format_args!(
-                "invalid AbiItem keyword: {keyword:?}, \
-                 expected one of \"constructor\", \"function\", \"error\", or \"event\""
+                "invalid AbiItem keyword: {keyword:?}, expected one of \"constructor\", \
+                 \"fallback\", \"receive\", \"function\", \"error\", or \"event\""
             ))),
         }
     }
@@ -334,8 +339,8 @@ impl Constructor {
     ///
     /// Note:
     /// - the name must always be `constructor`
-    /// - [`state_mutability`](Self::state_mutability) is currently not parsed from the input and is
-    ///   always set to [`StateMutability::NonPayable`]
+    /// - the constructor may be followed by `payable`; [`state_mutability`](Self::state_mutability)
+    ///   defaults to [`StateMutability::NonPayable`]
     ///
     /// # Examples
     ///
@@ -354,7 +359,9 @@ impl Constructor {
         parse_sig::<false>(s).and_then(Self::parsed)
     }
 
-    fn parsed((name, inputs, outputs, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
         if name != "constructor" {
             return Err(parser::Error::new("constructors' name must be exactly \"constructor\""));
         }
@@ -364,7 +371,116 @@ impl Constructor {
         if anonymous {
             return Err(parser::Error::new("constructors cannot be anonymous"));
         }
-        Ok(Self { inputs, state_mutability: StateMutability::NonPayable })
+        let state_mutability = state_mutability.unwrap_or_default();
+        if !matches!(state_mutability, StateMutability::NonPayable | StateMutability::Payable) {
+            return Err(parser::Error::new("constructors can only be payable or nonpayable"));
+        }
+        Ok(Self { inputs, state_mutability })
+    }
+}
+
+impl FromStr for Fallback {
+    type Err = parser::Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Fallback {
+    /// Parses a Solidity fallback function string:
+    /// `fallback() $(external)? $(payable)?`, or
+    /// `fallback(bytes) $(external)? $(payable)? returns (bytes)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Fallback, StateMutability};
+    /// assert_eq!(
+    ///     Fallback::parse("fallback() external payable"),
+    ///     Ok(Fallback { state_mutability: StateMutability::Payable }),
+    /// );
+    /// assert_eq!(
+    ///     Fallback::parse("fallback(bytes calldata) external returns (bytes memory)"),
+    ///     Ok(Fallback { state_mutability: StateMutability::NonPayable }),
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        parse_sig::<true>(s).and_then(Self::parsed)
+    }
+
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
+        if name != "fallback" {
+            return Err(parser::Error::new(
+                "fallback functions' name must be exactly \"fallback\"",
+            ));
+        }
+        let is_bytes = |params: &[Param]| matches!(params, [p] if p.ty == "bytes");
+        if !(inputs.is_empty() && outputs.is_empty() || is_bytes(&inputs) && is_bytes(&outputs)) {
+            return Err(parser::Error::new(
+                "fallback functions must either have no parameters, or take and return `bytes`",
+            ));
+        }
+        if anonymous {
+            return Err(parser::Error::new("fallback functions cannot be anonymous"));
+        }
+        let state_mutability = state_mutability.unwrap_or_default();
+        if !matches!(state_mutability, StateMutability::NonPayable | StateMutability::Payable) {
+            return Err(parser::Error::new("fallback functions can only be payable or nonpayable"));
+        }
+        Ok(Self { state_mutability })
+    }
+}
+
+impl FromStr for Receive {
+    type Err = parser::Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Receive {
+    /// Parses a Solidity receive function string:
+    /// `receive() $(external)? $(payable)?`
+    ///
+    /// Receive functions are always payable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Receive, StateMutability};
+    /// assert_eq!(
+    ///     Receive::parse("receive() external payable"),
+    ///     Ok(Receive { state_mutability: StateMutability::Payable }),
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        parse_sig::<false>(s).and_then(Self::parsed)
+    }
+
+    fn parsed(
+        (name, inputs, _, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
+        if name != "receive" {
+            return Err(parser::Error::new("receive functions' name must be exactly \"receive\""));
+        }
+        if !inputs.is_empty() {
+            return Err(parser::Error::new("receive functions cannot have inputs"));
+        }
+        if anonymous {
+            return Err(parser::Error::new("receive functions cannot be anonymous"));
+        }
+        if !matches!(state_mutability, None | Some(StateMutability::Payable)) {
+            return Err(parser::Error::new("receive functions must be payable"));
+        }
+        Ok(Self { state_mutability: StateMutability::Payable })
     }
 }
 
@@ -400,10 +516,15 @@ impl Error {
         parse_maybe_prefixed(s, "error", parse_sig::<false>).and_then(Self::parsed)
     }
 
-    fn parsed((name, inputs, outputs, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
         if !outputs.is_empty() {
             return Err(parser::Error::new("errors cannot have outputs"));
         }
+        if state_mutability.is_some() {
+            return Err(parser::Error::new("errors cannot have a state mutability"));
+        }
         if anonymous {
             return Err(parser::Error::new("errors cannot be anonymous"));
         }
@@ -438,10 +559,10 @@ impl Function {
     /// Parses a Solidity function signature string:
     /// `$(function)? $name($($inputs),*) $(returns ($($outputs),+))?`
     ///
-    /// Note:
-    /// - [`state_mutability`](Self::state_mutability) is currently not parsed from the input and is
-    /// always set to [`StateMutability::NonPayable`]
-    /// - visibility is rejected
+    /// The inputs may be followed by the `external` or `public` visibility,
+    /// which is ignored, and by a state mutability, which defaults to
+    /// [`StateMutability::NonPayable`]. `constant` is accepted as an alias of
+    /// `view`.
     ///
     /// If you want to parse a generic [Human-Readable ABI] string, use [`AbiItem::parse`].
     ///
@@ -483,11 +604,14 @@ impl Function {
         parse_maybe_prefixed(s, "function", parse_sig::<true>).and_then(Self::parsed)
     }
 
-    fn parsed((name, inputs, outputs, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
         if anonymous {
             return Err(parser::Error::new("function cannot be anonymous"));
         }
-        Ok(Self { name, inputs, outputs, state_mutability: StateMutability::NonPayable })
+        let state_mutability = state_mutability.unwrap_or_default();
+        Ok(Self { name, inputs, outputs, state_mutability })
     }
 
     /// Returns this function's signature: `$name($($inputs),*)`.
@@ -564,11 +688,14 @@ impl Event {
     }
 
     fn parsed(
-        (name, inputs, outputs, anonymous): ParseSigTuple<EventParam>,
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<EventParam>,
     ) -> parser::Result<Self> {
         if !outputs.is_empty() {
             return Err(parser::Error::new("events cannot have outputs"));
         }
+        if state_mutability.is_some() {
+            return Err(parser::Error::new("events cannot have a state mutability"));
+        }
         Ok(Self { name, inputs, anonymous })
     }
 
@@ -650,4 +777,30 @@ mod tests {
         assert_eq!(Error::parse("errorfoo()"), Ok(new("errorfoo")));
         assert_eq!(Error::parse("error errorfoo()"), Ok(new("errorfoo")));
     }
+
+    #[test]
+    fn parse_fallback() {
+        let new = |state_mutability| Fallback { state_mutability };
+        assert_eq!(Fallback::parse("fallback()"), Ok(new(StateMutability::NonPayable)));
+        assert_eq!(
+            Fallback::parse("fallback() external payable"),
+            Ok(new(StateMutability::Payable))
+        );
+        assert_eq!(
+            Fallback::parse("fallback(bytes calldata) returns (bytes)"),
+            Ok(new(StateMutability::NonPayable))
+        );
+        assert_eq!(
+            Fallback::parse(
+                "fallback(bytes calldata input) external payable returns (bytes memory output)"
+            ),
+            Ok(new(StateMutability::Payable))
+        );
+
+        assert!(Fallback::parse("fallback(bytes)").is_err());
+        assert!(Fallback::parse("fallback() returns (bytes)").is_err());
+        assert!(Fallback::parse("fallback(uint256) returns (bytes)").is_err());
+        assert!(Fallback::parse("fallback(bytes) returns (bytes, bytes)").is_err());
+        assert!(Fallback::parse("fallback() view").is_err());
+    }
 }
@@ -36,6 +36,11 @@ use serde::{Deserialize, Serialize};
 mod abi;
 pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
 
+pub mod artifact;
+
+mod bytecode;
+pub use bytecode::{Bytecode, LinkError, LinkReference, LinkReferences, UnlinkedBytecode};
+
 mod item;
 pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
 
@@ -45,6 +50,8 @@ pub use param::{EventParam, Param};
 mod internal_type;
 pub use internal_type::InternalType;
 
+mod human_readable;
+
 mod to_sol;
 
 pub(crate) mod utils;
@@ -72,6 +79,21 @@ pub enum StateMutability {
 }
 
 impl StateMutability {
+    /// Parses a state mutability from a string.
+    ///
+    /// `constant` is accepted as an alias of `view`, as used by Solidity
+    /// before 0.5.0.
+    #[inline]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pure" => Some(Self::Pure),
+            "view" | "constant" => Some(Self::View),
+            "nonpayable" => Some(Self::NonPayable),
+            "payable" => Some(Self::Payable),
+            _ => None,
+        }
+    }
+
     /// Returns the string representation of the state mutability.
     #[inline]
     pub const fn as_str(self) -> Option<&'static str> {
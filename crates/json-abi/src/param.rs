@@ -102,7 +102,7 @@ impl Param {
     /// );
     /// ```
     pub fn parse(input: &str) -> parser::Result<Self> {
-        ParameterSpecifier::parse(input).map(|p| mk_param(p.name, p.ty))
+        ParameterSpecifier::parse(input).map(mk_param)
     }
 
     /// The internal type of the parameter.
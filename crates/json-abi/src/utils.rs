@@ -5,7 +5,7 @@ use alloc::{
 };
 use alloy_primitives::Selector;
 use core::{fmt::Write, num::NonZeroUsize};
-use parser::{ParameterSpecifier, TypeSpecifier, TypeStem};
+use parser::{ParameterSpecifier, TypeStem};
 
 /// Capacity to allocate per [Param].
 const PARAM: usize = 32;
@@ -160,7 +160,7 @@ pub(crate) fn selector(preimage: &str) -> Selector {
     }
 }
 
-pub(crate) type ParseSigTuple<T> = (String, Vec<T>, Vec<T>, bool);
+pub(crate) type ParseSigTuple<T> = (String, Vec<T>, Vec<T>, Option<StateMutability>, bool);
 pub(crate) type ParseSigResult<T> = parser::Result<ParseSigTuple<T>>;
 
 /// Strips `prefix` from `s` before parsing with `parser`. `prefix` must be followed by whitespace.
@@ -179,36 +179,44 @@ pub(crate) fn parse_maybe_prefixed<F: FnOnce(&str) -> R, R>(
 
 #[inline]
 pub(crate) fn parse_sig<const O: bool>(s: &str) -> ParseSigResult<Param> {
-    parser::utils::parse_signature::<O, _, _>(s, |p| mk_param(p.name, p.ty))
+    parser::utils::parse_signature::<O, _, _>(s, mk_param).and_then(sig_state_mutability)
 }
 
 #[inline]
 pub(crate) fn parse_event_sig(s: &str) -> ParseSigResult<EventParam> {
-    parser::utils::parse_signature::<false, _, _>(s, mk_eparam)
+    parser::utils::parse_signature::<false, _, _>(s, mk_eparam).and_then(sig_state_mutability)
 }
 
-pub(crate) fn mk_param(name: Option<&str>, ty: TypeSpecifier<'_>) -> Param {
-    let name = name.unwrap_or_default().into();
-    let internal_type = None;
-    match ty.stem {
-        TypeStem::Root(s) => {
-            Param { name, ty: ty_string(s.span(), &ty.sizes), components: vec![], internal_type }
-        }
-        TypeStem::Tuple(t) => Param {
-            name,
-            ty: ty_string("tuple", &ty.sizes),
-            components: t.types.into_iter().map(|ty| mk_param(None, ty)).collect(),
-            internal_type,
-        },
-    }
+fn sig_state_mutability<T>(
+    (name, inputs, outputs, state_mutability, anonymous): parser::utils::ParsedSignature<'_, T>,
+) -> ParseSigResult<T> {
+    let state_mutability = state_mutability
+        .map(|s| {
+            StateMutability::parse(s)
+                .ok_or_else(|| parser::Error::_new("invalid state mutability: ", &s))
+        })
+        .transpose()?;
+    Ok((name, inputs, outputs, state_mutability, anonymous))
+}
+
+pub(crate) fn mk_param(spec: ParameterSpecifier<'_>) -> Param {
+    let name = spec.name.unwrap_or_default().into();
+    let ty = match &spec.ty.stem {
+        TypeStem::Root(s) => ty_string(s.span(), &spec.ty.sizes),
+        TypeStem::Tuple(_) => ty_string("tuple", &spec.ty.sizes),
+    };
+    // the parsed components mirror the tuple's types, with their names
+    let components = spec.into_components().into_iter().map(mk_param).collect();
+    Param { name, ty, components, internal_type: None }
 }
 
 pub(crate) fn mk_eparam(spec: ParameterSpecifier<'_>) -> EventParam {
-    let p = mk_param(spec.name, spec.ty);
+    let indexed = spec.indexed;
+    let p = mk_param(spec);
     EventParam {
         name: p.name,
         ty: p.ty,
-        indexed: spec.indexed,
+        indexed,
         components: p.components,
         internal_type: p.internal_type,
     }
@@ -425,54 +433,69 @@ mod tests {
 
     #[test]
     fn test_item_parse() {
-        assert_eq!(parse_sig::<true>("foo()"), Ok(("foo".into(), vec![], vec![], false)));
-        assert_eq!(parse_sig::<true>("foo()()"), Ok(("foo".into(), vec![], vec![], false)));
-        assert_eq!(parse_sig::<true>("foo(,) \t ()"), Ok(("foo".into(), vec![], vec![], false)));
-        assert_eq!(parse_sig::<true>("foo(,)  (,)"), Ok(("foo".into(), vec![], vec![], false)));
+        assert_eq!(parse_sig::<true>("foo()"), Ok(("foo".into(), vec![], vec![], None, false)));
+        assert_eq!(parse_sig::<true>("foo()()"), Ok(("foo".into(), vec![], vec![], None, false)));
+        assert_eq!(
+            parse_sig::<true>("foo(,) \t ()"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
+        assert_eq!(
+            parse_sig::<true>("foo(,)  (,)"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
 
-        assert_eq!(parse_sig::<false>("foo()"), Ok(("foo".into(), vec![], vec![], false)));
+        assert_eq!(parse_sig::<false>("foo()"), Ok(("foo".into(), vec![], vec![], None, false)));
         parse_sig::<false>("foo()()").unwrap_err();
         parse_sig::<false>("foo(,)()").unwrap_err();
         parse_sig::<false>("foo(,)(,)").unwrap_err();
 
-        assert_eq!(parse_sig::<false>("foo()anonymous"), Ok(("foo".into(), vec![], vec![], true)));
+        assert_eq!(
+            parse_sig::<false>("foo()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
+        );
         assert_eq!(
             parse_sig::<false>("foo()\t anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
 
-        assert_eq!(parse_sig::<true>("foo()anonymous"), Ok(("foo".into(), vec![], vec![], true)));
+        assert_eq!(
+            parse_sig::<true>("foo()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
+        );
         assert_eq!(
             parse_sig::<true>("foo()\t anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
 
         assert_eq!(
             parse_sig::<true>("foo() \t ()anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            Ok(("foo".into(), vec![], vec![], None, true))
+        );
+        assert_eq!(
+            parse_sig::<true>("foo()()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
-        assert_eq!(parse_sig::<true>("foo()()anonymous"), Ok(("foo".into(), vec![], vec![], true)));
         assert_eq!(
             parse_sig::<true>("foo()()\t anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
 
         assert_eq!(
             parse_sig::<false>("foo(uint256 param)"),
-            Ok(("foo".into(), vec![param("uint256")], vec![], false))
+            Ok(("foo".into(), vec![param("uint256")], vec![], None, false))
         );
         assert_eq!(
             parse_sig::<false>("bar(uint256 param)"),
-            Ok(("bar".into(), vec![param("uint256")], vec![], false))
+            Ok(("bar".into(), vec![param("uint256")], vec![], None, false))
         );
         assert_eq!(
             parse_sig::<false>("baz(uint256 param, bool param)"),
-            Ok(("baz".into(), vec![param("uint256"), param("bool")], vec![], false))
+            Ok(("baz".into(), vec![param("uint256"), param("bool")], vec![], None, false))
         );
 
         assert_eq!(
             parse_sig::<true>("f(a b)(c d)"),
-            Ok(("f".into(), vec![param2("a", "b")], vec![param2("c", "d")], false))
+            Ok(("f".into(), vec![param2("a", "b")], vec![param2("c", "d")], None, false))
         );
 
         assert_eq!(
@@ -481,8 +504,24 @@ mod tests {
                 "toString".into(),
                 vec![param2("uint256", "number")],
                 vec![param2("string", "s")],
+                None,
                 false
             ))
-        )
+        );
+
+        assert_eq!(
+            parse_sig::<true>("foo() external view returns (uint256 param)"),
+            Ok(("foo".into(), vec![], vec![param("uint256")], Some(StateMutability::View), false))
+        );
+        assert_eq!(
+            parse_sig::<true>("foo()public constant(uint256 param)"),
+            Ok(("foo".into(), vec![], vec![param("uint256")], Some(StateMutability::View), false))
+        );
+        assert_eq!(
+            parse_sig::<false>("constructor() payable"),
+            Ok(("constructor".into(), vec![], vec![], Some(StateMutability::Payable), false))
+        );
+        parse_sig::<true>("foo() view pure").unwrap_err();
+        parse_sig::<true>("foo() internal").unwrap_err();
     }
 }
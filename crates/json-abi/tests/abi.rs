@@ -288,10 +288,83 @@ fn get_solc_version() -> Option<(u16, u16, u16)> {
 #[cfg_attr(miri, ignore = "no fs")]
 #[cfg(all(feature = "std", feature = "serde_json"))]
 fn parse_unlinked_contract() {
+    use alloy_primitives::Address;
+
     const TESTDATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/testdata");
     // unlinked placeholder __$7233c33f2e1e35848c685b0eb24649959e$__
     let content = fs::read_to_string(Path::new(TESTDATA_PATH).join("UnlinkedNouns.json")).unwrap();
-    let res = serde_json::from_str::<alloy_json_abi::ContractObject>(&content);
-    let err = res.unwrap_err();
-    assert!(err.to_string().contains("expected bytecode, found unlinked bytecode with placeholder: 7233c33f2e1e35848c685b0eb24649959e"));
+    let mut contract = serde_json::from_str::<alloy_json_abi::ContractObject>(&content).unwrap();
+    let library = "contracts/libs/NFTDescriptorV2.sol:NFTDescriptorV2";
+    for bytecode in [&contract.bytecode, &contract.deployed_bytecode] {
+        assert_eq!(bytecode.as_ref().unwrap().missing_libraries(), [library]);
+    }
+    assert_eq!(contract.metadata(), None);
+
+    let err = contract.link_all([("Other", Address::ZERO)]).unwrap_err();
+    assert_eq!(err.missing(), [library]);
+
+    let address = Address::repeat_byte(0x11);
+    contract.link_all([("NFTDescriptorV2", address)]).unwrap();
+    let bytecode = contract.bytecode.unwrap().into_bytes().unwrap();
+    assert_eq!(bytecode[2536..2556], address);
+    assert_eq!(bytecode[4693..4713], address);
+    let deployed_bytecode = contract.deployed_bytecode.unwrap().into_bytes().unwrap();
+    assert_eq!(deployed_bytecode[2220..2240], address);
+    assert_eq!(deployed_bytecode[4377..4397], address);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn parse_hardhat_link_references() {
+    use alloy_primitives::Address;
+
+    let placeholder = "__$7233c33f2e1e35848c685b0eb24649959e$__";
+    let json = format!(
+        r#"{{
+            "abi": [],
+            "bytecode": "0x73{placeholder}",
+            "deployedBytecode": "0x73{placeholder}30",
+            "linkReferences": {{ "Lib.sol": {{ "Lib": [{{ "start": 1, "length": 20 }}] }} }},
+            "deployedLinkReferences": {{}}
+        }}"#
+    );
+    let contract = serde_json::from_str::<alloy_json_abi::ContractObject>(&json).unwrap();
+    assert_eq!(contract.bytecode.as_ref().unwrap().missing_libraries(), ["Lib.sol:Lib"]);
+    assert_eq!(contract.deployed_bytecode.as_ref().unwrap().missing_libraries(), [placeholder]);
+
+    let mut linked = contract.clone();
+    assert!(linked.link("Lib", Address::ZERO));
+    assert!(linked.bytecode.as_ref().unwrap().is_linked());
+    assert!(!linked.deployed_bytecode.as_ref().unwrap().is_linked());
+
+    let roundtrip = serde_json::to_string(&contract).unwrap();
+    assert_eq!(
+        serde_json::from_str::<alloy_json_abi::ContractObject>(&roundtrip).unwrap(),
+        contract
+    );
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn contract_object_metadata() {
+    let metadata = "a2646970667358221220\
+                    2a4f8d12a9e5a1b3e23ad5cb1fa4f3c9f1c52ecf2f6c7d5e6a1c5d19c6b3f2e1\
+                    64736f6c6343000814\
+                    0033";
+    let json = format!(r#"{{"abi": [], "deployedBytecode": "0x6080604052600080fd{metadata}"}}"#);
+    let contract = serde_json::from_str::<alloy_json_abi::ContractObject>(&json).unwrap();
+
+    let solc = contract.metadata().unwrap().solc.unwrap();
+    assert_eq!(solc.to_string(), "0.8.20");
+    assert_eq!(
+        contract.deployed_bytecode_without_metadata().unwrap()[..],
+        alloy_primitives::hex!("6080604052600080fd")
+    );
+
+    let mut deployed = contract.deployed_bytecode.clone().unwrap().into_bytes().unwrap().to_vec();
+    let len = deployed.len();
+    deployed[len - 20] ^= 0xff;
+    assert!(contract.matches_deployed_bytecode(&deployed));
+    assert!(!contract.matches_deployed_bytecode(&alloy_primitives::hex!("6080604052600080fe")));
+    assert!(!alloy_json_abi::ContractObject::default().matches_deployed_bytecode(&[]));
 }
@@ -0,0 +1,138 @@
+use super::{instructions, OpCode};
+use crate::Selector;
+use alloc::vec::Vec;
+
+/// The set of valid jump destinations in legacy EVM bytecode.
+///
+/// A `JUMPDEST` byte is only a valid destination if it is an opcode, and not
+/// part of a `PUSH` immediate.
+///
+/// Created by [`jump_destinations`](super::jump_destinations).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JumpDestinations {
+    bits: Vec<u64>,
+}
+
+impl JumpDestinations {
+    /// Analyzes the given bytecode.
+    pub fn new(code: &[u8]) -> Self {
+        let mut bits = vec![0u64; (code.len() + 63) / 64];
+        for instruction in instructions(code) {
+            if instruction.opcode == OpCode::JUMPDEST {
+                bits[instruction.pc / 64] |= 1 << (instruction.pc % 64);
+            }
+        }
+        Self { bits }
+    }
+
+    /// Returns `true` if `pc` is a valid jump destination.
+    #[inline]
+    pub fn contains(&self, pc: usize) -> bool {
+        self.bits.get(pc / 64).map_or(false, |word| word & (1 << (pc % 64)) != 0)
+    }
+
+    /// Returns the number of valid jump destinations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if there are no valid jump destinations.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// Returns an iterator over the valid jump destinations, in ascending
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64).filter(move |bit| word & (1 << bit) != 0).map(move |bit| i * 64 + bit)
+        })
+    }
+}
+
+/// Extracts the function selectors which a Solidity or Vyper function
+/// dispatcher compares the calldata against.
+///
+/// This recognizes the `PUSHn <selector> EQ PUSHm <dest> JUMPI` sequence
+/// emitted by the compilers for each external function, where `n <= 4`.
+/// `PUSH1` to `PUSH3` are only considered when preceded by a `DUP1`, since
+/// they are also commonly used for comparisons with small constants.
+///
+/// This is a heuristic: dispatchers generated by other compilers or
+/// obfuscated code may not be recognized, and selectors are returned in the
+/// order they appear in the bytecode, without duplicates.
+pub fn dispatch_selectors(code: &[u8]) -> Vec<Selector> {
+    let instructions = instructions(code).collect::<Vec<_>>();
+    let mut selectors = Vec::new();
+    for (i, window) in instructions.windows(4).enumerate() {
+        let [push, eq, dest, jumpi] = window else { unreachable!() };
+        let n = push.opcode.immediate_size();
+        if !(1..=4).contains(&n)
+            || push.is_truncated()
+            || eq.opcode != OpCode::EQ
+            || !dest.opcode.is_push()
+            || jumpi.opcode != OpCode::JUMPI
+        {
+            continue;
+        }
+        if n < 4 && (i == 0 || instructions[i - 1].opcode != OpCode::DUP1) {
+            continue;
+        }
+
+        let mut selector = Selector::ZERO;
+        selector[4 - n..].copy_from_slice(push.immediate);
+        if !selectors.contains(&selector) {
+            selectors.push(selector);
+        }
+    }
+    selectors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn jumpdests() {
+        // PUSH1 0x5b JUMPDEST PUSH2 0x5b5b JUMPDEST
+        let code = hex!("605b5b615b5b5b");
+        let jumpdests = JumpDestinations::new(&code);
+        assert_eq!(jumpdests.iter().collect::<Vec<_>>(), [2, 6]);
+        assert_eq!(jumpdests.len(), 2);
+        assert!(!jumpdests.contains(1));
+        assert!(jumpdests.contains(2));
+        assert!(!jumpdests.contains(100));
+
+        let jumpdests = JumpDestinations::new(&[0x5b; 130]);
+        assert_eq!(jumpdests.len(), 130);
+        assert_eq!(jumpdests.iter().last(), Some(129));
+
+        assert!(JumpDestinations::new(&[]).is_empty());
+    }
+
+    #[test]
+    fn selectors() {
+        let code = hex!(
+            // PUSH1 0 CALLDATALOAD PUSH1 0xe0 SHR
+            "60003560e01c"
+            // DUP1 PUSH4 0xa9059cbb EQ PUSH2 0x0030 JUMPI
+            "8063a9059cbb1461003057"
+            // DUP1 PUSH3 0x00fdd58e EQ PUSH2 0x0032 JUMPI
+            "8062fdd58e1461003257"
+            // DUP1 PUSH4 0xa9059cbb EQ PUSH2 0x0030 JUMPI (duplicate)
+            "8063a9059cbb1461003057"
+            // PUSH1 0x02 EQ PUSH2 0x0034 JUMPI (not a selector)
+            "60021461003457"
+            // PUSH1 0 DUP1 REVERT
+            "600080fd"
+        );
+        assert_eq!(
+            dispatch_selectors(&code),
+            [Selector::from(hex!("a9059cbb")), Selector::from(hex!("00fdd58e"))]
+        );
+    }
+}
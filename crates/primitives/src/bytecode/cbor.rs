@@ -0,0 +1,136 @@
+//! A minimal CBOR ([RFC 8949]) reader, supporting only the definite-length
+//! subset used by compilers to encode contract metadata.
+//!
+//! [RFC 8949]: https://www.rfc-editor.org/rfc/rfc8949
+
+use alloc::vec::Vec;
+
+/// The maximum nesting depth of arrays and maps.
+const MAX_DEPTH: usize = 8;
+
+/// A decoded CBOR value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Value<'a> {
+    Uint(u64),
+    NegInt(u64),
+    Bytes(&'a [u8]),
+    Text(&'a str),
+    Array(Vec<Value<'a>>),
+    Map(Vec<(Value<'a>, Value<'a>)>),
+    Bool(bool),
+    Null,
+}
+
+impl<'a> Value<'a> {
+    /// Decodes a single value, which must span the entire input.
+    pub(crate) fn decode_exact(mut data: &'a [u8]) -> Option<Self> {
+        let value = Self::decode(&mut data, 0)?;
+        data.is_empty().then_some(value)
+    }
+
+    fn decode(data: &mut &'a [u8], depth: usize) -> Option<Self> {
+        let (&initial, rest) = data.split_first()?;
+        *data = rest;
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+
+        if major == 7 {
+            return match info {
+                20 => Some(Self::Bool(false)),
+                21 => Some(Self::Bool(true)),
+                22 => Some(Self::Null),
+                _ => None,
+            };
+        }
+
+        let arg = match info {
+            0..=23 => info as u64,
+            24 => take(data, 1)?[0] as u64,
+            25 => u16::from_be_bytes(take(data, 2)?.try_into().ok()?) as u64,
+            26 => u32::from_be_bytes(take(data, 4)?.try_into().ok()?) as u64,
+            27 => u64::from_be_bytes(take(data, 8)?.try_into().ok()?),
+            // indefinite lengths and reserved values
+            _ => return None,
+        };
+
+        match major {
+            0 => Some(Self::Uint(arg)),
+            1 => Some(Self::NegInt(arg)),
+            2 => take(data, usize::try_from(arg).ok()?).map(Self::Bytes),
+            3 => {
+                let bytes = take(data, usize::try_from(arg).ok()?)?;
+                core::str::from_utf8(bytes).ok().map(Self::Text)
+            }
+            4 | 5 if depth < MAX_DEPTH => {
+                // every item is at least one byte long
+                let len = usize::try_from(arg).ok().filter(|&len| len <= data.len())?;
+                if major == 4 {
+                    let mut items = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        items.push(Self::decode(data, depth + 1)?);
+                    }
+                    Some(Self::Array(items))
+                } else {
+                    let mut entries = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        let key = Self::decode(data, depth + 1)?;
+                        let value = Self::decode(data, depth + 1)?;
+                        entries.push((key, value));
+                    }
+                    Some(Self::Map(entries))
+                }
+            }
+            // tags, or too deeply nested
+            _ => None,
+        }
+    }
+}
+
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+    let (taken, rest) = data.split_at(len);
+    *data = rest;
+    Some(taken)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn decode() {
+        assert_eq!(Value::decode_exact(&hex!("00")), Some(Value::Uint(0)));
+        assert_eq!(Value::decode_exact(&hex!("1903e8")), Some(Value::Uint(1000)));
+        assert_eq!(Value::decode_exact(&hex!("20")), Some(Value::NegInt(0)));
+        assert_eq!(Value::decode_exact(&hex!("4401020304")), Some(Value::Bytes(&[1, 2, 3, 4])));
+        assert_eq!(Value::decode_exact(&hex!("6161")), Some(Value::Text("a")));
+        assert_eq!(Value::decode_exact(&hex!("f5")), Some(Value::Bool(true)));
+        assert_eq!(
+            Value::decode_exact(&hex!("a1616183010203")),
+            Some(Value::Map(vec![(
+                Value::Text("a"),
+                Value::Array(vec![Value::Uint(1), Value::Uint(2), Value::Uint(3)])
+            )]))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        // empty, trailing data, truncated, indefinite length, tag, invalid UTF-8
+        for data in
+            [&[][..], &hex!("0000"), &hex!("4401"), &hex!("9fff"), &hex!("c000"), &hex!("61ff")]
+        {
+            assert_eq!(Value::decode_exact(data), None, "{}", hex::encode(data));
+        }
+
+        // too deeply nested
+        let mut nested = vec![0x81; MAX_DEPTH];
+        nested.push(0x00);
+        assert!(Value::decode_exact(&nested).is_some());
+        nested.insert(0, 0x81);
+        assert_eq!(Value::decode_exact(&nested), None);
+    }
+}
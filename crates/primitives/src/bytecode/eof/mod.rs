@@ -0,0 +1,536 @@
+//! EVM Object Format (EOF) containers.
+//!
+//! See [EIP-3540] for the container format, and [`Eof::validate`] for the
+//! validation rules.
+//!
+//! [EIP-3540]: https://eips.ethereum.org/EIPS/eip-3540
+
+use crate::Bytes;
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+mod validate;
+pub use validate::CodeError;
+
+/// The magic bytes at the start of every EOF container.
+pub const EOF_MAGIC: [u8; 2] = [0xef, 0x00];
+
+/// The only supported EOF version.
+pub const EOF_VERSION: u8 = 1;
+
+/// The maximum number of code sections in a container.
+pub const MAX_CODE_SECTIONS: usize = 1024;
+
+/// The maximum number of container sections in a container.
+pub const MAX_CONTAINER_SECTIONS: usize = 256;
+
+/// The maximum stack height of a code section.
+pub const MAX_STACK_HEIGHT: u16 = 1023;
+
+const KIND_TERMINATOR: u8 = 0x00;
+const KIND_TYPES: u8 = 0x01;
+const KIND_CODE: u8 = 0x02;
+const KIND_CONTAINER: u8 = 0x03;
+const KIND_DATA: u8 = 0x04;
+
+/// Returns `true` if the given code starts with the [EOF magic](EOF_MAGIC).
+///
+/// Since [EIP-3541], legacy contracts cannot be deployed with code starting
+/// with `0xEF`, so this is enough to tell EOF and legacy code apart.
+///
+/// [EIP-3541]: https://eips.ethereum.org/EIPS/eip-3541
+#[inline]
+pub fn is_eof(code: &[u8]) -> bool {
+    code.starts_with(&EOF_MAGIC)
+}
+
+/// The header of an [`Eof`] container, declaring the sizes of its sections.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EofHeader {
+    /// The size of the type section.
+    pub types_size: u16,
+    /// The sizes of the code sections.
+    pub code_sizes: Vec<u16>,
+    /// The sizes of the container sections.
+    pub container_sizes: Vec<u16>,
+    /// The size of the data section.
+    ///
+    /// The data section itself may be shorter than this in initcode
+    /// containers, as it is completed at deployment.
+    pub data_size: u16,
+}
+
+impl EofHeader {
+    /// Returns the encoded size of the header.
+    #[inline]
+    pub fn size(&self) -> usize {
+        let containers =
+            if self.container_sizes.is_empty() { 0 } else { 3 + 2 * self.container_sizes.len() };
+        // magic, version, types, code, containers, data, terminator
+        2 + 1 + 3 + 3 + 2 * self.code_sizes.len() + containers + 3 + 1
+    }
+
+    /// Returns the size of the body declared by the header, including the full
+    /// data section.
+    #[inline]
+    pub fn body_size(&self) -> usize {
+        self.types_size as usize
+            + self.code_sizes.iter().map(|&s| s as usize).sum::<usize>()
+            + self.container_sizes.iter().map(|&s| s as usize).sum::<usize>()
+            + self.data_size as usize
+    }
+
+    fn decode(code: &[u8]) -> Result<(Self, &[u8]), EofError> {
+        let mut reader = Reader(code);
+        if reader.take(2)? != EOF_MAGIC {
+            return Err(EofError::InvalidMagic);
+        }
+        let version = reader.u8()?;
+        if version != EOF_VERSION {
+            return Err(EofError::UnsupportedVersion(version));
+        }
+
+        reader.expect_kind(KIND_TYPES)?;
+        let types_size = reader.u16()?;
+
+        reader.expect_kind(KIND_CODE)?;
+        let code_sizes = reader.sizes(MAX_CODE_SECTIONS)?;
+
+        let container_sizes = if reader.peek() == Some(KIND_CONTAINER) {
+            reader.u8()?;
+            reader.sizes(MAX_CONTAINER_SECTIONS)?
+        } else {
+            Vec::new()
+        };
+
+        reader.expect_kind(KIND_DATA)?;
+        let data_size = reader.u16()?;
+
+        reader.expect_kind(KIND_TERMINATOR)?;
+
+        let header = Self { types_size, code_sizes, container_sizes, data_size };
+        if header.types_size as usize != header.code_sizes.len() * 4 {
+            return Err(EofError::InvalidHeader("type section size does not match code sections"));
+        }
+        Ok((header, reader.0))
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&EOF_MAGIC);
+        out.push(EOF_VERSION);
+        out.push(KIND_TYPES);
+        out.extend_from_slice(&self.types_size.to_be_bytes());
+        out.push(KIND_CODE);
+        out.extend_from_slice(&(self.code_sizes.len() as u16).to_be_bytes());
+        for size in &self.code_sizes {
+            out.extend_from_slice(&size.to_be_bytes());
+        }
+        if !self.container_sizes.is_empty() {
+            out.push(KIND_CONTAINER);
+            out.extend_from_slice(&(self.container_sizes.len() as u16).to_be_bytes());
+            for size in &self.container_sizes {
+                out.extend_from_slice(&size.to_be_bytes());
+            }
+        }
+        out.push(KIND_DATA);
+        out.extend_from_slice(&self.data_size.to_be_bytes());
+        out.push(KIND_TERMINATOR);
+    }
+}
+
+/// The type of an EOF code section, as declared in the type section.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CodeType {
+    /// The number of stack items the section takes as arguments.
+    pub inputs: u8,
+    /// The number of stack items the section returns, or
+    /// [`NON_RETURNING`](Self::NON_RETURNING).
+    pub outputs: u8,
+    /// The maximum stack height reached while executing the section.
+    pub max_stack_height: u16,
+}
+
+impl CodeType {
+    /// The `outputs` value of a section which never returns to its caller.
+    pub const NON_RETURNING: u8 = 0x80;
+
+    /// Creates a new code section type.
+    #[inline]
+    pub const fn new(inputs: u8, outputs: u8, max_stack_height: u16) -> Self {
+        Self { inputs, outputs, max_stack_height }
+    }
+
+    /// Returns `true` if the section returns to its caller with `RETF`.
+    #[inline]
+    pub const fn is_returning(&self) -> bool {
+        self.outputs != Self::NON_RETURNING
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Self {
+        Self::new(bytes[0], bytes[1], u16::from_be_bytes([bytes[2], bytes[3]]))
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(self.inputs);
+        out.push(self.outputs);
+        out.extend_from_slice(&self.max_stack_height.to_be_bytes());
+    }
+}
+
+impl fmt::Display for CodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "inputs: {}, outputs: ", self.inputs)?;
+        if self.is_returning() {
+            write!(f, "{}", self.outputs)?;
+        } else {
+            f.write_str("non-returning")?;
+        }
+        write!(f, ", max stack height: {}", self.max_stack_height)
+    }
+}
+
+/// An [EIP-3540] EVM Object Format container.
+///
+/// [EIP-3540]: https://eips.ethereum.org/EIPS/eip-3540
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{bytecode::Eof, hex};
+///
+/// // A single code section: `PUSH0 PUSH0 RETURN`
+/// let code = hex!("ef000101000402000100030400000000800002" "5f5ff3");
+/// let eof = Eof::decode(&code).unwrap();
+/// eof.validate().unwrap();
+/// assert_eq!(eof.code_sections.len(), 1);
+/// assert_eq!(eof.encode(), code[..]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Eof {
+    /// The header.
+    pub header: EofHeader,
+    /// The types of the code sections.
+    pub types: Vec<CodeType>,
+    /// The code sections.
+    pub code_sections: Vec<Bytes>,
+    /// The subcontainers, used by `EOFCREATE` and `RETURNCONTRACT`.
+    pub container_sections: Vec<Bytes>,
+    /// The data section.
+    pub data: Bytes,
+}
+
+impl Eof {
+    /// Creates a new container from its sections, computing the header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a section is longer than `u16::MAX` bytes.
+    pub fn new(
+        types: Vec<CodeType>,
+        code_sections: Vec<Bytes>,
+        container_sections: Vec<Bytes>,
+        data: Bytes,
+    ) -> Self {
+        let size = |len: usize| u16::try_from(len).expect("EOF section is too large");
+        let header = EofHeader {
+            types_size: size(types.len() * 4),
+            code_sizes: code_sections.iter().map(|c| size(c.len())).collect(),
+            container_sizes: container_sections.iter().map(|c| size(c.len())).collect(),
+            data_size: size(data.len()),
+        };
+        Self { header, types, code_sections, container_sections, data }
+    }
+
+    /// Decodes a container.
+    ///
+    /// The data section may be shorter than declared in the header, as in
+    /// initcode containers. This does not [validate](Self::validate) the code.
+    pub fn decode(code: &[u8]) -> Result<Self, EofError> {
+        let (header, body) = EofHeader::decode(code)?;
+        if header.code_sizes.is_empty() || header.code_sizes.contains(&0) {
+            return Err(EofError::InvalidHeader("empty code section"));
+        }
+        if header.container_sizes.contains(&0) {
+            return Err(EofError::InvalidHeader("empty container section"));
+        }
+
+        let mut reader = Reader(body);
+        let types = reader
+            .take(header.types_size as usize)?
+            .chunks_exact(4)
+            .map(CodeType::decode)
+            .collect::<Vec<_>>();
+        let mut section = |size: &u16| reader.take(*size as usize).map(Bytes::copy_from_slice);
+        let code_sections = header.code_sizes.iter().map(&mut section).collect::<Result<_, _>>()?;
+        let container_sections =
+            header.container_sizes.iter().map(&mut section).collect::<Result<_, _>>()?;
+        let data = reader.0;
+        if data.len() > header.data_size as usize {
+            return Err(EofError::TrailingBytes);
+        }
+
+        Ok(Self { header, types, code_sections, container_sections, data: data.to_vec().into() })
+    }
+
+    /// Encodes the container.
+    pub fn encode(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.header.size() + self.header.body_size());
+        self.header.encode(&mut out);
+        for ty in &self.types {
+            ty.encode(&mut out);
+        }
+        for section in self.code_sections.iter().chain(&self.container_sections) {
+            out.extend_from_slice(section);
+        }
+        out.extend_from_slice(&self.data);
+        out.into()
+    }
+
+    /// Returns `true` if the data section is shorter than declared in the
+    /// header.
+    #[inline]
+    pub fn is_data_truncated(&self) -> bool {
+        self.data.len() < self.header.data_size as usize
+    }
+}
+
+impl fmt::Display for Eof {
+    /// Formats the header, the disassembled code sections, the sizes of the
+    /// subcontainers and the data section.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "EOF v{EOF_VERSION}: {} code sections, {} container sections, {} data bytes",
+            self.code_sections.len(),
+            self.container_sections.len(),
+            self.header.data_size,
+        )?;
+        for (i, (ty, code)) in self.types.iter().zip(&self.code_sections).enumerate() {
+            writeln!(f, "code section {i} ({ty}):")?;
+            validate::disassemble(code, f)?;
+        }
+        for (i, container) in self.container_sections.iter().enumerate() {
+            writeln!(f, "container section {i}: {} bytes", container.len())?;
+        }
+        if !self.data.is_empty() {
+            writeln!(f, "data: {}", self.data)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error decoding or validating an [`Eof`] container.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EofError {
+    /// The container does not start with the [EOF magic](EOF_MAGIC).
+    InvalidMagic,
+    /// The container version is not supported.
+    UnsupportedVersion(u8),
+    /// The header is malformed.
+    InvalidHeader(&'static str),
+    /// The container ends before the end of a section.
+    UnexpectedEof,
+    /// The container is longer than declared in its header.
+    TrailingBytes,
+    /// A code section type is invalid.
+    InvalidType {
+        /// The index of the code section.
+        section: usize,
+        /// The reason.
+        reason: &'static str,
+    },
+    /// A code section is invalid.
+    InvalidCode {
+        /// The index of the code section.
+        section: usize,
+        /// The offset of the invalid instruction in the section.
+        pc: usize,
+        /// The reason.
+        error: CodeError,
+    },
+    /// A code section is not reachable from the first code section.
+    UnreachableCodeSection(usize),
+    /// A subcontainer is invalid.
+    InvalidContainer {
+        /// The index of the container section.
+        section: usize,
+        /// The error in the subcontainer.
+        error: Box<EofError>,
+    },
+    /// The subcontainers are nested too deeply.
+    NestingTooDeep,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EofError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidContainer { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for EofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("invalid EOF magic"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported EOF version {v}"),
+            Self::InvalidHeader(reason) => write!(f, "invalid EOF header: {reason}"),
+            Self::UnexpectedEof => f.write_str("unexpected end of EOF container"),
+            Self::TrailingBytes => f.write_str("trailing bytes after EOF container"),
+            Self::InvalidType { section, reason } => {
+                write!(f, "invalid type of code section {section}: {reason}")
+            }
+            Self::InvalidCode { section, pc, error } => {
+                write!(f, "invalid code section {section} at offset {pc:#x}: {error}")
+            }
+            Self::UnreachableCodeSection(section) => {
+                write!(f, "code section {section} is unreachable")
+            }
+            Self::InvalidContainer { section, error } => {
+                write!(f, "invalid container section {section}: {error}")
+            }
+            Self::NestingTooDeep => f.write_str("EOF containers are nested too deeply"),
+        }
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    #[inline]
+    fn take(&mut self, n: usize) -> Result<&'a [u8], EofError> {
+        if self.0.len() < n {
+            return Err(EofError::UnexpectedEof);
+        }
+        let (bytes, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.0.first().copied()
+    }
+
+    #[inline]
+    fn u8(&mut self) -> Result<u8, EofError> {
+        self.take(1).map(|b| b[0])
+    }
+
+    #[inline]
+    fn u16(&mut self) -> Result<u16, EofError> {
+        self.take(2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    #[inline]
+    fn expect_kind(&mut self, kind: u8) -> Result<(), EofError> {
+        if self.u8()? != kind {
+            return Err(EofError::InvalidHeader(match kind {
+                KIND_TYPES => "expected type section",
+                KIND_CODE => "expected code section",
+                KIND_DATA => "expected data section",
+                _ => "expected header terminator",
+            }));
+        }
+        Ok(())
+    }
+
+    fn sizes(&mut self, max: usize) -> Result<Vec<u16>, EofError> {
+        let n = self.u16()? as usize;
+        if n == 0 || n > max {
+            return Err(EofError::InvalidHeader("invalid number of sections"));
+        }
+        (0..n).map(|_| self.u16()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::string::ToString;
+
+    #[test]
+    fn roundtrip() {
+        let eof = Eof::new(
+            vec![CodeType::new(0, 0x80, 0), CodeType::new(0, 0, 0)],
+            vec![Bytes::from_static(&hex!("e3000100")), Bytes::from_static(&hex!("e4"))],
+            vec![Bytes::from_static(&hex!("ef00"))],
+            Bytes::from_static(&hex!("aabb")),
+        );
+        let encoded = eof.encode();
+        assert_eq!(
+            encoded,
+            hex!(
+                "ef0001"
+                "010008"
+                "020002" "0004" "0001"
+                "030001" "0002"
+                "040002"
+                "00"
+                "00800000" "00000000"
+                "e3000100" "e4"
+                "ef00"
+                "aabb"
+            )[..]
+        );
+        assert_eq!(eof.header.size() + eof.header.body_size(), encoded.len());
+        assert_eq!(Eof::decode(&encoded).unwrap(), eof);
+        assert!(is_eof(&encoded));
+        assert!(!is_eof(&hex!("6080")));
+    }
+
+    #[test]
+    fn truncated_data() {
+        let code = hex!("ef000101000402000100010400040000800000" "00" "aa");
+        let eof = Eof::decode(&code).unwrap();
+        assert!(eof.is_data_truncated());
+        assert_eq!(eof.encode(), code[..]);
+
+        let code = hex!("ef000101000402000100010400010000800000" "00" "aabb");
+        assert_eq!(Eof::decode(&code), Err(EofError::TrailingBytes));
+    }
+
+    #[test]
+    fn decode_errors() {
+        let cases: &[(&[u8], EofError)] = &[
+            (&hex!("ef01"), EofError::InvalidMagic),
+            (&hex!("ef0002"), EofError::UnsupportedVersion(2)),
+            (&hex!("ef0001"), EofError::UnexpectedEof),
+            (&hex!("ef0001020004"), EofError::InvalidHeader("expected type section")),
+            (&hex!("ef0001010004020000"), EofError::InvalidHeader("invalid number of sections")),
+            (
+                &hex!("ef000101000802000100010400000000800000"),
+                EofError::InvalidHeader("type section size does not match code sections"),
+            ),
+            (
+                &hex!("ef00010100040200010000040000000080000000"),
+                EofError::InvalidHeader("empty code section"),
+            ),
+            (&hex!("ef000101000402000100010400000000800000"), EofError::UnexpectedEof),
+        ];
+        for (code, error) in cases {
+            assert_eq!(Eof::decode(code).as_ref(), Err(error), "{}", hex::encode(code));
+        }
+    }
+
+    #[test]
+    fn display() {
+        let code = hex!("ef000101000402000100040400020000800002" "5f5ff300" "aabb");
+        let eof = Eof::decode(&code).unwrap();
+        assert_eq!(
+            eof.to_string(),
+            "EOF v1: 1 code sections, 0 container sections, 2 data bytes\n\
+             code section 0 (inputs: 0, outputs: non-returning, max stack height: 2):\n\
+             0000: PUSH0\n\
+             0001: PUSH0\n\
+             0002: RETURN\n\
+             0003: STOP\n\
+             data: 0xaabb\n"
+        );
+    }
+}
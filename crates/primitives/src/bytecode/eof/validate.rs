@@ -0,0 +1,666 @@
+//! EOF code validation.
+//!
+//! Implements the rules of [EIP-3670] (valid instructions), [EIP-4750]
+//! (functions) and [EIP-5450] (stack validation), as amended by the EOF
+//! specification.
+//!
+//! [EIP-3670]: https://eips.ethereum.org/EIPS/eip-3670
+//! [EIP-4750]: https://eips.ethereum.org/EIPS/eip-4750
+//! [EIP-5450]: https://eips.ethereum.org/EIPS/eip-5450
+
+use super::{CodeType, Eof, EofError, MAX_STACK_HEIGHT};
+use crate::bytecode::OpCode;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+
+/// The maximum nesting depth of subcontainers.
+const MAX_DEPTH: usize = 16;
+
+const DATALOAD: u8 = 0xd0;
+const DATALOADN: u8 = 0xd1;
+const DATASIZE: u8 = 0xd2;
+const DATACOPY: u8 = 0xd3;
+const RJUMP: u8 = 0xe0;
+const RJUMPI: u8 = 0xe1;
+const RJUMPV: u8 = 0xe2;
+const CALLF: u8 = 0xe3;
+const RETF: u8 = 0xe4;
+const JUMPF: u8 = 0xe5;
+const DUPN: u8 = 0xe6;
+const SWAPN: u8 = 0xe7;
+const EXCHANGE: u8 = 0xe8;
+const EOFCREATE: u8 = 0xec;
+const RETURNCONTRACT: u8 = 0xee;
+const RETURNDATALOAD: u8 = 0xf7;
+const EXTCALL: u8 = 0xf8;
+const EXTDELEGATECALL: u8 = 0xf9;
+const EXTSTATICCALL: u8 = 0xfb;
+
+/// Legacy opcodes which are not valid in EOF code.
+const DEPRECATED: [OpCode; 16] = [
+    OpCode::CODESIZE,
+    OpCode::CODECOPY,
+    OpCode::EXTCODESIZE,
+    OpCode::EXTCODECOPY,
+    OpCode::EXTCODEHASH,
+    OpCode::JUMP,
+    OpCode::JUMPI,
+    OpCode::PC,
+    OpCode::GAS,
+    OpCode::CREATE,
+    OpCode::CALL,
+    OpCode::CALLCODE,
+    OpCode::DELEGATECALL,
+    OpCode::CREATE2,
+    OpCode::STATICCALL,
+    OpCode::SELFDESTRUCT,
+];
+
+/// An opcode of EOF code.
+#[derive(Clone, Copy)]
+struct EofOpCode {
+    name: &'static str,
+    inputs: u8,
+    outputs: u8,
+    /// The immediate size; variable for `RJUMPV`.
+    immediate: u8,
+    terminating: bool,
+}
+
+/// Returns the EOF opcode, or `None` if it is not valid in EOF code.
+fn eof_opcode(opcode: u8) -> Option<EofOpCode> {
+    let new = |name, inputs, outputs, immediate, terminating| {
+        Some(EofOpCode { name, inputs, outputs, immediate, terminating })
+    };
+    match opcode {
+        DATALOAD => new("DATALOAD", 1, 1, 0, false),
+        DATALOADN => new("DATALOADN", 0, 1, 2, false),
+        DATASIZE => new("DATASIZE", 0, 1, 0, false),
+        DATACOPY => new("DATACOPY", 3, 0, 0, false),
+        RJUMP => new("RJUMP", 0, 0, 2, true),
+        RJUMPI => new("RJUMPI", 1, 0, 2, false),
+        RJUMPV => new("RJUMPV", 1, 0, 1, false),
+        // the stack effects of the following depend on their immediates
+        CALLF => new("CALLF", 0, 0, 2, false),
+        RETF => new("RETF", 0, 0, 0, true),
+        JUMPF => new("JUMPF", 0, 0, 2, true),
+        DUPN => new("DUPN", 0, 0, 1, false),
+        SWAPN => new("SWAPN", 0, 0, 1, false),
+        EXCHANGE => new("EXCHANGE", 0, 0, 1, false),
+        EOFCREATE => new("EOFCREATE", 4, 1, 1, false),
+        RETURNCONTRACT => new("RETURNCONTRACT", 2, 0, 1, true),
+        RETURNDATALOAD => new("RETURNDATALOAD", 1, 1, 0, false),
+        EXTCALL => new("EXTCALL", 4, 1, 0, false),
+        EXTDELEGATECALL => new("EXTDELEGATECALL", 3, 1, 0, false),
+        EXTSTATICCALL => new("EXTSTATICCALL", 3, 1, 0, false),
+        _ => {
+            let opcode = OpCode(opcode);
+            if DEPRECATED.contains(&opcode) {
+                return None;
+            }
+            let info = opcode.info()?;
+            new(
+                info.name(),
+                info.inputs(),
+                info.outputs(),
+                opcode.immediate_size() as u8,
+                info.is_terminating(),
+            )
+        }
+    }
+}
+
+/// An instruction of EOF code.
+struct Instruction<'a> {
+    pc: usize,
+    opcode: u8,
+    immediate: &'a [u8],
+    truncated: bool,
+}
+
+impl Instruction<'_> {
+    /// Returns the offset of the next instruction.
+    #[inline]
+    const fn end(&self) -> usize {
+        self.pc + 1 + self.immediate.len()
+    }
+
+    #[inline]
+    fn u8_immediate(&self) -> u8 {
+        self.immediate[0]
+    }
+
+    #[inline]
+    fn u16_immediate(&self) -> u16 {
+        u16::from_be_bytes([self.immediate[0], self.immediate[1]])
+    }
+
+    /// Returns the targets of a relative jump, which may be out of bounds.
+    fn jump_targets(&self) -> Vec<isize> {
+        let offsets = match self.opcode {
+            RJUMP | RJUMPI => self.immediate,
+            RJUMPV => &self.immediate[1..],
+            _ => return Vec::new(),
+        };
+        offsets
+            .chunks_exact(2)
+            .map(|offset| self.end() as isize + i16::from_be_bytes([offset[0], offset[1]]) as isize)
+            .collect()
+    }
+}
+
+/// Returns an iterator over the instructions of EOF code.
+///
+/// Unknown opcodes have no immediate.
+fn instructions(code: &[u8]) -> impl Iterator<Item = Instruction<'_>> {
+    let mut pc = 0;
+    core::iter::from_fn(move || {
+        let &opcode = code.get(pc)?;
+        let start = pc + 1;
+        let size = match opcode {
+            RJUMPV => code.get(start).map_or(1, |&max_index| 1 + 2 * (max_index as usize + 1)),
+            _ => eof_opcode(opcode).map_or(0, |op| op.immediate as usize),
+        };
+        let end = start + size;
+        let instruction = Instruction {
+            pc,
+            opcode,
+            immediate: &code[start..end.min(code.len())],
+            truncated: end > code.len(),
+        };
+        pc = end;
+        Some(instruction)
+    })
+}
+
+/// Writes a disassembly listing of EOF code, in the same format as
+/// [`Disassembly`](crate::bytecode::Disassembly).
+pub(super) fn disassemble(code: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let width = if code.len() > 0x10000 { 6 } else { 4 };
+    for instruction in instructions(code) {
+        write!(f, "{:0width$x}: ", instruction.pc)?;
+        match eof_opcode(instruction.opcode) {
+            Some(op) => f.write_str(op.name)?,
+            None => write!(f, "UNKNOWN(0x{:02x})", instruction.opcode)?,
+        }
+        if !instruction.immediate.is_empty() {
+            f.write_str(" 0x")?;
+            for byte in instruction.immediate {
+                write!(f, "{byte:02x}")?;
+            }
+        }
+        if instruction.truncated {
+            f.write_str(" (truncated)")?;
+        }
+        f.write_str("\n")?;
+    }
+    Ok(())
+}
+
+/// The reason an EOF code section is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeError {
+    /// The code section is empty.
+    EmptyCode,
+    /// The opcode is undefined or deprecated in EOF code.
+    UnknownOpcode(u8),
+    /// The immediate of the instruction extends past the end of the code.
+    TruncatedImmediate,
+    /// A relative jump targets the immediate of an instruction, or is out of
+    /// bounds.
+    InvalidJumpTarget,
+    /// `CALLF` or `JUMPF` target a code section which does not exist.
+    InvalidSectionIndex(u16),
+    /// `CALLF` targets a non-returning code section.
+    CallNonReturning(u16),
+    /// `RETF`, or `JUMPF` to a returning section, in a non-returning section.
+    ReturnFromNonReturning,
+    /// `DATALOADN` reads past the end of the data section.
+    InvalidDataOffset(u16),
+    /// `EOFCREATE` or `RETURNCONTRACT` reference a container section which
+    /// does not exist.
+    InvalidContainerIndex(u8),
+    /// The instruction is not reachable.
+    UnreachableCode,
+    /// The code ends with an instruction which is not terminating.
+    MissingTerminator,
+    /// The instruction requires more stack items than available.
+    StackUnderflow,
+    /// The stack height exceeds [`MAX_STACK_HEIGHT`].
+    StackOverflow,
+    /// The stack height differs between the paths reaching a backward jump
+    /// target, or does not match the section outputs on return.
+    InvalidStackHeight,
+    /// The maximum stack height of the section differs from its declared
+    /// type.
+    MaxStackHeightMismatch {
+        /// The declared maximum stack height.
+        declared: u16,
+        /// The computed maximum stack height.
+        computed: u16,
+    },
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyCode => f.write_str("empty code section"),
+            Self::UnknownOpcode(opcode) => write!(f, "unknown opcode 0x{opcode:02x}"),
+            Self::TruncatedImmediate => f.write_str("truncated immediate"),
+            Self::InvalidJumpTarget => f.write_str("invalid jump target"),
+            Self::InvalidSectionIndex(index) => write!(f, "invalid code section index {index}"),
+            Self::CallNonReturning(index) => {
+                write!(f, "call to non-returning code section {index}")
+            }
+            Self::ReturnFromNonReturning => f.write_str("return from non-returning code section"),
+            Self::InvalidDataOffset(offset) => write!(f, "data offset {offset} is out of bounds"),
+            Self::InvalidContainerIndex(index) => write!(f, "invalid container index {index}"),
+            Self::UnreachableCode => f.write_str("unreachable code"),
+            Self::MissingTerminator => f.write_str("code does not end with a terminating opcode"),
+            Self::StackUnderflow => f.write_str("stack underflow"),
+            Self::StackOverflow => f.write_str("stack overflow"),
+            Self::InvalidStackHeight => f.write_str("invalid stack height"),
+            Self::MaxStackHeightMismatch { declared, computed } => {
+                write!(f, "declared max stack height {declared} does not match computed {computed}")
+            }
+        }
+    }
+}
+
+impl Eof {
+    /// Validates the container and its subcontainers.
+    ///
+    /// This checks that:
+    /// - the first code section takes no inputs and is non-returning, and all section types are
+    ///   within limits;
+    /// - all instructions are valid in EOF code and have complete immediates ([EIP-3670]);
+    /// - relative jumps target instruction boundaries, and `CALLF`, `JUMPF`, `DATALOADN`,
+    ///   `EOFCREATE` and `RETURNCONTRACT` reference existing sections or data ([EIP-4750]);
+    /// - the stack never underflows or overflows, has a consistent height on backward jumps, and
+    ///   reaches the declared maximum height; every instruction is reachable and the code cannot
+    ///   run past its end ([EIP-5450]);
+    /// - all code sections are reachable from the first one.
+    ///
+    /// Subcontainers are validated recursively, up to a nesting depth of 16.
+    /// It does not check whether the container is used as initcode or
+    /// runtime code.
+    ///
+    /// [EIP-3670]: https://eips.ethereum.org/EIPS/eip-3670
+    /// [EIP-4750]: https://eips.ethereum.org/EIPS/eip-4750
+    /// [EIP-5450]: https://eips.ethereum.org/EIPS/eip-5450
+    pub fn validate(&self) -> Result<(), EofError> {
+        self.validate_at(0)
+    }
+
+    fn validate_at(&self, depth: usize) -> Result<(), EofError> {
+        if self.types.len() != self.code_sections.len() || self.code_sections.is_empty() {
+            return Err(EofError::InvalidHeader("type section size does not match code sections"));
+        }
+        for (section, ty) in self.types.iter().enumerate() {
+            validate_type(section, ty)?;
+        }
+
+        let mut callees = Vec::with_capacity(self.code_sections.len());
+        for section in 0..self.code_sections.len() {
+            let called = validate_code(self, section)
+                .map_err(|(pc, error)| EofError::InvalidCode { section, pc, error })?;
+            callees.push(called);
+        }
+
+        let mut reachable = vec![false; self.code_sections.len()];
+        let mut queue = vec![0];
+        reachable[0] = true;
+        while let Some(section) = queue.pop() {
+            for &callee in &callees[section] {
+                if !reachable[callee] {
+                    reachable[callee] = true;
+                    queue.push(callee);
+                }
+            }
+        }
+        if let Some(section) = reachable.iter().position(|&reachable| !reachable) {
+            return Err(EofError::UnreachableCodeSection(section));
+        }
+
+        if !self.container_sections.is_empty() && depth >= MAX_DEPTH {
+            return Err(EofError::NestingTooDeep);
+        }
+        for (section, container) in self.container_sections.iter().enumerate() {
+            Self::decode(container)
+                .and_then(|eof| eof.validate_at(depth + 1))
+                .map_err(|error| EofError::InvalidContainer { section, error: Box::new(error) })?;
+        }
+        Ok(())
+    }
+}
+
+const fn validate_type(section: usize, ty: &CodeType) -> Result<(), EofError> {
+    let reason = if section == 0 && (ty.inputs != 0 || ty.is_returning()) {
+        "the first code section must take no inputs and be non-returning"
+    } else if ty.inputs > 127 {
+        "too many inputs"
+    } else if ty.outputs > CodeType::NON_RETURNING {
+        "too many outputs"
+    } else if ty.max_stack_height > MAX_STACK_HEIGHT {
+        "max stack height is too large"
+    } else {
+        return Ok(());
+    };
+    Err(EofError::InvalidType { section, reason })
+}
+
+/// Validates a code section, returning the sections it calls or jumps to.
+fn validate_code(eof: &Eof, section: usize) -> Result<Vec<usize>, (usize, CodeError)> {
+    let code = &eof.code_sections[section];
+    let ty = eof.types[section];
+    if code.is_empty() {
+        return Err((0, CodeError::EmptyCode));
+    }
+
+    // instructions and immediates
+    let mut is_start = vec![false; code.len()];
+    let mut instructions_ = Vec::new();
+    for instruction in instructions(code) {
+        let Some(op) = eof_opcode(instruction.opcode) else {
+            return Err((instruction.pc, CodeError::UnknownOpcode(instruction.opcode)));
+        };
+        if instruction.truncated {
+            return Err((instruction.pc, CodeError::TruncatedImmediate));
+        }
+        is_start[instruction.pc] = true;
+        instructions_.push((instruction, op));
+    }
+
+    // references
+    let mut callees = Vec::new();
+    for (instruction, _) in &instructions_ {
+        let error = |error| Err((instruction.pc, error));
+        for target in instruction.jump_targets() {
+            if usize::try_from(target).ok().and_then(|t| is_start.get(t)) != Some(&true) {
+                return error(CodeError::InvalidJumpTarget);
+            }
+        }
+        match instruction.opcode {
+            CALLF | JUMPF => {
+                let index = instruction.u16_immediate();
+                let Some(target) = eof.types.get(index as usize) else {
+                    return error(CodeError::InvalidSectionIndex(index));
+                };
+                if instruction.opcode == CALLF && !target.is_returning() {
+                    return error(CodeError::CallNonReturning(index));
+                }
+                if instruction.opcode == JUMPF && target.is_returning() && !ty.is_returning() {
+                    return error(CodeError::ReturnFromNonReturning);
+                }
+                callees.push(index as usize);
+            }
+            RETF if !ty.is_returning() => return error(CodeError::ReturnFromNonReturning),
+            DATALOADN => {
+                let offset = instruction.u16_immediate();
+                if offset as usize + 32 > eof.header.data_size as usize {
+                    return error(CodeError::InvalidDataOffset(offset));
+                }
+            }
+            EOFCREATE | RETURNCONTRACT => {
+                let index = instruction.u8_immediate();
+                if index as usize >= eof.container_sections.len() {
+                    return error(CodeError::InvalidContainerIndex(index));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // stack heights, as (min, max) ranges at the start of each instruction
+    let mut heights: Vec<Option<(u16, u16)>> = vec![None; code.len()];
+    heights[0] = Some((ty.inputs as u16, ty.inputs as u16));
+    let mut max_height = ty.inputs as u16;
+    for (instruction, op) in &instructions_ {
+        let pc = instruction.pc;
+        let Some((min, max)) = heights[pc] else {
+            return Err((pc, CodeError::UnreachableCode));
+        };
+        let (inputs, outputs) = match instruction.opcode {
+            CALLF | JUMPF => {
+                let target = eof.types[instruction.u16_immediate() as usize];
+                let inputs = target.inputs as u16;
+                if max - inputs.min(max) + target.max_stack_height > MAX_STACK_HEIGHT {
+                    return Err((pc, CodeError::StackOverflow));
+                }
+                if instruction.opcode == CALLF {
+                    (inputs, target.outputs as u16)
+                } else if target.is_returning() {
+                    // the callee returns directly to our caller
+                    let expected = (ty.outputs as u16 + inputs).checked_sub(target.outputs as u16);
+                    if expected.is_none() || min != max || Some(min) != expected {
+                        return Err((pc, CodeError::InvalidStackHeight));
+                    }
+                    (inputs, 0)
+                } else {
+                    (inputs, 0)
+                }
+            }
+            RETF => {
+                if min != max || min != ty.outputs as u16 {
+                    return Err((pc, CodeError::InvalidStackHeight));
+                }
+                (min, 0)
+            }
+            DUPN => {
+                let n = instruction.u8_immediate() as u16 + 1;
+                (n, n + 1)
+            }
+            SWAPN => {
+                let n = instruction.u8_immediate() as u16 + 2;
+                (n, n)
+            }
+            EXCHANGE => {
+                let imm = instruction.u8_immediate() as u16;
+                let n = (imm >> 4) + (imm & 0x0f) + 3;
+                (n, n)
+            }
+            _ => (op.inputs as u16, op.outputs as u16),
+        };
+        if min < inputs {
+            return Err((pc, CodeError::StackUnderflow));
+        }
+        let next = (min - inputs + outputs, max - inputs + outputs);
+        if next.1 > MAX_STACK_HEIGHT {
+            return Err((pc, CodeError::StackOverflow));
+        }
+        max_height = max_height.max(next.1);
+
+        let mut successors = instruction.jump_targets();
+        if !op.terminating {
+            if instruction.end() >= code.len() {
+                return Err((pc, CodeError::MissingTerminator));
+            }
+            successors.push(instruction.end() as isize);
+        }
+        for target in successors {
+            let target = target as usize;
+            if target <= pc {
+                if heights[target] != Some(next) {
+                    return Err((pc, CodeError::InvalidStackHeight));
+                }
+            } else {
+                heights[target] = Some(match heights[target] {
+                    Some((min, max)) => (min.min(next.0), max.max(next.1)),
+                    None => next,
+                });
+            }
+        }
+    }
+
+    if max_height != ty.max_stack_height {
+        let error = CodeError::MaxStackHeightMismatch {
+            declared: ty.max_stack_height,
+            computed: max_height,
+        };
+        return Err((0, error));
+    }
+    Ok(callees)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, Bytes};
+
+    fn container(types: &[(u8, u8, u16)], code: &[&[u8]]) -> Eof {
+        Eof::new(
+            types.iter().map(|&(i, o, h)| CodeType::new(i, o, h)).collect(),
+            code.iter().map(|c| Bytes::copy_from_slice(c)).collect(),
+            Vec::new(),
+            Bytes::new(),
+        )
+    }
+
+    fn code_error(eof: &Eof) -> (usize, usize, CodeError) {
+        match eof.validate() {
+            Err(EofError::InvalidCode { section, pc, error }) => (section, pc, error),
+            r => panic!("unexpected result: {r:?}"),
+        }
+    }
+
+    const NR: u8 = CodeType::NON_RETURNING;
+
+    type Sections<'a> = (&'a [(u8, u8, u16)], &'a [&'a [u8]]);
+
+    #[test]
+    fn valid() {
+        let cases: &[Sections<'_>] = &[
+            // PUSH0 PUSH0 RETURN
+            (&[(0, NR, 2)], &[&hex!("5f5ff3")]),
+            // PUSH0 DUPN 0 STOP
+            (&[(0, NR, 2)], &[&hex!("5fe60000")]),
+            // PUSH0 RJUMPI -4 STOP
+            (&[(0, NR, 1)], &[&hex!("5fe1fffc00")]),
+            // PUSH0 RJUMPV [1, 2] STOP STOP PUSH0 POP STOP
+            (&[(0, NR, 1)], &[&hex!("5fe20100010002" "00" "00" "5f5000")]),
+            // PUSH0 CALLF 1 PUSH0 RETURN; RETF
+            (&[(0, NR, 2), (1, 1, 1)], &[&hex!("5fe300015ff3"), &hex!("e4")]),
+            // JUMPF 1; PUSH0 PUSH0 REVERT
+            (&[(0, NR, 0), (0, NR, 2)], &[&hex!("e50001"), &hex!("5f5ffd")]),
+        ];
+        for (types, code) in cases {
+            let eof = container(types, code);
+            assert_eq!(eof.validate(), Ok(()), "{eof}");
+            assert_eq!(Eof::decode(&eof.encode()).unwrap(), eof);
+        }
+    }
+
+    #[test]
+    fn invalid_code() {
+        let cases: &[(&[u8], u16, usize, CodeError)] = &[
+            (&[], 0, 0, CodeError::EmptyCode),
+            (&hex!("5f56"), 1, 1, CodeError::UnknownOpcode(0x56)),
+            (&hex!("0c"), 0, 0, CodeError::UnknownOpcode(0x0c)),
+            (&hex!("60"), 1, 0, CodeError::TruncatedImmediate),
+            (&hex!("e2"), 0, 0, CodeError::TruncatedImmediate),
+            // RJUMP into the immediate of PUSH1
+            (&hex!("e00001600000"), 1, 0, CodeError::InvalidJumpTarget),
+            (&hex!("e0ff0000"), 0, 0, CodeError::InvalidJumpTarget),
+            (&hex!("e3000100"), 0, 0, CodeError::InvalidSectionIndex(1)),
+            (&hex!("e4"), 0, 0, CodeError::ReturnFromNonReturning),
+            (&hex!("d1000000"), 1, 0, CodeError::InvalidDataOffset(0)),
+            (&hex!("5f5fec0000"), 1, 2, CodeError::InvalidContainerIndex(0)),
+            (&hex!("0000"), 0, 1, CodeError::UnreachableCode),
+            (&hex!("5f"), 1, 0, CodeError::MissingTerminator),
+            (&hex!("0100"), 0, 0, CodeError::StackUnderflow),
+            // PUSH0 RJUMP -4
+            (&hex!("5fe0fffc"), 1, 1, CodeError::InvalidStackHeight),
+            (&hex!("5f5ff3"), 1, 0, CodeError::MaxStackHeightMismatch { declared: 1, computed: 2 }),
+        ];
+        for &(code, max_stack_height, pc, error) in cases {
+            let eof = container(&[(0, NR, max_stack_height)], &[code]);
+            assert_eq!(code_error(&eof), (0, pc, error), "{}", hex::encode(code));
+        }
+
+        // CALLF to a non-returning section
+        let eof = container(&[(0, NR, 0), (0, NR, 0)], &[&hex!("e3000100"), &hex!("00")]);
+        assert_eq!(code_error(&eof), (0, 0, CodeError::CallNonReturning(1)));
+
+        // RETF with the wrong number of outputs
+        let eof = container(&[(0, NR, 1), (0, 1, 0)], &[&hex!("e3000100"), &hex!("e4")]);
+        assert_eq!(code_error(&eof), (1, 0, CodeError::InvalidStackHeight));
+
+        // an empty section after a valid one
+        let eof = container(&[(0, NR, 0), (0, NR, 0)], &[&hex!("e50001"), &[]]);
+        assert_eq!(code_error(&eof), (1, 0, CodeError::EmptyCode));
+    }
+
+    #[test]
+    fn data_offset() {
+        // DATALOADN 0 POP STOP
+        let mut eof = container(&[(0, NR, 1)], &[&hex!("d100005000")]);
+        eof.header.data_size = 32;
+        assert_eq!(eof.validate(), Ok(()));
+        eof.header.data_size = 31;
+        assert_eq!(code_error(&eof), (0, 0, CodeError::InvalidDataOffset(0)));
+    }
+
+    #[test]
+    fn invalid_types() {
+        let eof = container(&[(0, 0, 0)], &[&hex!("00")]);
+        assert!(matches!(eof.validate(), Err(EofError::InvalidType { section: 0, .. })));
+
+        let eof = container(&[(0, NR, 0), (128, 0, 128)], &[&hex!("00"), &hex!("e4")]);
+        assert_eq!(
+            eof.validate(),
+            Err(EofError::InvalidType { section: 1, reason: "too many inputs" })
+        );
+    }
+
+    #[test]
+    fn unreachable_section() {
+        let eof = container(&[(0, NR, 0), (0, NR, 0)], &[&hex!("00"), &hex!("00")]);
+        assert_eq!(eof.validate(), Err(EofError::UnreachableCodeSection(1)));
+    }
+
+    #[test]
+    fn containers() {
+        let inner = container(&[(0, NR, 2)], &[&hex!("5f5ff3")]).encode();
+        // PUSH0 PUSH0 PUSH0 PUSH0 EOFCREATE 0 POP STOP
+        let code = Bytes::from_static(&hex!("5f5f5f5fec005000"));
+        let mut outer =
+            Eof::new(vec![CodeType::new(0, NR, 4)], vec![code], vec![inner], Bytes::new());
+        assert_eq!(outer.validate(), Ok(()));
+
+        outer.container_sections[0] = Bytes::from_static(&hex!("ef00"));
+        assert_eq!(
+            outer.validate(),
+            Err(EofError::InvalidContainer {
+                section: 0,
+                error: Box::new(EofError::UnexpectedEof)
+            })
+        );
+    }
+
+    #[test]
+    fn nested_containers() {
+        let code = Bytes::from_static(&hex!("5f5f5f5fec005000"));
+        let nest = |inner: Eof| {
+            Eof::new(
+                vec![CodeType::new(0, NR, 4)],
+                vec![code.clone()],
+                vec![inner.encode()],
+                Bytes::new(),
+            )
+        };
+        let mut eof = container(&[(0, NR, 0)], &[&hex!("00")]);
+        for _ in 0..MAX_DEPTH {
+            eof = nest(eof);
+        }
+        assert_eq!(eof.validate(), Ok(()));
+
+        let mut error = nest(eof).validate().unwrap_err();
+        for _ in 0..MAX_DEPTH {
+            let EofError::InvalidContainer { section: 0, error: inner } = error else {
+                panic!("unexpected error: {error:?}");
+            };
+            error = *inner;
+        }
+        assert_eq!(error, EofError::NestingTooDeep);
+    }
+}
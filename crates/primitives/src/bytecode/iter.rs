@@ -0,0 +1,110 @@
+use super::OpCode;
+use core::iter::FusedIterator;
+
+/// A single EVM instruction, as yielded by [`Instructions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Instruction<'a> {
+    /// The offset of the opcode in the bytecode.
+    pub pc: usize,
+    /// The opcode.
+    pub opcode: OpCode,
+    /// The immediate bytes following the opcode.
+    ///
+    /// This may be shorter than [`OpCode::immediate_size`] if the bytecode
+    /// ends in the middle of the immediate, see [`is_truncated`].
+    ///
+    /// [`is_truncated`]: Instruction::is_truncated
+    pub immediate: &'a [u8],
+}
+
+impl Instruction<'_> {
+    /// Returns `true` if the bytecode ends before the end of this
+    /// instruction's immediate.
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.immediate.len() < self.opcode.immediate_size()
+    }
+
+    /// Returns the offset of the next instruction.
+    #[inline]
+    pub const fn next_pc(&self) -> usize {
+        self.pc + 1 + self.opcode.immediate_size()
+    }
+}
+
+/// An iterator over the instructions of legacy EVM bytecode.
+///
+/// Created by [`instructions`](super::instructions).
+#[derive(Clone, Debug)]
+pub struct Instructions<'a> {
+    code: &'a [u8],
+    pc: usize,
+}
+
+impl<'a> Instructions<'a> {
+    /// Creates a new iterator over the given bytecode.
+    #[inline]
+    pub const fn new(code: &'a [u8]) -> Self {
+        Self { code, pc: 0 }
+    }
+
+    /// Returns the offset of the next instruction.
+    #[inline]
+    pub const fn pc(&self) -> usize {
+        self.pc
+    }
+}
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = Instruction<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pc = self.pc;
+        let opcode = OpCode(*self.code.get(pc)?);
+        let start = pc + 1;
+        let end = (start + opcode.immediate_size()).min(self.code.len());
+        self.pc = start + opcode.immediate_size();
+        Some(Instruction { pc, opcode, immediate: &self.code[start..end] })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.code.len().saturating_sub(self.pc);
+        ((remaining + 32) / 33, Some(remaining))
+    }
+}
+
+impl FusedIterator for Instructions<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iter() {
+        let code = hex!("6080604052600436106100");
+        let instructions: Vec<_> = Instructions::new(&code).collect();
+        assert_eq!(
+            instructions,
+            [
+                Instruction { pc: 0, opcode: OpCode::PUSH1, immediate: &[0x80] },
+                Instruction { pc: 2, opcode: OpCode::PUSH1, immediate: &[0x40] },
+                Instruction { pc: 4, opcode: OpCode::MSTORE, immediate: &[] },
+                Instruction { pc: 5, opcode: OpCode::PUSH1, immediate: &[0x04] },
+                Instruction { pc: 7, opcode: OpCode::CALLDATASIZE, immediate: &[] },
+                Instruction { pc: 8, opcode: OpCode::LT, immediate: &[] },
+                Instruction { pc: 9, opcode: OpCode::PUSH2, immediate: &[0x00] },
+            ]
+        );
+        assert!(instructions.last().unwrap().is_truncated());
+        assert_eq!(instructions.last().unwrap().next_pc(), 12);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Instructions::new(&[]).next(), None);
+    }
+}
@@ -0,0 +1,298 @@
+use super::cbor::Value;
+use crate::B256;
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+/// A compiler version, as encoded in [`CompilerMetadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// The major version.
+    pub major: u64,
+    /// The minor version.
+    pub minor: u64,
+    /// The patch version.
+    pub patch: u64,
+    /// The pre-release and build suffix, including the leading `-` or `+`,
+    /// such as `-nightly.2023.5.1+commit.7dd6d404`. Empty for releases.
+    pub suffix: String,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}{}", self.major, self.minor, self.patch, self.suffix)
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numbers, suffix) = s.find(['-', '+']).map_or((s, ""), |i| s.split_at(i));
+        let mut numbers = numbers.splitn(3, '.').map(|n| n.parse().map_err(|_| ParseVersionError));
+        let mut next = || numbers.next().unwrap_or(Err(ParseVersionError));
+        Ok(Self { major: next()?, minor: next()?, patch: next()?, suffix: suffix.into() })
+    }
+}
+
+impl Version {
+    /// Creates a new release version.
+    #[inline]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch, suffix: String::new() }
+    }
+}
+
+/// The error type that is returned when parsing a [`Version`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseVersionError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVersionError {}
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid version, expected `major.minor.patch` with an optional suffix")
+    }
+}
+
+/// The compiler metadata which Solidity and Vyper append to the end of the
+/// runtime code of a contract.
+///
+/// The metadata is CBOR-encoded, followed by its length as a big-endian
+/// `u16`. See the [Solidity documentation] for more details.
+///
+/// [Solidity documentation]: https://docs.soliditylang.org/en/latest/metadata.html#encoding-of-the-metadata-hash-in-the-bytecode
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CompilerMetadata {
+    /// The IPFS multihash of the Solidity metadata JSON file.
+    pub ipfs: Option<Vec<u8>>,
+    /// The Swarm hash of the metadata file, for Solidity versions `0.5.9` and
+    /// earlier.
+    pub bzzr0: Option<B256>,
+    /// The Swarm hash of the metadata file, for Solidity versions `0.5.10` to
+    /// `0.6.x`.
+    pub bzzr1: Option<B256>,
+    /// The Solidity compiler version.
+    pub solc: Option<Version>,
+    /// The Vyper compiler version.
+    pub vyper: Option<Version>,
+    /// Whether experimental compiler features were enabled.
+    pub experimental: bool,
+}
+
+impl CompilerMetadata {
+    /// Decodes the CBOR-encoded metadata, without the trailing length.
+    ///
+    /// Returns `None` if the data is not valid metadata, or if it does not
+    /// contain any of the metadata hashes or compiler versions. Unknown keys
+    /// are ignored.
+    pub fn decode(cbor: &[u8]) -> Option<Self> {
+        match Value::decode_exact(cbor)? {
+            Value::Map(entries) => Self::from_entries(entries),
+            // Vyper 0.3.10 and later:
+            // `[runtime size, [data sizes], immutables size, {"vyper": [major, minor, patch]}]`
+            Value::Array(mut items) => match items.pop()? {
+                Value::Map(entries) => Self::from_entries(entries).filter(|m| m.vyper.is_some()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn from_entries(entries: Vec<(Value<'_>, Value<'_>)>) -> Option<Self> {
+        let mut metadata = Self::default();
+        for (key, value) in entries {
+            let Value::Text(key) = key else { return None };
+            match (key, value) {
+                ("ipfs", Value::Bytes(hash)) => metadata.ipfs = Some(hash.to_vec()),
+                ("bzzr0", Value::Bytes(hash)) if hash.len() == 32 => {
+                    metadata.bzzr0 = Some(B256::from_slice(hash))
+                }
+                ("bzzr1", Value::Bytes(hash)) if hash.len() == 32 => {
+                    metadata.bzzr1 = Some(B256::from_slice(hash))
+                }
+                ("solc", Value::Bytes(&[major, minor, patch])) => {
+                    metadata.solc = Some(Version::new(major as u64, minor as u64, patch as u64))
+                }
+                ("solc", Value::Text(version)) => metadata.solc = Some(version.parse().ok()?),
+                ("vyper", Value::Array(version)) => {
+                    let [Value::Uint(major), Value::Uint(minor), Value::Uint(patch)] = version[..]
+                    else {
+                        return None;
+                    };
+                    metadata.vyper = Some(Version::new(major, minor, patch));
+                }
+                ("experimental", Value::Bool(experimental)) => metadata.experimental = experimental,
+                ("ipfs" | "bzzr0" | "bzzr1" | "solc" | "vyper" | "experimental", _) => return None,
+                _ => {}
+            }
+        }
+        // require a known key, so that arbitrary code ending in a small CBOR
+        // map is not mistaken for metadata
+        let known = metadata.ipfs.is_some()
+            || metadata.bzzr0.is_some()
+            || metadata.bzzr1.is_some()
+            || metadata.solc.is_some()
+            || metadata.vyper.is_some();
+        known.then_some(metadata)
+    }
+}
+
+/// Splits bytecode into the code and the CBOR-encoded compiler metadata
+/// appended to it, if any.
+///
+/// The returned metadata excludes the trailing 2-byte length, and the code
+/// excludes both.
+///
+/// Returns `None` if the code does not end in valid [`CompilerMetadata`].
+pub fn split_metadata(code: &[u8]) -> Option<(&[u8], &[u8])> {
+    decode_trailer(code).map(|(code, cbor, _)| (code, cbor))
+}
+
+/// Decodes the compiler metadata appended to the given bytecode, if any.
+pub fn metadata(code: &[u8]) -> Option<CompilerMetadata> {
+    decode_trailer(code).map(|(_, _, metadata)| metadata)
+}
+
+/// Splits bytecode into the code, the CBOR-encoded metadata and the decoded
+/// metadata.
+fn decode_trailer(code: &[u8]) -> Option<(&[u8], &[u8], CompilerMetadata)> {
+    let (rest, len) = code.split_at(code.len().checked_sub(2)?);
+    let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+    let (code, cbor) = rest.split_at(rest.len().checked_sub(len)?);
+    let metadata = CompilerMetadata::decode(cbor)?;
+    Some((code, cbor, metadata))
+}
+
+/// Returns the bytecode without the compiler metadata appended to it, if any.
+#[inline]
+pub fn strip_metadata(code: &[u8]) -> &[u8] {
+    split_metadata(code).map_or(code, |(code, _)| code)
+}
+
+/// Returns `true` if the two bytecodes are equal, ignoring any compiler
+/// metadata appended to them.
+///
+/// This only considers metadata at the very end of the code, so it does not
+/// apply to creation code followed by constructor arguments, or to metadata
+/// of other contracts embedded in the code.
+#[inline]
+pub fn eq_ignoring_metadata(a: &[u8], b: &[u8]) -> bool {
+    strip_metadata(a) == strip_metadata(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    // `solc 0.8.20`, IPFS
+    const SOLC: [u8; 53] = hex!(
+        "a2646970667358221220"
+        "2a4f8d12a9e5a1b3e23ad5cb1fa4f3c9f1c52ecf2f6c7d5e6a1c5d19c6b3f2e1"
+        "64736f6c6343000814"
+        "0033"
+    );
+
+    #[test]
+    fn solc() {
+        let code = [&hex!("6080604052600080fd")[..], &SOLC].concat();
+        let (stripped, cbor) = split_metadata(&code).unwrap();
+        assert_eq!(stripped, hex!("6080604052600080fd"));
+        assert_eq!(cbor, &SOLC[..51]);
+
+        let metadata = metadata(&code).unwrap();
+        assert_eq!(
+            metadata,
+            CompilerMetadata {
+                ipfs: Some(
+                    hex!("12202a4f8d12a9e5a1b3e23ad5cb1fa4f3c9f1c52ecf2f6c7d5e6a1c5d19c6b3f2e1")
+                        .to_vec()
+                ),
+                solc: Some(Version::new(0, 8, 20)),
+                ..Default::default()
+            }
+        );
+        assert_eq!(metadata.solc.unwrap().to_string(), "0.8.20");
+    }
+
+    #[test]
+    fn solc_bzzr1_experimental() {
+        let cbor = hex!(
+            "a365627a7a72315820"
+            "7f0fd2fe0e2b5c2e6b5d7c0c4e6f0f8b9a5f3d7b1c2e5f0a3b6c9d2e5f8a1b4c"
+            "6c6578706572696d656e74616cf5"
+            "64736f6c63781f"
+            "302e362e31322d6e696768746c792e323032302e372e31352b636f6d6d6974"
+        );
+        let metadata = CompilerMetadata::decode(&cbor).unwrap();
+        assert_eq!(
+            metadata.bzzr1,
+            Some(B256::new(hex!(
+                "7f0fd2fe0e2b5c2e6b5d7c0c4e6f0f8b9a5f3d7b1c2e5f0a3b6c9d2e5f8a1b4c"
+            )))
+        );
+        assert!(metadata.experimental);
+        let solc = metadata.solc.unwrap();
+        assert_eq!((solc.major, solc.minor, solc.patch), (0, 6, 12));
+        assert_eq!(solc.to_string(), "0.6.12-nightly.2020.7.15+commit");
+    }
+
+    #[test]
+    fn vyper() {
+        // 0.3.4 to 0.3.9
+        let code = hex!("6003" "a165767970657283000309" "000b");
+        assert_eq!(metadata(&code).unwrap().vyper, Some(Version::new(0, 3, 9)));
+        assert_eq!(strip_metadata(&code), hex!("6003"));
+
+        // 0.3.10 and later
+        let code = hex!("6003" "8419012380" "00a16576797065728300030a" "0011");
+        assert_eq!(metadata(&code).unwrap().vyper, Some(Version::new(0, 3, 10)));
+        assert_eq!(strip_metadata(&code), hex!("6003"));
+    }
+
+    #[test]
+    fn no_metadata() {
+        for code in [&[][..], &[0x00], &hex!("0000"), &hex!("6080604052600080fd"), &hex!("a10033")]
+        {
+            assert_eq!(split_metadata(code), None, "{}", hex::encode(code));
+            assert_eq!(strip_metadata(code), code);
+        }
+
+        // valid CBOR which is not metadata
+        assert_eq!(CompilerMetadata::decode(&hex!("a164736f6c6301")), None);
+        assert_eq!(CompilerMetadata::decode(&hex!("83010203")), None);
+
+        // CBOR maps without any known keys
+        for code in [
+            &hex!("6080" "a0" "0001")[..],
+            &hex!("6080" "a1616101" "0004"),
+            &hex!("6080" "a16c6578706572696d656e74616cf5" "000f"),
+        ] {
+            assert_eq!(split_metadata(code), None, "{}", hex::encode(code));
+            assert_eq!(strip_metadata(code), code);
+        }
+    }
+
+    #[test]
+    fn eq_ignoring() {
+        let a = [&hex!("6080604052600080fd")[..], &SOLC].concat();
+        let mut b = a.clone();
+        b[20] ^= 0xff;
+        assert_ne!(a, b);
+        assert!(eq_ignoring_metadata(&a, &b));
+        assert!(eq_ignoring_metadata(&a, &hex!("6080604052600080fd")));
+        assert!(!eq_ignoring_metadata(&a, &hex!("6080604052600080fe")));
+    }
+
+    #[test]
+    fn version() {
+        assert_eq!("0.8.20".parse(), Ok(Version::new(0, 8, 20)));
+        let v: Version = "0.8.21+commit.d9974bed".parse().unwrap();
+        assert_eq!(v.suffix, "+commit.d9974bed");
+        assert_eq!(v.to_string(), "0.8.21+commit.d9974bed");
+        assert_eq!("0.8".parse::<Version>(), Err(ParseVersionError));
+        assert!("0.8.x".parse::<Version>().is_err());
+        assert!("0.8.1.2".parse::<Version>().is_err());
+    }
+}
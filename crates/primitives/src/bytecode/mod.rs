@@ -0,0 +1,144 @@
+//! EVM bytecode inspection.
+//!
+//! This module operates on raw legacy EVM bytecode, such as the runtime code
+//! of a deployed contract, and provides:
+//! - an [opcode table](OpCode) with the [hardfork](Hardfork) each opcode was introduced in;
+//! - an [iterator](instructions) over the instructions of the bytecode;
+//! - [jump destination analysis](jump_destinations);
+//! - [function selector extraction](dispatch_selectors) from the dispatcher;
+//! - a [disassembly listing](disassemble);
+//! - [compiler metadata](CompilerMetadata) extraction;
+//! - [EVM Object Format](Eof) container parsing and validation.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{bytecode, hex};
+//!
+//! let code = hex!("6080604052348015600f57600080fd5b50");
+//! let listing = bytecode::disassemble(&code).to_string();
+//! assert_eq!(
+//!     listing.lines().take(4).collect::<Vec<_>>(),
+//!     ["0000: PUSH1 0x80", "0002: PUSH1 0x40", "0004: MSTORE", "0005: CALLVALUE"],
+//! );
+//!
+//! let jumpdests = bytecode::jump_destinations(&code);
+//! assert!(jumpdests.contains(0x0f));
+//! ```
+
+use core::fmt;
+
+mod analysis;
+pub use analysis::{dispatch_selectors, JumpDestinations};
+
+mod cbor;
+
+mod eof;
+pub use eof::{
+    is_eof, CodeError, CodeType, Eof, EofError, EofHeader, EOF_MAGIC, EOF_VERSION,
+    MAX_CODE_SECTIONS, MAX_CONTAINER_SECTIONS, MAX_STACK_HEIGHT,
+};
+
+mod iter;
+pub use iter::{Instruction, Instructions};
+
+mod metadata;
+pub use metadata::{
+    eq_ignoring_metadata, metadata, split_metadata, strip_metadata, CompilerMetadata,
+    ParseVersionError, Version,
+};
+
+mod opcode;
+pub use opcode::{Hardfork, OpCode, OpCodeInfo};
+
+/// Returns an iterator over the instructions of legacy EVM bytecode.
+#[inline]
+pub const fn instructions(code: &[u8]) -> Instructions<'_> {
+    Instructions::new(code)
+}
+
+/// Returns the set of valid jump destinations of legacy EVM bytecode.
+#[inline]
+pub fn jump_destinations(code: &[u8]) -> JumpDestinations {
+    JumpDestinations::new(code)
+}
+
+/// Returns a [`Display`](fmt::Display) adapter which formats legacy EVM
+/// bytecode as a disassembly listing, using the [latest hardfork].
+///
+/// [latest hardfork]: Hardfork::LATEST
+#[inline]
+pub const fn disassemble(code: &[u8]) -> Disassembly<'_> {
+    Disassembly::new(code, Hardfork::LATEST)
+}
+
+/// A disassembly listing of legacy EVM bytecode.
+///
+/// Each instruction is formatted on its own line as its hex offset, its
+/// mnemonic and its immediate, if any. Opcodes which are not valid at the
+/// given hardfork are formatted as `UNKNOWN(0x..)`, and a truncated immediate
+/// at the end of the code is marked as such.
+///
+/// Created by [`disassemble`].
+#[derive(Clone, Copy, Debug)]
+pub struct Disassembly<'a> {
+    code: &'a [u8],
+    hardfork: Hardfork,
+}
+
+impl fmt::Display for Disassembly<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = if self.code.len() > 0x10000 { 6 } else { 4 };
+        for instruction in instructions(self.code) {
+            write!(f, "{:0width$x}: ", instruction.pc)?;
+            match instruction.opcode.info_at(self.hardfork) {
+                Some(info) => f.write_str(info.name())?,
+                None => write!(f, "UNKNOWN(0x{:02x})", instruction.opcode.0)?,
+            }
+            if !instruction.immediate.is_empty() {
+                f.write_str(" 0x")?;
+                for byte in instruction.immediate {
+                    write!(f, "{byte:02x}")?;
+                }
+            }
+            if instruction.is_truncated() {
+                f.write_str(" (truncated)")?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Disassembly<'a> {
+    /// Creates a new disassembly listing of the given bytecode at the given
+    /// hardfork.
+    #[inline]
+    pub const fn new(code: &'a [u8], hardfork: Hardfork) -> Self {
+        Self { code, hardfork }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+    use alloc::string::ToString;
+
+    #[test]
+    fn disassembly() {
+        let code = hex!("5f600160020c61ff");
+        assert_eq!(
+            disassemble(&code).to_string(),
+            "0000: PUSH0\n\
+             0001: PUSH1 0x01\n\
+             0003: PUSH1 0x02\n\
+             0005: UNKNOWN(0x0c)\n\
+             0006: PUSH2 0xff (truncated)\n"
+        );
+        assert!(Disassembly::new(&code, Hardfork::London)
+            .to_string()
+            .starts_with("0000: UNKNOWN(0x5f)\n"));
+        assert_eq!(disassemble(&[]).to_string(), "");
+    }
+}
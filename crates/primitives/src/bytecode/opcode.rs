@@ -0,0 +1,439 @@
+use core::fmt;
+
+/// An Ethereum hardfork which changed the set of valid EVM opcodes.
+///
+/// Hardforks which did not introduce new opcodes are omitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Hardfork {
+    /// The initial opcode set.
+    Frontier,
+    /// [EIP-7]: `DELEGATECALL`.
+    ///
+    /// [EIP-7]: https://eips.ethereum.org/EIPS/eip-7
+    Homestead,
+    /// [EIP-140], [EIP-211] and [EIP-214]: `REVERT`, `RETURNDATASIZE`,
+    /// `RETURNDATACOPY` and `STATICCALL`.
+    ///
+    /// [EIP-140]: https://eips.ethereum.org/EIPS/eip-140
+    /// [EIP-211]: https://eips.ethereum.org/EIPS/eip-211
+    /// [EIP-214]: https://eips.ethereum.org/EIPS/eip-214
+    Byzantium,
+    /// [EIP-145], [EIP-1014] and [EIP-1052]: `SHL`, `SHR`, `SAR`, `CREATE2`
+    /// and `EXTCODEHASH`.
+    ///
+    /// [EIP-145]: https://eips.ethereum.org/EIPS/eip-145
+    /// [EIP-1014]: https://eips.ethereum.org/EIPS/eip-1014
+    /// [EIP-1052]: https://eips.ethereum.org/EIPS/eip-1052
+    Constantinople,
+    /// [EIP-1344] and [EIP-1884]: `CHAINID` and `SELFBALANCE`.
+    ///
+    /// [EIP-1344]: https://eips.ethereum.org/EIPS/eip-1344
+    /// [EIP-1884]: https://eips.ethereum.org/EIPS/eip-1884
+    Istanbul,
+    /// [EIP-3198]: `BASEFEE`.
+    ///
+    /// [EIP-3198]: https://eips.ethereum.org/EIPS/eip-3198
+    London,
+    /// [EIP-3855]: `PUSH0`.
+    ///
+    /// [EIP-3855]: https://eips.ethereum.org/EIPS/eip-3855
+    Shanghai,
+    /// [EIP-1153], [EIP-4844], [EIP-5656] and [EIP-7516]: `TLOAD`, `TSTORE`,
+    /// `BLOBHASH`, `MCOPY` and `BLOBBASEFEE`.
+    ///
+    /// [EIP-1153]: https://eips.ethereum.org/EIPS/eip-1153
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    /// [EIP-5656]: https://eips.ethereum.org/EIPS/eip-5656
+    /// [EIP-7516]: https://eips.ethereum.org/EIPS/eip-7516
+    Cancun,
+}
+
+impl Hardfork {
+    /// The latest hardfork.
+    pub const LATEST: Self = Self::Cancun;
+}
+
+/// Static information about an [`OpCode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpCodeInfo {
+    name: &'static str,
+    inputs: u8,
+    outputs: u8,
+    terminating: bool,
+    introduced: Hardfork,
+}
+
+impl OpCodeInfo {
+    /// Returns the mnemonic of the opcode.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the number of stack items the opcode pops.
+    #[inline]
+    pub const fn inputs(&self) -> u8 {
+        self.inputs
+    }
+
+    /// Returns the number of stack items the opcode pushes.
+    #[inline]
+    pub const fn outputs(&self) -> u8 {
+        self.outputs
+    }
+
+    /// Returns `true` if execution never continues to the next instruction
+    /// after this opcode.
+    #[inline]
+    pub const fn is_terminating(&self) -> bool {
+        self.terminating
+    }
+
+    /// Returns the hardfork in which the opcode was introduced.
+    #[inline]
+    pub const fn introduced(&self) -> Hardfork {
+        self.introduced
+    }
+}
+
+/// An EVM opcode.
+///
+/// This is a thin wrapper around the opcode byte, and can hold any value,
+/// including ones which are not assigned to an instruction. Use [`info`] or
+/// [`info_at`] to check validity.
+///
+/// [`info`]: OpCode::info
+/// [`info_at`]: OpCode::info_at
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OpCode(pub u8);
+
+impl fmt::Debug for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.info() {
+            Some(info) => f.write_str(info.name),
+            None => write!(f, "UNKNOWN(0x{:02x})", self.0),
+        }
+    }
+}
+
+impl From<u8> for OpCode {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OpCode> for u8 {
+    #[inline]
+    fn from(value: OpCode) -> Self {
+        value.0
+    }
+}
+
+impl OpCode {
+    /// Returns information about the opcode in the [latest hardfork], or
+    /// `None` if it is not a valid opcode.
+    ///
+    /// [latest hardfork]: Hardfork::LATEST
+    #[inline]
+    pub fn info(self) -> Option<&'static OpCodeInfo> {
+        OPCODE_INFO[self.0 as usize].as_ref()
+    }
+
+    /// Returns information about the opcode at the given hardfork, or `None`
+    /// if it is not a valid opcode at that point.
+    #[inline]
+    pub fn info_at(self, hardfork: Hardfork) -> Option<&'static OpCodeInfo> {
+        self.info().filter(|info| info.introduced <= hardfork)
+    }
+
+    /// Returns `true` if the opcode is valid in the [latest hardfork].
+    ///
+    /// [latest hardfork]: Hardfork::LATEST
+    #[inline]
+    pub fn is_valid(self) -> bool {
+        self.info().is_some()
+    }
+
+    /// Returns the mnemonic of the opcode, or `None` if it is not a valid
+    /// opcode.
+    #[inline]
+    pub fn name(self) -> Option<&'static str> {
+        self.info().map(OpCodeInfo::name)
+    }
+
+    /// Returns `true` if the opcode is one of `PUSH0..=PUSH32`.
+    #[inline]
+    pub const fn is_push(self) -> bool {
+        matches!(self.0, 0x5f..=0x7f)
+    }
+
+    /// Returns the number of immediate bytes which follow the opcode in
+    /// legacy bytecode. This is only non-zero for `PUSH1..=PUSH32`.
+    #[inline]
+    pub const fn immediate_size(self) -> usize {
+        match self.0 {
+            0x60..=0x7f => (self.0 - 0x5f) as usize,
+            _ => 0,
+        }
+    }
+
+    /// Returns the `PUSHn` opcode, or `None` if `n > 32`.
+    #[inline]
+    pub const fn push(n: u8) -> Option<Self> {
+        if n <= 32 {
+            Some(Self(0x5f + n))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the opcode is one of `JUMP` or `JUMPI`.
+    #[inline]
+    pub const fn is_jump(self) -> bool {
+        matches!(self.0, 0x56 | 0x57)
+    }
+}
+
+macro_rules! opcodes {
+    (@term) => { false };
+    (@term terminating) => { true };
+    ($($val:literal => $name:ident($inputs:literal, $outputs:literal) $fork:ident $($term:ident)?;)*) => {
+        #[allow(missing_docs)]
+        impl OpCode {
+            $(
+                pub const $name: Self = Self($val);
+            )*
+        }
+
+        static OPCODE_INFO: [Option<OpCodeInfo>; 256] = {
+            let mut table = [None; 256];
+            $(
+                table[$val] = Some(OpCodeInfo {
+                    name: stringify!($name),
+                    inputs: $inputs,
+                    outputs: $outputs,
+                    terminating: opcodes!(@term $($term)?),
+                    introduced: Hardfork::$fork,
+                });
+            )*
+            table
+        };
+    };
+}
+
+opcodes! {
+    0x00 => STOP(0, 0) Frontier terminating;
+    0x01 => ADD(2, 1) Frontier;
+    0x02 => MUL(2, 1) Frontier;
+    0x03 => SUB(2, 1) Frontier;
+    0x04 => DIV(2, 1) Frontier;
+    0x05 => SDIV(2, 1) Frontier;
+    0x06 => MOD(2, 1) Frontier;
+    0x07 => SMOD(2, 1) Frontier;
+    0x08 => ADDMOD(3, 1) Frontier;
+    0x09 => MULMOD(3, 1) Frontier;
+    0x0a => EXP(2, 1) Frontier;
+    0x0b => SIGNEXTEND(2, 1) Frontier;
+
+    0x10 => LT(2, 1) Frontier;
+    0x11 => GT(2, 1) Frontier;
+    0x12 => SLT(2, 1) Frontier;
+    0x13 => SGT(2, 1) Frontier;
+    0x14 => EQ(2, 1) Frontier;
+    0x15 => ISZERO(1, 1) Frontier;
+    0x16 => AND(2, 1) Frontier;
+    0x17 => OR(2, 1) Frontier;
+    0x18 => XOR(2, 1) Frontier;
+    0x19 => NOT(1, 1) Frontier;
+    0x1a => BYTE(2, 1) Frontier;
+    0x1b => SHL(2, 1) Constantinople;
+    0x1c => SHR(2, 1) Constantinople;
+    0x1d => SAR(2, 1) Constantinople;
+
+    0x20 => KECCAK256(2, 1) Frontier;
+
+    0x30 => ADDRESS(0, 1) Frontier;
+    0x31 => BALANCE(1, 1) Frontier;
+    0x32 => ORIGIN(0, 1) Frontier;
+    0x33 => CALLER(0, 1) Frontier;
+    0x34 => CALLVALUE(0, 1) Frontier;
+    0x35 => CALLDATALOAD(1, 1) Frontier;
+    0x36 => CALLDATASIZE(0, 1) Frontier;
+    0x37 => CALLDATACOPY(3, 0) Frontier;
+    0x38 => CODESIZE(0, 1) Frontier;
+    0x39 => CODECOPY(3, 0) Frontier;
+    0x3a => GASPRICE(0, 1) Frontier;
+    0x3b => EXTCODESIZE(1, 1) Frontier;
+    0x3c => EXTCODECOPY(4, 0) Frontier;
+    0x3d => RETURNDATASIZE(0, 1) Byzantium;
+    0x3e => RETURNDATACOPY(3, 0) Byzantium;
+    0x3f => EXTCODEHASH(1, 1) Constantinople;
+
+    0x40 => BLOCKHASH(1, 1) Frontier;
+    0x41 => COINBASE(0, 1) Frontier;
+    0x42 => TIMESTAMP(0, 1) Frontier;
+    0x43 => NUMBER(0, 1) Frontier;
+    0x44 => PREVRANDAO(0, 1) Frontier;
+    0x45 => GASLIMIT(0, 1) Frontier;
+    0x46 => CHAINID(0, 1) Istanbul;
+    0x47 => SELFBALANCE(0, 1) Istanbul;
+    0x48 => BASEFEE(0, 1) London;
+    0x49 => BLOBHASH(1, 1) Cancun;
+    0x4a => BLOBBASEFEE(0, 1) Cancun;
+
+    0x50 => POP(1, 0) Frontier;
+    0x51 => MLOAD(1, 1) Frontier;
+    0x52 => MSTORE(2, 0) Frontier;
+    0x53 => MSTORE8(2, 0) Frontier;
+    0x54 => SLOAD(1, 1) Frontier;
+    0x55 => SSTORE(2, 0) Frontier;
+    0x56 => JUMP(1, 0) Frontier;
+    0x57 => JUMPI(2, 0) Frontier;
+    0x58 => PC(0, 1) Frontier;
+    0x59 => MSIZE(0, 1) Frontier;
+    0x5a => GAS(0, 1) Frontier;
+    0x5b => JUMPDEST(0, 0) Frontier;
+    0x5c => TLOAD(1, 1) Cancun;
+    0x5d => TSTORE(2, 0) Cancun;
+    0x5e => MCOPY(3, 0) Cancun;
+    0x5f => PUSH0(0, 1) Shanghai;
+
+    0x60 => PUSH1(0, 1) Frontier;
+    0x61 => PUSH2(0, 1) Frontier;
+    0x62 => PUSH3(0, 1) Frontier;
+    0x63 => PUSH4(0, 1) Frontier;
+    0x64 => PUSH5(0, 1) Frontier;
+    0x65 => PUSH6(0, 1) Frontier;
+    0x66 => PUSH7(0, 1) Frontier;
+    0x67 => PUSH8(0, 1) Frontier;
+    0x68 => PUSH9(0, 1) Frontier;
+    0x69 => PUSH10(0, 1) Frontier;
+    0x6a => PUSH11(0, 1) Frontier;
+    0x6b => PUSH12(0, 1) Frontier;
+    0x6c => PUSH13(0, 1) Frontier;
+    0x6d => PUSH14(0, 1) Frontier;
+    0x6e => PUSH15(0, 1) Frontier;
+    0x6f => PUSH16(0, 1) Frontier;
+    0x70 => PUSH17(0, 1) Frontier;
+    0x71 => PUSH18(0, 1) Frontier;
+    0x72 => PUSH19(0, 1) Frontier;
+    0x73 => PUSH20(0, 1) Frontier;
+    0x74 => PUSH21(0, 1) Frontier;
+    0x75 => PUSH22(0, 1) Frontier;
+    0x76 => PUSH23(0, 1) Frontier;
+    0x77 => PUSH24(0, 1) Frontier;
+    0x78 => PUSH25(0, 1) Frontier;
+    0x79 => PUSH26(0, 1) Frontier;
+    0x7a => PUSH27(0, 1) Frontier;
+    0x7b => PUSH28(0, 1) Frontier;
+    0x7c => PUSH29(0, 1) Frontier;
+    0x7d => PUSH30(0, 1) Frontier;
+    0x7e => PUSH31(0, 1) Frontier;
+    0x7f => PUSH32(0, 1) Frontier;
+
+    0x80 => DUP1(1, 2) Frontier;
+    0x81 => DUP2(2, 3) Frontier;
+    0x82 => DUP3(3, 4) Frontier;
+    0x83 => DUP4(4, 5) Frontier;
+    0x84 => DUP5(5, 6) Frontier;
+    0x85 => DUP6(6, 7) Frontier;
+    0x86 => DUP7(7, 8) Frontier;
+    0x87 => DUP8(8, 9) Frontier;
+    0x88 => DUP9(9, 10) Frontier;
+    0x89 => DUP10(10, 11) Frontier;
+    0x8a => DUP11(11, 12) Frontier;
+    0x8b => DUP12(12, 13) Frontier;
+    0x8c => DUP13(13, 14) Frontier;
+    0x8d => DUP14(14, 15) Frontier;
+    0x8e => DUP15(15, 16) Frontier;
+    0x8f => DUP16(16, 17) Frontier;
+
+    0x90 => SWAP1(2, 2) Frontier;
+    0x91 => SWAP2(3, 3) Frontier;
+    0x92 => SWAP3(4, 4) Frontier;
+    0x93 => SWAP4(5, 5) Frontier;
+    0x94 => SWAP5(6, 6) Frontier;
+    0x95 => SWAP6(7, 7) Frontier;
+    0x96 => SWAP7(8, 8) Frontier;
+    0x97 => SWAP8(9, 9) Frontier;
+    0x98 => SWAP9(10, 10) Frontier;
+    0x99 => SWAP10(11, 11) Frontier;
+    0x9a => SWAP11(12, 12) Frontier;
+    0x9b => SWAP12(13, 13) Frontier;
+    0x9c => SWAP13(14, 14) Frontier;
+    0x9d => SWAP14(15, 15) Frontier;
+    0x9e => SWAP15(16, 16) Frontier;
+    0x9f => SWAP16(17, 17) Frontier;
+
+    0xa0 => LOG0(2, 0) Frontier;
+    0xa1 => LOG1(3, 0) Frontier;
+    0xa2 => LOG2(4, 0) Frontier;
+    0xa3 => LOG3(5, 0) Frontier;
+    0xa4 => LOG4(6, 0) Frontier;
+
+    0xf0 => CREATE(3, 1) Frontier;
+    0xf1 => CALL(7, 1) Frontier;
+    0xf2 => CALLCODE(7, 1) Frontier;
+    0xf3 => RETURN(2, 0) Frontier terminating;
+    0xf4 => DELEGATECALL(6, 1) Homestead;
+    0xf5 => CREATE2(4, 1) Constantinople;
+    0xfa => STATICCALL(6, 1) Byzantium;
+    0xfd => REVERT(2, 0) Byzantium terminating;
+    0xfe => INVALID(0, 0) Frontier terminating;
+    0xff => SELFDESTRUCT(1, 0) Frontier terminating;
+}
+
+impl OpCode {
+    /// The pre-merge name of [`PREVRANDAO`](Self::PREVRANDAO).
+    pub const DIFFICULTY: Self = Self::PREVRANDAO;
+    /// The pre-[EIP-6780](https://eips.ethereum.org/EIPS/eip-6780) name of
+    /// [`SELFDESTRUCT`](Self::SELFDESTRUCT).
+    pub const SUICIDE: Self = Self::SELFDESTRUCT;
+    /// An alias for [`KECCAK256`](Self::KECCAK256).
+    pub const SHA3: Self = Self::KECCAK256;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        let valid = (0..=255u8).filter(|&b| OpCode(b).is_valid()).count();
+        assert_eq!(valid, 149);
+
+        for b in 0..=255u8 {
+            let op = OpCode(b);
+            assert_eq!(op.is_push(), op.name().map_or(false, |n| n.starts_with("PUSH")), "{op}");
+        }
+
+        assert_eq!(OpCode::PUSH0.immediate_size(), 0);
+        assert_eq!(OpCode::PUSH1.immediate_size(), 1);
+        assert_eq!(OpCode::PUSH32.immediate_size(), 32);
+        assert_eq!(OpCode::push(4), Some(OpCode::PUSH4));
+        assert_eq!(OpCode::push(33), None);
+    }
+
+    #[test]
+    fn hardforks() {
+        assert!(OpCode::PUSH0.info_at(Hardfork::London).is_none());
+        assert!(OpCode::PUSH0.info_at(Hardfork::Shanghai).is_some());
+        assert!(OpCode::DELEGATECALL.info_at(Hardfork::Frontier).is_none());
+        assert!(OpCode::TSTORE.info_at(Hardfork::Cancun).is_some());
+        assert!(OpCode(0x0c).info_at(Hardfork::LATEST).is_none());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(OpCode::PUSH1.to_string(), "PUSH1");
+        assert_eq!(OpCode::DIFFICULTY.to_string(), "PREVRANDAO");
+        assert_eq!(OpCode(0x0c).to_string(), "UNKNOWN(0x0c)");
+    }
+}
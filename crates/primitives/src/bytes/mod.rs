@@ -319,6 +319,37 @@ impl Bytes {
     pub fn split_to(&mut self, at: usize) -> Self {
         Self(self.0.split_to(at))
     }
+
+    /// Decodes the [compiler metadata] appended to this bytecode, if any.
+    ///
+    /// [compiler metadata]: crate::bytecode::CompilerMetadata
+    #[inline]
+    pub fn metadata(&self) -> Option<crate::bytecode::CompilerMetadata> {
+        crate::bytecode::metadata(self)
+    }
+
+    /// Returns this bytecode without the [compiler metadata] appended to it,
+    /// if any.
+    ///
+    /// This does not allocate or copy.
+    ///
+    /// [compiler metadata]: crate::bytecode::CompilerMetadata
+    #[inline]
+    pub fn strip_metadata(&self) -> Self {
+        self.slice(..crate::bytecode::strip_metadata(self).len())
+    }
+
+    /// Returns `true` if the two bytecodes are equal, ignoring any
+    /// [compiler metadata] appended to them.
+    ///
+    /// See [`eq_ignoring_metadata`](crate::bytecode::eq_ignoring_metadata) for
+    /// more details.
+    ///
+    /// [compiler metadata]: crate::bytecode::CompilerMetadata
+    #[inline]
+    pub fn eq_ignoring_metadata(&self, other: &[u8]) -> bool {
+        crate::bytecode::eq_ignoring_metadata(self, other)
+    }
 }
 
 #[cfg(feature = "arbitrary")]
@@ -381,4 +412,21 @@ mod tests {
         assert_eq!(format!("{b:X}"), "0x0123456789ABCDEF");
         assert_eq!(format!("{b:#X}"), "0x0123456789ABCDEF");
     }
+
+    #[test]
+    fn metadata() {
+        let code = Bytes::from_static(&crate::hex!(
+            "6080604052600080fd"
+            "a2646970667358221220"
+            "2a4f8d12a9e5a1b3e23ad5cb1fa4f3c9f1c52ecf2f6c7d5e6a1c5d19c6b3f2e1"
+            "64736f6c6343000814"
+            "0033"
+        ));
+        let stripped = code.strip_metadata();
+        assert_eq!(stripped[..], crate::hex!("6080604052600080fd"));
+        assert_eq!(stripped.strip_metadata(), stripped);
+        assert_eq!(stripped.metadata(), None);
+        assert_eq!(code.metadata().unwrap().solc.unwrap().to_string(), "0.8.20");
+        assert!(code.eq_ignoring_metadata(&stripped));
+    }
 }
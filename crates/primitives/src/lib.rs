@@ -39,6 +39,8 @@ pub use bits::{
     BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
 
+pub mod bytecode;
+
 #[path = "bytes/mod.rs"]
 mod bytes_;
 pub use self::bytes_::Bytes;
@@ -83,6 +85,9 @@ pub use ruint::uint;
 #[doc(no_inline)]
 pub use ::hex::serde as serde_hex;
 
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+
 /// 20-byte [fixed byte-array][FixedBytes] type.
 ///
 /// You'll likely want to use [`Address`] instead, as it is a different type
@@ -1,9 +1,15 @@
 use crate::{Address, Bytes, B256};
 use alloc::vec::Vec;
 
+#[cfg(feature = "serde")]
+mod serde;
+
 /// An Ethereum event log object.
+///
+/// When serialized with a human-readable format this is an object with
+/// `topics` and `data` keys. Otherwise it is a tuple of the concatenated
+/// topics and the data, both as raw bytes.
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
 pub struct LogData {
     /// The indexed topic list.
@@ -76,14 +82,16 @@ impl LogData {
 }
 
 /// A log consists of an address, and some log data.
+///
+/// When serialized with a human-readable format the log data is flattened into
+/// the same object as the address. Otherwise it is an `(address, data)` tuple,
+/// which is supported by non-self-describing formats such as `bincode`.
 #[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
 pub struct Log<T = LogData> {
     /// The address which emitted this log.
     pub address: Address,
     /// The log data.
-    #[cfg_attr(feature = "serde", serde(flatten))]
     pub data: T,
 }
 
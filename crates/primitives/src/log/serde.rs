@@ -0,0 +1,192 @@
+use super::{Log, LogData};
+use crate::{Address, Bytes, B256};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{
+    de::{self, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Human-readable representation of [`LogData`].
+#[derive(Serialize)]
+struct LogDataRef<'a> {
+    topics: &'a [B256],
+    data: &'a Bytes,
+}
+
+/// Human-readable representation of [`LogData`].
+#[derive(Deserialize)]
+struct LogDataOwned {
+    topics: Vec<B256>,
+    data: Bytes,
+}
+
+/// Human-readable representation of [`Log`].
+#[derive(Serialize)]
+struct LogRef<'a, T> {
+    address: &'a Address,
+    #[serde(flatten)]
+    data: &'a T,
+}
+
+/// Human-readable representation of [`Log`].
+#[derive(Deserialize)]
+struct LogOwned<T> {
+    address: Address,
+    #[serde(flatten)]
+    data: T,
+}
+
+/// The concatenated topics of a [`LogData`], serialized as a single byte
+/// string in binary formats.
+struct PackedTopics<'a>(&'a [B256]);
+
+impl Serialize for PackedTopics<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::with_capacity(self.0.len() * 32);
+        for topic in self.0 {
+            bytes.extend_from_slice(topic.as_slice());
+        }
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+impl Serialize for LogData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            LogDataRef { topics: &self.topics, data: &self.data }.serialize(serializer)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&PackedTopics(&self.topics))?;
+            tuple.serialize_element(&self.data)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LogData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LogDataVisitor;
+
+        impl<'de> Visitor<'de> for LogDataVisitor {
+            type Value = LogData;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a tuple of packed topics and log data")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let topics: Bytes =
+                    seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let data =
+                    seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                if topics.len() % 32 != 0 {
+                    return Err(de::Error::invalid_length(
+                        topics.len(),
+                        &"a multiple of 32 bytes of topics",
+                    ));
+                }
+                let topics = topics.chunks_exact(32).map(B256::from_slice).collect();
+                Ok(LogData { topics, data })
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            LogDataOwned::deserialize(deserializer)
+                .map(|LogDataOwned { topics, data }| LogData { topics, data })
+        } else {
+            deserializer.deserialize_tuple(2, LogDataVisitor)
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Log<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            LogRef { address: &self.address, data: &self.data }.serialize(serializer)
+        } else {
+            (&self.address, &self.data).serialize(serializer)
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Log<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            LogOwned::deserialize(deserializer)
+                .map(|LogOwned { address, data }| Log { address, data })
+        } else {
+            <(Address, T)>::deserialize(deserializer).map(|(address, data)| Log { address, data })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log() -> Log {
+        Log::new(
+            address!("dAC17F958D2ee523a2206206994597C13D831ec7"),
+            vec![
+                b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"),
+                b256!("000000000000000000000000a9d1e08c7793af67e9d92fe308d5697fb81d3e43"),
+            ],
+            bytes!("0000000000000000000000000000000000000000000000000000000005f5e100"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn serde_json() {
+        let log = log();
+        let json = serde_json::to_value(&log).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "address": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+                "topics": [
+                    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                    "0x000000000000000000000000a9d1e08c7793af67e9d92fe308d5697fb81d3e43"
+                ],
+                "data": "0x0000000000000000000000000000000000000000000000000000000005f5e100"
+            })
+        );
+        assert_eq!(serde_json::from_value::<Log>(json).unwrap(), log);
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        let log = log();
+        let bin = bincode::serialize(&log).unwrap();
+        // address + topics + data, each with a length prefix
+        assert_eq!(bin.len(), 8 + 20 + 8 + 64 + 8 + 32);
+        assert_eq!(bincode::deserialize::<Log>(&bin).unwrap(), log);
+
+        let bin = bincode::serialize(&LogData::empty()).unwrap();
+        assert_eq!(bincode::deserialize::<LogData>(&bin).unwrap(), LogData::empty());
+    }
+
+    #[test]
+    fn postcard_roundtrip() {
+        let log = log();
+        let bin = postcard::to_allocvec(&log).unwrap();
+        assert_eq!(postcard::from_bytes::<Log>(&bin).unwrap(), log);
+    }
+
+    #[test]
+    fn cbor_roundtrip() {
+        let log = log();
+        let mut bin = Vec::new();
+        ciborium::into_writer(&log, &mut bin).unwrap();
+        assert_eq!(ciborium::from_reader::<Log, _>(bin.as_slice()).unwrap(), log);
+    }
+
+    #[test]
+    fn bincode_invalid_topics() {
+        let bin = bincode::serialize(&(bytes!("00"), Bytes::new())).unwrap();
+        bincode::deserialize::<LogData>(&bin).unwrap_err();
+    }
+}
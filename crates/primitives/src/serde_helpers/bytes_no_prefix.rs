@@ -0,0 +1,58 @@
+//! Serde functions for encoding bytes as a hex string without the `0x` prefix,
+//! such as `"deadbeef"`.
+//!
+//! Deserialization accepts hex strings with or without the prefix.
+
+use core::fmt;
+use serde::{Deserializer, Serializer};
+
+/// Serializes bytes as a hex string without the `0x` prefix.
+pub fn serialize<S: Serializer, T: AsRef<[u8]>>(
+    value: T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode(value.as_ref()))
+}
+
+/// Deserializes bytes from a hex string, with or without the `0x` prefix.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: hex::FromHex,
+    T::Error: fmt::Display,
+{
+    hex::serde::deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bytes, B256};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        bytes: Bytes,
+        #[serde(with = "super")]
+        word: B256,
+        #[serde(with = "crate::serde_helpers::opt_bytes_no_prefix")]
+        opt: Option<Bytes>,
+    }
+
+    #[test]
+    fn roundtrip() {
+        let value = Value {
+            bytes: Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]),
+            word: B256::with_last_byte(1),
+            opt: Some(Bytes::new()),
+        };
+        let json = r#"{"bytes":"deadbeef","word":"0000000000000000000000000000000000000000000000000000000000000001","opt":""}"#;
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Value>(json).unwrap(), value);
+
+        let json = r#"{"bytes":"0xDEADBEEF","word":"0x0000000000000000000000000000000000000000000000000000000000000001","opt":null}"#;
+        let value = serde_json::from_str::<Value>(json).unwrap();
+        assert_eq!(value.bytes, Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(value.opt, None);
+    }
+}
@@ -0,0 +1,62 @@
+//! Serde functions for encoding an [`Address`] as an [EIP-55] checksummed hex
+//! string.
+//!
+//! Deserialization requires a valid checksum.
+//!
+//! [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+
+use crate::Address;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes an [`Address`] as an EIP-55 checksummed hex string.
+pub fn serialize<S: Serializer>(value: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(value.to_checksum_buffer(None).as_str())
+}
+
+/// Deserializes an [`Address`] from an EIP-55 checksummed hex string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+    struct ChecksumVisitor;
+
+    impl de::Visitor<'_> for ChecksumVisitor {
+        type Value = Address;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an EIP-55 checksummed address")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Address::parse_checksummed(v, None).map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_str(ChecksumVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Address;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        inner: Address,
+    }
+
+    #[test]
+    fn roundtrip() {
+        let value = Value { inner: address!("d8da6bf26964af9d7eed9e03e53415d37aa96045") };
+        let json = r#"{"inner":"0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"}"#;
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Value>(json).unwrap(), value);
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        let json = r#"{"inner":"0xd8da6bf26964af9d7eed9e03e53415d37aa96045"}"#;
+        serde_json::from_str::<Value>(json).unwrap_err();
+        let json = r#"{"inner":"0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045"}"#;
+        serde_json::from_str::<Value>(json).unwrap_err();
+    }
+}
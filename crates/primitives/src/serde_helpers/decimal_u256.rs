@@ -0,0 +1,89 @@
+//! Serde functions for encoding a [`U256`] as a decimal string, such as
+//! `"1000"`.
+//!
+//! Deserialization also accepts JSON numbers.
+
+use crate::U256;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes a [`U256`] as a decimal string.
+pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Deserializes a [`U256`] from a decimal string or a number.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+    struct DecimalVisitor;
+
+    impl de::Visitor<'_> for DecimalVisitor {
+        type Value = U256;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal string or a non-negative integer")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(U256::from(v))
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            Ok(U256::from(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            U256::try_from(v)
+                .map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            // `from_str_radix` accepts `_` separators, which are not valid here
+            if v.is_empty() || !v.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            U256::from_str_radix(v, 10).map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(DecimalVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::U256;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        inner: U256,
+    }
+
+    #[test]
+    fn roundtrip() {
+        for s in ["0", "1000", "58750003716598352816469", &U256::MAX.to_string()] {
+            let value = Value { inner: s.parse().unwrap() };
+            let json = format!(r#"{{"inner":"{s}"}}"#);
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            serde_json::from_str::<Value>(r#"{"inner":1000}"#).unwrap().inner,
+            U256::from(1000)
+        );
+        serde_json::from_str::<Value>(r#"{"inner":-1}"#).unwrap_err();
+        serde_json::from_str::<Value>(r#"{"inner":1.5}"#).unwrap_err();
+    }
+
+    #[test]
+    fn invalid() {
+        for s in ["", "0x10", "1_000", "-1", " 1"] {
+            let json = format!(r#"{{"inner":"{s}"}}"#);
+            serde_json::from_str::<Value>(&json).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Serde functions for leniently deserializing a [`U256`] from a JSON number,
+//! a decimal string, or a `0x`-prefixed hex string.
+//!
+//! Serialization uses the default hex string format.
+
+use crate::U256;
+use core::fmt;
+use serde::{de, Deserializer, Serialize, Serializer};
+
+/// Serializes a [`U256`] as a minimal `0x`-prefixed hex string.
+pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
+
+/// Deserializes a [`U256`] from a number, a decimal string, or a `0x`-prefixed
+/// hex string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+    struct LenientVisitor;
+
+    impl de::Visitor<'_> for LenientVisitor {
+        type Value = U256;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a non-negative integer, a decimal string, or a 0x-prefixed hex string")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(U256::from(v))
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            Ok(U256::from(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            U256::try_from(v)
+                .map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let (digits, radix) = match v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")) {
+                Some(hex) => (hex, 16),
+                None => (v, 10),
+            };
+            // `from_str_radix` accepts `_` separators, which are not valid here
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            U256::from_str_radix(digits, radix).map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(LenientVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::U256;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        inner: U256,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Opt {
+        #[serde(with = "crate::serde_helpers::opt_lenient_u256")]
+        inner: Option<U256>,
+    }
+
+    #[test]
+    fn lenient() {
+        for s in ["26", r#""26""#, r#""0x1a""#, r#""0x001A""#] {
+            let json = format!(r#"{{"inner":{s}}}"#);
+            assert_eq!(serde_json::from_str::<Value>(&json).unwrap().inner, U256::from(26), "{s}");
+        }
+        assert_eq!(
+            serde_json::to_string(&Value { inner: U256::from(26) }).unwrap(),
+            r#"{"inner":"0x1a"}"#
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [r#""""#, r#""0x""#, r#""1a""#, r#""1_000""#, "-1", "1.5"] {
+            let json = format!(r#"{{"inner":{s}}}"#);
+            serde_json::from_str::<Value>(&json).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn opt() {
+        assert_eq!(
+            serde_json::from_str::<Opt>(r#"{"inner":26}"#).unwrap().inner,
+            Some(U256::from(26))
+        );
+        assert_eq!(serde_json::from_str::<Opt>(r#"{"inner":null}"#).unwrap().inner, None);
+    }
+}
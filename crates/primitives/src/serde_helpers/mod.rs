@@ -0,0 +1,161 @@
+//! Modules for use with serde's [`with`] field attribute, for encodings that
+//! differ from the default ones of the primitive types.
+//!
+//! Each module exports a `serialize` and a `deserialize` function, and has an
+//! `opt_*` counterpart for `Option`al fields, which serializes `None` as
+//! `null`.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_helpers, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "serde_helpers::u64_hex")]
+//!     number: u64,
+//!     #[serde(with = "serde_helpers::lenient_u256")]
+//!     difficulty: U256,
+//!     #[serde(with = "serde_helpers::opt_decimal_u256")]
+//!     total_difficulty: Option<U256>,
+//! }
+//!
+//! let block: Block = serde_json::from_str(
+//!     r#"{"number":"0x1a","difficulty":1000,"total_difficulty":"58750003716598352816469"}"#,
+//! )?;
+//! assert_eq!(block.number, 26);
+//! assert_eq!(block.difficulty, U256::from(1000));
+//! assert_eq!(
+//!     serde_json::to_string(&block)?,
+//!     r#"{"number":"0x1a","difficulty":"0x3e8","total_difficulty":"58750003716598352816469"}"#,
+//! );
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+//!
+//! [`with`]: https://serde.rs/field-attrs.html#with
+
+use serde::de;
+
+pub mod bytes_no_prefix;
+pub mod checksummed_address;
+pub mod decimal_u256;
+pub mod lenient_u256;
+pub mod quantity;
+pub mod u64_hex;
+
+/// Generates an `opt_*` module from a module with `serialize` and
+/// `deserialize` functions for a concrete type.
+macro_rules! opt_module {
+    ($(#[$attr:meta])* mod $name:ident = $inner:ident($ty:ty);) => {
+        $(#[$attr])*
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            struct Wrapper<T>(T);
+
+            impl Serialize for Wrapper<&$ty> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::$inner::serialize(self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for Wrapper<$ty> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::$inner::deserialize(deserializer).map(Wrapper)
+                }
+            }
+
+            #[doc = concat!("Serializes an optional value like [`", stringify!($inner), "`](super::", stringify!($inner), "), or `None` as `null`.")]
+            pub fn serialize<S: Serializer>(
+                value: &Option<$ty>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(value) => serializer.serialize_some(&Wrapper(value)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            #[doc = concat!("Deserializes an optional value like [`", stringify!($inner), "`](super::", stringify!($inner), "), or `null` as `None`.")]
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<$ty>, D::Error> {
+                Option::<Wrapper<$ty>>::deserialize(deserializer).map(|opt| opt.map(|w| w.0))
+            }
+        }
+    };
+}
+
+/// Like [`quantity`], for `Option<Uint>`.
+pub mod opt_quantity {
+    use crate::Uint;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    struct Wrapper<T>(T);
+
+    impl<const BITS: usize, const LIMBS: usize> Serialize for Wrapper<&Uint<BITS, LIMBS>> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::quantity::serialize(self.0, serializer)
+        }
+    }
+
+    impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Wrapper<Uint<BITS, LIMBS>> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::quantity::deserialize(deserializer).map(Wrapper)
+        }
+    }
+
+    /// Serializes an optional value like [`quantity`](super::quantity), or
+    /// `None` as `null`.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Option<Uint<BITS, LIMBS>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&Wrapper(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional value like [`quantity`](super::quantity), or
+    /// `null` as `None`.
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Option<Uint<BITS, LIMBS>>, D::Error> {
+        Option::<Wrapper<_>>::deserialize(deserializer).map(|opt| opt.map(|w| w.0))
+    }
+}
+
+opt_module! {
+    /// Like [`decimal_u256`], for `Option<U256>`.
+    mod opt_decimal_u256 = decimal_u256(crate::U256);
+}
+
+opt_module! {
+    /// Like [`lenient_u256`], for `Option<U256>`.
+    mod opt_lenient_u256 = lenient_u256(crate::U256);
+}
+
+opt_module! {
+    /// Like [`u64_hex`], for `Option<u64>`.
+    mod opt_u64_hex = u64_hex(u64);
+}
+
+opt_module! {
+    /// Like [`bytes_no_prefix`], for `Option<Bytes>`.
+    mod opt_bytes_no_prefix = bytes_no_prefix(crate::Bytes);
+}
+
+opt_module! {
+    /// Like [`checksummed_address`], for `Option<Address>`.
+    mod opt_checksummed_address = checksummed_address(crate::Address);
+}
+
+/// Strips the mandatory `0x` prefix of a hex quantity.
+fn strip_hex_prefix<'a, E: de::Error>(s: &'a str, exp: &dyn de::Expected) -> Result<&'a str, E> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) if !hex.is_empty() => Ok(hex),
+        _ => Err(E::invalid_value(de::Unexpected::Str(s), exp)),
+    }
+}
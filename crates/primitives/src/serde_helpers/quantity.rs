@@ -0,0 +1,94 @@
+//! Serde functions for encoding unsigned integers as JSON-RPC quantities:
+//! `0x`-prefixed hex strings without leading zeros, such as `"0x1a"`.
+//!
+//! Deserialization requires the `0x` prefix and at least one digit.
+
+use super::strip_hex_prefix;
+use crate::Uint;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes an unsigned integer as a minimal `0x`-prefixed hex string.
+pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if value.is_zero() {
+        return serializer.serialize_str("0x0");
+    }
+    let hex = format!("{value:x}");
+    serializer.collect_str(&format_args!("0x{}", hex.trim_start_matches('0')))
+}
+
+/// Deserializes an unsigned integer from a `0x`-prefixed hex string.
+pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+    deserializer: D,
+) -> Result<Uint<BITS, LIMBS>, D::Error> {
+    struct QuantityVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<const BITS: usize, const LIMBS: usize> de::Visitor<'_> for QuantityVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a 0x-prefixed hex string of a {BITS} bit unsigned integer")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let hex = strip_hex_prefix(v, &self)?;
+            Uint::from_str_radix(hex, 16).map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_str(QuantityVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{U256, U64};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        inner: U256,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Opt {
+        #[serde(with = "crate::serde_helpers::opt_quantity")]
+        inner: Option<U64>,
+    }
+
+    #[test]
+    fn roundtrip() {
+        for (n, s) in [(0, "0x0"), (1, "0x1"), (26, "0x1a"), (1024, "0x400")] {
+            let value = Value { inner: U256::from(n) };
+            let json = format!(r#"{{"inner":"{s}"}}"#);
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+        }
+        assert_eq!(
+            serde_json::from_str::<Value>(r#"{"inner":"0x001A"}"#).unwrap().inner,
+            U256::from(26)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [r#""""#, r#""0x""#, r#""1a""#, r#""0xg""#, "26"] {
+            let json = format!(r#"{{"inner":{s}}}"#);
+            serde_json::from_str::<Value>(&json).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn opt() {
+        let value = Opt { inner: Some(U64::from(26)) };
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"inner":"0x1a"}"#);
+        assert_eq!(serde_json::from_str::<Opt>(r#"{"inner":"0x1a"}"#).unwrap(), value);
+
+        let value = Opt { inner: None };
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"inner":null}"#);
+        assert_eq!(serde_json::from_str::<Opt>(r#"{"inner":null}"#).unwrap(), value);
+    }
+}
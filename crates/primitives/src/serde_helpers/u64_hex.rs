@@ -0,0 +1,76 @@
+//! Serde functions for encoding a `u64` as a `0x`-prefixed hex string without
+//! leading zeros, such as `"0x1a"`.
+//!
+//! Deserialization requires the `0x` prefix and at least one digit.
+
+use super::strip_hex_prefix;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes a `u64` as a minimal `0x`-prefixed hex string.
+pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{value:#x}"))
+}
+
+/// Deserializes a `u64` from a `0x`-prefixed hex string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    struct U64HexVisitor;
+
+    impl de::Visitor<'_> for U64HexVisitor {
+        type Value = u64;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a 0x-prefixed hex string of a 64 bit unsigned integer")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let hex = strip_hex_prefix(v, &self)?;
+            u64::from_str_radix(hex, 16).map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_str(U64HexVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value {
+        #[serde(with = "super")]
+        inner: u64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Opt {
+        #[serde(with = "crate::serde_helpers::opt_u64_hex", default)]
+        inner: Option<u64>,
+    }
+
+    #[test]
+    fn roundtrip() {
+        for (n, s) in [(0, "0x0"), (26, "0x1a"), (u64::MAX, "0xffffffffffffffff")] {
+            let value = Value { inner: n };
+            let json = format!(r#"{{"inner":"{s}"}}"#);
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [r#""0x""#, r#""1a""#, r#""0x10000000000000000""#, "26"] {
+            let json = format!(r#"{{"inner":{s}}}"#);
+            serde_json::from_str::<Value>(&json).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn opt() {
+        assert_eq!(serde_json::from_str::<Opt>(r#"{"inner":"0x1a"}"#).unwrap().inner, Some(26));
+        assert_eq!(serde_json::from_str::<Opt>(r#"{"inner":null}"#).unwrap().inner, None);
+        assert_eq!(serde_json::from_str::<Opt>("{}").unwrap().inner, None);
+        assert_eq!(serde_json::to_string(&Opt { inner: Some(26) }).unwrap(), r#"{"inner":"0x1a"}"#);
+    }
+}
@@ -407,6 +407,10 @@ impl serde::Serialize for crate::Signature {
     {
         use serde::ser::SerializeMap;
 
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(&self.as_compact_bytes());
+        }
+
         let mut map = serializer.serialize_map(Some(3))?;
 
         map.serialize_entry("r", &self.r)?;
@@ -526,7 +530,56 @@ impl<'de> serde::Deserialize<'de> for crate::Signature {
             }
         }
 
-        deserializer.deserialize_map(SignatureVisitor)
+        struct CompactVisitor;
+        impl<'de> serde::de::Visitor<'de> for CompactVisitor {
+            type Value = crate::Signature;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("72 bytes of r, s, and v")
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let bytes: &[u8; 72] =
+                    v.try_into().map_err(|_| serde::de::Error::invalid_length(v.len(), &self))?;
+                crate::Signature::from_compact_bytes(bytes).map_err(serde::de::Error::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_map(SignatureVisitor)
+        } else {
+            deserializer.deserialize_bytes(CompactVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::Signature {
+    /// Returns the binary serialization format: `r`, `s` and the `v` value as
+    /// a big-endian `u64`, in that order.
+    fn as_compact_bytes(&self) -> [u8; 72] {
+        let v = match self.v {
+            Parity::Eip155(v) => v,
+            Parity::NonEip155(b) => b as u64 + 27,
+            Parity::Parity(b) => b as u64,
+        };
+
+        let mut bytes = [0u8; 72];
+        bytes[..32].copy_from_slice(&self.r.to_be_bytes::<32>());
+        bytes[32..64].copy_from_slice(&self.s.to_be_bytes::<32>());
+        bytes[64..].copy_from_slice(&v.to_be_bytes());
+        bytes
+    }
+
+    /// Parses the binary serialization format. See [`Self::as_compact_bytes`].
+    fn from_compact_bytes(bytes: &[u8; 72]) -> Result<Self, SignatureError> {
+        let r = U256::from_be_slice(&bytes[..32]);
+        let s = U256::from_be_slice(&bytes[32..64]);
+        let v = u64::from_be_bytes(bytes[64..].try_into().unwrap());
+        Self::from_rs_and_parity(r, s, v)
     }
 }
 
@@ -664,6 +717,36 @@ mod tests {
         assert_eq!(serialized, expected);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_binary_roundtrip() {
+        let r =
+            U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
+                .unwrap();
+        let s =
+            U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
+                .unwrap();
+
+        for v in [0u64, 1, 27, 28, 37, 38, 2 * 1337 + 36] {
+            let signature = crate::Signature::from_rs_and_parity(r, s, v).unwrap();
+
+            let bin = bincode::serialize(&signature).unwrap();
+            assert_eq!(bin.len(), 8 + 72);
+            assert_eq!(bincode::deserialize::<crate::Signature>(&bin).unwrap(), signature);
+
+            let bin = postcard::to_allocvec(&signature).unwrap();
+            assert_eq!(postcard::from_bytes::<crate::Signature>(&bin).unwrap(), signature);
+
+            let mut bin = Vec::new();
+            ciborium::into_writer(&signature, &mut bin).unwrap();
+            let de: crate::Signature = ciborium::from_reader(bin.as_slice()).unwrap();
+            assert_eq!(de, signature);
+        }
+
+        let bin = bincode::serialize(&crate::Bytes::from_static(&[0; 71])).unwrap();
+        bincode::deserialize::<crate::Signature>(&bin).unwrap_err();
+    }
+
     #[cfg(feature = "rlp")]
     #[test]
     fn signature_rlp_decode() {
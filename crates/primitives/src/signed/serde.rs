@@ -1,4 +1,5 @@
 use super::Signed;
+use crate::Uint;
 use alloc::string::String;
 use core::fmt;
 use serde::{
@@ -8,7 +9,11 @@ use serde::{
 
 impl<const BITS: usize, const LIMBS: usize> Serialize for Signed<BITS, LIMBS> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.collect_str(self)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0.to_be_bytes_vec())
+        }
     }
 }
 
@@ -46,10 +51,60 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Signed<BIT
             fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
                 self.visit_str(&v)
             }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                if v.len() != Uint::<BITS, LIMBS>::BYTES {
+                    return Err(de::Error::invalid_length(v.len(), &self));
+                }
+                Uint::try_from_be_slice(v)
+                    .map(Signed)
+                    .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Bytes(v), &self))
+            }
         }
 
-        deserializer.deserialize_any(SignedVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignedVisitor)
+        } else {
+            deserializer.deserialize_bytes(SignedVisitor)
+        }
     }
 }
 
-// TODO: Tests
+#[cfg(test)]
+mod tests {
+    use crate::{I128, I256, I8};
+
+    #[test]
+    fn serde_json() {
+        let n = I256::try_from(-42).unwrap();
+        let ser = serde_json::to_string(&n).unwrap();
+        assert_eq!(ser, "\"-42\"");
+        assert_eq!(serde_json::from_str::<I256>(&ser).unwrap(), n);
+        assert_eq!(serde_json::from_str::<I256>("-42").unwrap(), n);
+    }
+
+    #[test]
+    fn serde_binary_roundtrip() {
+        for n in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::ONE, I256::MAX] {
+            let bin = bincode::serialize(&n).unwrap();
+            assert_eq!(bin.len(), 8 + 32);
+            assert_eq!(bincode::deserialize::<I256>(&bin).unwrap(), n);
+
+            let bin = postcard::to_allocvec(&n).unwrap();
+            assert_eq!(postcard::from_bytes::<I256>(&bin).unwrap(), n);
+
+            let mut bin = Vec::new();
+            ciborium::into_writer(&n, &mut bin).unwrap();
+            assert_eq!(ciborium::from_reader::<I256, _>(bin.as_slice()).unwrap(), n);
+        }
+
+        let n = I8::try_from(-2).unwrap();
+        let bin = bincode::serialize(&n).unwrap();
+        assert_eq!(bin, [1, 0, 0, 0, 0, 0, 0, 0, 0xfe]);
+        assert_eq!(bincode::deserialize::<I8>(&bin).unwrap(), n);
+
+        // wrong width
+        let bin = bincode::serialize(&I128::MINUS_ONE).unwrap();
+        bincode::deserialize::<I256>(&bin).unwrap_err();
+    }
+}
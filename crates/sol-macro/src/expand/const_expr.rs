@@ -0,0 +1,266 @@
+//! Evaluation of constant expressions in array sizes.
+
+use super::RESOLVE_LIMIT;
+use crate::utils;
+use ast::{
+    visit_mut, ArgListImpl, BinOp, Block, Expr, File, Item, ItemContract, Lit, LitNumber, Spanned,
+    Type, VisitMut,
+};
+use indexmap::IndexMap;
+use proc_macro2::Span;
+use syn::{Error, LitInt, Result};
+
+/// `(contract, name) => (contract, value)`
+type Constants = IndexMap<(Option<String>, String), (Option<String>, Expr)>;
+
+/// Evaluates the constant expressions used as array sizes in the file,
+/// replacing them with integer literals.
+///
+/// Expressions may reference `constant` variables defined at the file level
+/// or, unqualified from inside of the same contract or qualified as
+/// `Contract.NAME`, at the contract level.
+pub(super) fn eval_array_sizes(file: &mut File) -> Result<()> {
+    let mut constants = Constants::new();
+    collect_constants(&file.items, None, &mut constants);
+    let mut evaluator = Evaluator { constants: &constants, contract: None, errors: Vec::new() };
+    evaluator.visit_file(file);
+    utils::combine_errors(evaluator.errors)
+}
+
+/// Evaluates the constant expressions used as array sizes in the type.
+///
+/// Only literals can be referenced in this case.
+pub(crate) fn eval_type_array_sizes(ty: &mut Type) -> Result<()> {
+    let mut evaluator =
+        Evaluator { constants: &Constants::new(), contract: None, errors: Vec::new() };
+    evaluator.visit_type(ty);
+    utils::combine_errors(evaluator.errors)
+}
+
+fn collect_constants(items: &[Item], contract: Option<&str>, constants: &mut Constants) {
+    for item in items {
+        match item {
+            Item::Variable(var) if var.attributes.has_constant() => {
+                if let Some((_, expr)) = &var.initializer {
+                    let contract = contract.map(ToString::to_string);
+                    let key = (contract.clone(), var.name.as_string());
+                    constants.insert(key, (contract, expr.clone()));
+                }
+            }
+            Item::Contract(c) => {
+                collect_constants(&c.body, Some(&c.name.as_string()), constants);
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Evaluator<'a> {
+    constants: &'a Constants,
+    contract: Option<String>,
+    errors: Vec<Error>,
+}
+
+impl<'ast> VisitMut<'ast> for Evaluator<'_> {
+    fn visit_type(&mut self, ty: &'ast mut Type) {
+        if let Type::Array(array) = ty {
+            if !array.is_size_evaluated() {
+                let size = array.size.as_deref_mut().unwrap();
+                match self.eval_size(size) {
+                    Ok(value) => {
+                        let lit = LitInt::new(&value.to_string(), size.span());
+                        *size = Expr::Lit(Lit::Number(LitNumber::Int(lit)));
+                    }
+                    Err(e) => self.errors.push(e),
+                }
+            }
+        }
+        visit_mut::visit_type(self, ty);
+    }
+
+    fn visit_item_contract(&mut self, contract: &'ast mut ItemContract) {
+        let prev = self.contract.replace(contract.name.as_string());
+        visit_mut::visit_item_contract(self, contract);
+        self.contract = prev;
+    }
+
+    // Function bodies are not expanded.
+    fn visit_block(&mut self, _block: &'ast mut Block) {}
+}
+
+impl Evaluator<'_> {
+    fn eval_size(&self, expr: &Expr) -> Result<usize> {
+        let value = self.eval(expr, self.contract.as_deref(), 0)?;
+        match usize::try_from(value) {
+            Ok(0) => Err(Error::new(expr.span(), "array size must be greater than zero")),
+            Ok(value) => Ok(value),
+            Err(_) => Err(Error::new(expr.span(), "array size is too large")),
+        }
+    }
+
+    /// Evaluates a constant expression in the scope of `contract`.
+    ///
+    /// Binary expressions are parsed right-associatively and without operator
+    /// precedence, so they are flattened and re-associated here.
+    fn eval(&self, expr: &Expr, contract: Option<&str>, depth: usize) -> Result<u128> {
+        let mut operands = Vec::new();
+        let mut ops = Vec::new();
+        flatten(expr, &mut operands, &mut ops);
+        let values = operands
+            .into_iter()
+            .map(|e| self.eval_operand(e, contract, depth))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut values = values.into_iter();
+        let mut ops = ops.into_iter().peekable();
+        let first = values.next().unwrap();
+        eval_binary(first, 0, &mut values, &mut ops)
+    }
+
+    fn eval_operand(&self, expr: &Expr, contract: Option<&str>, depth: usize) -> Result<u128> {
+        match expr {
+            Expr::Lit(Lit::Number(LitNumber::Int(lit))) => lit.base10_parse(),
+            Expr::Lit(Lit::Number(LitNumber::Float(lit))) => {
+                let digits = lit.base10_digits();
+                let value = digits.split_once(['e', 'E']).and_then(|(mantissa, exp)| {
+                    let exp = 10u128.checked_pow(exp.parse().ok()?)?;
+                    mantissa.parse::<u128>().ok()?.checked_mul(exp)
+                });
+                value.ok_or_else(|| Error::new(lit.span(), "expected an integer literal"))
+            }
+            Expr::Tuple(tuple) if tuple.elems.len() == 1 && !tuple.elems.trailing_punct() => {
+                self.eval(&tuple.elems[0], contract, depth)
+            }
+            // type conversions, e.g. `uint8(N)`, which truncate the value
+            Expr::Call(call) => {
+                let Expr::Type(Type::Uint(_, size)) = &*call.expr else {
+                    return Err(unsupported(call.expr.span()));
+                };
+                let ArgListImpl::Unnamed(args) = &call.args.list else {
+                    return Err(Error::new(call.args.span(), "expected a single argument"));
+                };
+                if args.len() != 1 {
+                    return Err(Error::new(call.args.span(), "expected a single argument"));
+                }
+                let value = self.eval(&args[0], contract, depth)?;
+                let bits = size.map_or(256, |size| size.get() as u32);
+                Ok(if bits >= u128::BITS { value } else { value & ((1 << bits) - 1) })
+            }
+            Expr::Ident(name) => {
+                let name = name.as_string();
+                let found = contract
+                    .and_then(|c| self.constants.get(&(Some(c.to_string()), name.clone())))
+                    .or_else(|| self.constants.get(&(None, name)));
+                self.eval_constant(expr.span(), found, depth)
+            }
+            Expr::Member(member) => match (&*member.expr, &*member.member) {
+                (Expr::Ident(contract), Expr::Ident(name)) => {
+                    let key = (Some(contract.as_string()), name.as_string());
+                    self.eval_constant(expr.span(), self.constants.get(&key), depth)
+                }
+                _ => Err(unsupported(expr.span())),
+            },
+            _ => Err(unsupported(expr.span())),
+        }
+    }
+
+    fn eval_constant(
+        &self,
+        span: Span,
+        constant: Option<&(Option<String>, Expr)>,
+        depth: usize,
+    ) -> Result<u128> {
+        let Some((contract, expr)) = constant else {
+            return Err(Error::new(span, "unresolved constant"));
+        };
+        if depth >= RESOLVE_LIMIT {
+            return Err(Error::new(span, "failed to evaluate recursive constant"));
+        }
+        self.eval(expr, contract.as_deref(), depth + 1)
+    }
+}
+
+/// Flattens a chain of binary expressions into its operands and operators.
+fn flatten<'a>(expr: &'a Expr, operands: &mut Vec<&'a Expr>, ops: &mut Vec<&'a BinOp>) {
+    match expr {
+        Expr::Binary(binary) => {
+            flatten(&binary.left, operands, ops);
+            ops.push(&binary.op);
+            flatten(&binary.right, operands, ops);
+        }
+        expr => operands.push(expr),
+    }
+}
+
+/// Precedence climbing over the flattened operands.
+fn eval_binary<'a>(
+    mut lhs: u128,
+    min_precedence: u8,
+    values: &mut impl Iterator<Item = u128>,
+    ops: &mut std::iter::Peekable<impl Iterator<Item = &'a BinOp>>,
+) -> Result<u128> {
+    while let Some(&op) = ops.peek() {
+        let precedence = precedence(op)?;
+        if precedence < min_precedence {
+            break;
+        }
+        ops.next();
+        let rhs = values.next().unwrap();
+        // `**` is right-associative
+        let rhs = eval_binary(
+            rhs,
+            if matches!(op, BinOp::Pow(..)) { precedence } else { precedence + 1 },
+            values,
+            ops,
+        )?;
+        lhs = apply(op, lhs, rhs)?;
+    }
+    Ok(lhs)
+}
+
+fn precedence(op: &BinOp) -> Result<u8> {
+    Ok(match op {
+        BinOp::Pow(..) => 7,
+        BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => 6,
+        BinOp::Add(_) | BinOp::Sub(_) => 5,
+        BinOp::Shl(_) | BinOp::Shr(_) => 4,
+        BinOp::BitAnd(_) => 3,
+        BinOp::BitXor(_) => 2,
+        BinOp::BitOr(_) => 1,
+        op => return Err(Error::new(op.span(), "unsupported operator in constant expression")),
+    })
+}
+
+fn apply(op: &BinOp, lhs: u128, rhs: u128) -> Result<u128> {
+    let shift = u32::try_from(rhs).ok();
+    let value = match op {
+        BinOp::Pow(..) => shift.and_then(|rhs| lhs.checked_pow(rhs)),
+        BinOp::Mul(_) => lhs.checked_mul(rhs),
+        BinOp::Div(_) => lhs.checked_div(rhs),
+        BinOp::Rem(_) => lhs.checked_rem(rhs),
+        BinOp::Add(_) => lhs.checked_add(rhs),
+        BinOp::Sub(_) => lhs.checked_sub(rhs),
+        BinOp::Shl(_) => shift.and_then(|rhs| lhs.checked_shl(rhs)).filter(|v| v >> rhs == lhs),
+        BinOp::Shr(_) => Some(shift.and_then(|rhs| lhs.checked_shr(rhs)).unwrap_or(0)),
+        BinOp::BitAnd(_) => Some(lhs & rhs),
+        BinOp::BitXor(_) => Some(lhs ^ rhs),
+        BinOp::BitOr(_) => Some(lhs | rhs),
+        _ => unreachable!(),
+    };
+    value.ok_or_else(|| {
+        let msg = match op {
+            BinOp::Div(_) | BinOp::Rem(_) => "division by zero in constant expression",
+            BinOp::Sub(_) => "arithmetic underflow in constant expression",
+            _ => "arithmetic overflow in constant expression",
+        };
+        Error::new(op.span(), msg)
+    })
+}
+
+fn unsupported(span: Span) -> Error {
+    Error::new(
+        span,
+        "unsupported expression in array size; \
+         expected an integer constant expression",
+    )
+}
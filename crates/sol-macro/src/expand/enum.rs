@@ -183,6 +183,34 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 }
             }
 
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolStorage for #name {
+                const STORAGE_BYTES: usize = <#uint8 as ::alloy_sol_types::SolStorage>::STORAGE_BYTES;
+
+                #[inline]
+                fn storage_store(
+                    rust: &Self::RustType,
+                    slot: ::alloy_sol_types::private::U256,
+                    offset: usize,
+                    storage: &mut ::alloy_sol_types::private::BTreeMap<
+                        ::alloy_sol_types::private::B256,
+                        ::alloy_sol_types::private::B256,
+                    >,
+                ) -> ::alloy_sol_types::Result<()> {
+                    <#uint8 as ::alloy_sol_types::SolStorage>::storage_store(rust.as_u8(), slot, offset, storage)
+                }
+
+                #[inline]
+                fn storage_load<F: FnMut(::alloy_sol_types::private::B256) -> ::alloy_sol_types::private::B256>(
+                    slot: ::alloy_sol_types::private::U256,
+                    offset: usize,
+                    sload: &mut F,
+                ) -> ::alloy_sol_types::Result<Self::RustType> {
+                    <#uint8 as ::alloy_sol_types::SolStorage>::storage_load(slot, offset, sload)
+                        .and_then(<Self as ::core::convert::TryFrom<u8>>::try_from)
+                }
+            }
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolEnum for #name {
                 const COUNT: usize = #count;
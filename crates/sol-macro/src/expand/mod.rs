@@ -25,6 +25,9 @@ mod macros;
 mod ty;
 pub use ty::expand_type;
 
+mod const_expr;
+pub(crate) use const_expr::eval_type_array_sizes;
+
 mod contract;
 mod r#enum;
 mod error;
@@ -41,7 +44,8 @@ mod to_abi;
 const RESOLVE_LIMIT: usize = 32;
 
 /// The [`sol!`](crate::sol!) expansion implementation.
-pub fn expand(ast: File) -> Result<TokenStream> {
+pub fn expand(mut ast: File) -> Result<TokenStream> {
+    const_expr::eval_array_sizes(&mut ast)?;
     ExpCtxt::new(&ast).expand()
 }
 
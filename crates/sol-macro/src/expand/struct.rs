@@ -18,6 +18,10 @@ use syn::Result;
 ///     ...
 /// }
 ///
+/// impl SolStorage for #name {
+///     ...
+/// }
+///
 /// // Needed to use in event parameters
 /// impl EventTopic for #name {
 ///     ...
@@ -141,6 +145,37 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 }
             }
 
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolStorage for #name {
+                const STORAGE_BYTES: usize =
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolStorage>::STORAGE_BYTES;
+
+                #[inline]
+                fn storage_store(
+                    rust: &Self::RustType,
+                    slot: ::alloy_sol_types::private::U256,
+                    offset: usize,
+                    storage: &mut ::alloy_sol_types::private::BTreeMap<
+                        ::alloy_sol_types::private::B256,
+                        ::alloy_sol_types::private::B256,
+                    >,
+                ) -> ::alloy_sol_types::Result<()> {
+                    // TODO: Avoid cloning
+                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(rust.clone());
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolStorage>::storage_store(&tuple, slot, offset, storage)
+                }
+
+                #[inline]
+                fn storage_load<F: FnMut(::alloy_sol_types::private::B256) -> ::alloy_sol_types::private::B256>(
+                    slot: ::alloy_sol_types::private::U256,
+                    offset: usize,
+                    sload: &mut F,
+                ) -> ::alloy_sol_types::Result<Self::RustType> {
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolStorage>::storage_load(slot, offset, sload)
+                        .map(<Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from)
+                }
+            }
+
             #[automatically_derived]
             impl ::alloy_sol_types::EventTopic for #name {
                 #[inline]
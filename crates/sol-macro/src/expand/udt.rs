@@ -112,6 +112,33 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
             }
         }
 
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolStorage for #name {
+            const STORAGE_BYTES: usize = <#underlying_sol as ::alloy_sol_types::SolStorage>::STORAGE_BYTES;
+
+            #[inline]
+            fn storage_store(
+                rust: &Self::RustType,
+                slot: ::alloy_sol_types::private::U256,
+                offset: usize,
+                storage: &mut ::alloy_sol_types::private::BTreeMap<
+                    ::alloy_sol_types::private::B256,
+                    ::alloy_sol_types::private::B256,
+                >,
+            ) -> ::alloy_sol_types::Result<()> {
+                <#underlying_sol as ::alloy_sol_types::SolStorage>::storage_store(rust, slot, offset, storage)
+            }
+
+            #[inline]
+            fn storage_load<F: FnMut(::alloy_sol_types::private::B256) -> ::alloy_sol_types::private::B256>(
+                slot: ::alloy_sol_types::private::U256,
+                offset: usize,
+                sload: &mut F,
+            ) -> ::alloy_sol_types::Result<Self::RustType> {
+                <#underlying_sol as ::alloy_sol_types::SolStorage>::storage_load(slot, offset, sload)
+            }
+        }
+
         #[automatically_derived]
         impl ::alloy_sol_types::EventTopic for #name {
             #[inline]
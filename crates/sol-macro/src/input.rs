@@ -140,9 +140,10 @@ impl SolInput {
                 file.attrs.extend(attrs);
                 crate::expand::expand(file)
             }
-            SolInputKind::Type(ty) => {
+            SolInputKind::Type(mut ty) => {
                 if attrs.is_empty() {
-                    Ok(crate::expand::expand_type(&ty))
+                    crate::expand::eval_type_array_sizes(&mut ty)
+                        .map(|()| crate::expand::expand_type(&ty))
                 } else {
                     Err(Error::new_spanned(
                         attrs.first().unwrap(),
@@ -1,4 +1,4 @@
-use alloy_json_abi::{ContractObject, JsonAbi};
+use alloy_json_abi::{Bytecode, ContractObject, JsonAbi};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, TokenStreamExt};
 use syn::{Attribute, Result};
@@ -9,11 +9,12 @@ pub fn expand(name: Ident, json: ContractObject, attrs: Vec<Attribute>) -> Resul
     let mut abi = abi.ok_or_else(|| syn::Error::new(name.span(), "ABI not found in JSON"))?;
     let sol = abi_to_sol(&name, &mut abi);
     let sol_interface_tokens = tokens_for_sol(&name, &sol)?;
-    let bytecode = bytecode.map(|bytes| {
+    // Unlinked bytecode cannot be represented as constant bytes, so it is skipped.
+    let bytecode = bytecode.and_then(Bytecode::into_bytes).map(|bytes| {
         let s = bytes.to_string();
         quote!(bytecode = #s,)
     });
-    let deployed_bytecode = deployed_bytecode.map(|bytes| {
+    let deployed_bytecode = deployed_bytecode.and_then(Bytecode::into_bytes).map(|bytes| {
         let s = bytes.to_string();
         quote!(deployed_bytecode = #s)
     });
@@ -0,0 +1,354 @@
+use crate::{is_id_continue, is_id_start, Error, Result};
+use alloc::{borrow::Cow, string::String};
+use core::{fmt::Write, ops::Range};
+
+/// Evaluates a constant integer expression, such as `2 * MAX_TOKENS + 1`.
+///
+/// Supports decimal, hexadecimal and scientific notation literals,
+/// parentheses, the `**`, `*`, `/`, `%`, `+`, `-`, `<<`, `>>`, `&`, `^` and `|`
+/// binary operators with Solidity's precedence, and `uint` type conversions
+/// like `uint8(x)`, which truncate the value to the type's size.
+///
+/// Identifiers, including qualified ones like `Lib.MAX`, are looked up with
+/// `constants`.
+///
+/// Arithmetic is performed on unsigned 128-bit integers; overflows and
+/// underflows are errors, and so are expressions nested more than 32 levels
+/// deep.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_type_parser::eval_const_expr;
+///
+/// let constants = |name: &str| match name {
+///     "N" => Some(4),
+///     "Lib.MAX" => Some(10),
+///     _ => None,
+/// };
+/// assert_eq!(eval_const_expr("2 * N + 1", constants), Ok(9));
+/// assert_eq!(eval_const_expr("2 ** (N - 1) / Lib.MAX", constants), Ok(0));
+/// assert!(eval_const_expr("N + M", constants).is_err());
+/// ```
+pub fn eval_const_expr(input: &str, constants: impl Fn(&str) -> Option<u128>) -> Result<u128> {
+    let mut evaluator = Evaluator { input, pos: 0, depth: 0, constants };
+    let value = evaluator.expr(0)?;
+    evaluator.skip_whitespace();
+    if evaluator.pos < input.len() {
+        return Err(evaluator.unexpected());
+    }
+    Ok(value)
+}
+
+/// Evaluates the constant expressions used as array sizes in a type string,
+/// replacing them with their values.
+///
+/// Returns the input unchanged if all array sizes are already integer
+/// literals. See [`eval_const_expr`] for the supported expressions.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_type_parser::{eval_array_sizes, TypeSpecifier};
+/// use std::collections::BTreeMap;
+///
+/// let constants = BTreeMap::from([("N_TOKENS", 3), ("MAX", 8)]);
+/// let ty = eval_array_sizes("(uint256[N_TOKENS],bytes32[2 * MAX])[]", |name| {
+///     constants.get(name).copied()
+/// })?;
+/// assert_eq!(ty, "(uint256[3],bytes32[16])[]");
+///
+/// let ty = TypeSpecifier::parse(&ty)?;
+/// assert_eq!(ty.stem.span(), "(uint256[3],bytes32[16])");
+/// # Ok::<_, alloy_sol_type_parser::Error>(())
+/// ```
+pub fn eval_array_sizes(
+    input: &str,
+    constants: impl Fn(&str) -> Option<u128>,
+) -> Result<Cow<'_, str>> {
+    let mut out = String::new();
+    let mut last = 0;
+    let mut rest = input;
+    while let Some(open) = rest.find('[') {
+        let start = input.len() - rest.len() + open + 1;
+        let Some(len) = input[start..].find(']') else { break };
+        let size = &input[start..start + len];
+        rest = &input[start + len..];
+
+        let trimmed = size.trim();
+        if trimmed.is_empty() || trimmed.bytes().all(|b| b.is_ascii_digit()) {
+            continue;
+        }
+
+        let value = eval_const_expr(size, &constants).map_err(|e| e.relocate(size, input))?;
+        out.push_str(&input[last..start]);
+        write!(out, "{value}").unwrap();
+        last = start + len;
+    }
+
+    if last == 0 {
+        return Ok(Cow::Borrowed(input));
+    }
+    out.push_str(&input[last..]);
+    Ok(Cow::Owned(out))
+}
+
+/// The maximum nesting depth of parentheses, conversions and `**` chains.
+const MAX_DEPTH: usize = 32;
+
+struct Evaluator<'a, F> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+    constants: F,
+}
+
+impl<F: Fn(&str) -> Option<u128>> Evaluator<'_, F> {
+    fn expr(&mut self, min_precedence: u8) -> Result<u128> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error("expression is nested too deeply", self.pos..self.pos));
+        }
+        self.depth += 1;
+        let value = self.binary(min_precedence);
+        self.depth -= 1;
+        value
+    }
+
+    /// Precedence climbing over the binary operators.
+    fn binary(&mut self, min_precedence: u8) -> Result<u128> {
+        let mut lhs = self.primary()?;
+        loop {
+            self.skip_whitespace();
+            let Some((op, precedence)) = self.peek_op() else { break };
+            if precedence < min_precedence {
+                break;
+            }
+            let start = self.pos;
+            self.pos += op.len();
+            let span = start..self.pos;
+            // `**` is right-associative
+            let rhs = self.expr(if op == "**" { precedence } else { precedence + 1 })?;
+            lhs = apply(op, lhs, rhs).map_err(|msg| self.error(msg, span))?;
+        }
+        Ok(lhs)
+    }
+
+    fn primary(&mut self) -> Result<u128> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let start = self.pos;
+        match rest.chars().next() {
+            Some('(') => {
+                self.pos += 1;
+                let value = self.expr(0)?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let len = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_');
+                self.pos += len.unwrap_or(rest.len());
+                let span = start..self.pos;
+                parse_number(&self.input[span.clone()])
+                    .ok_or_else(|| self.error("invalid number literal", span))
+            }
+            Some(c) if is_id_start(c) => {
+                let len = rest.find(|c: char| !is_id_continue(c) && c != '.');
+                self.pos += len.unwrap_or(rest.len());
+                let span = start..self.pos;
+                let name = &self.input[span.clone()];
+
+                // type conversion, e.g. `uint8(x)`
+                self.skip_whitespace();
+                if self.input[self.pos..].starts_with('(') {
+                    let bits = uint_bits(name)
+                        .ok_or_else(|| self.error("unsupported function call", span))?;
+                    return self.primary().map(|value| truncate(value, bits));
+                }
+
+                (self.constants)(name).ok_or_else(|| self.error("unknown constant", span))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn peek_op(&self) -> Option<(&'static str, u8)> {
+        const OPS: [(&str, u8); 11] = [
+            ("**", 7),
+            ("*", 6),
+            ("/", 6),
+            ("%", 6),
+            ("+", 5),
+            ("-", 5),
+            ("<<", 4),
+            (">>", 4),
+            ("&", 3),
+            ("^", 2),
+            ("|", 1),
+        ];
+        let rest = &self.input[self.pos..];
+        OPS.into_iter().find(|(op, _)| rest.starts_with(op))
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with(c) {
+            self.pos += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn unexpected(&self) -> Error {
+        let rest = &self.input[self.pos..];
+        match rest.chars().next() {
+            Some(c) => self.error("unexpected token", self.pos..self.pos + c.len_utf8()),
+            None => self.error("unexpected end of input", self.pos..self.pos),
+        }
+    }
+
+    fn error(&self, msg: &str, span: Range<usize>) -> Error {
+        let token = &self.input[span.clone()];
+        let msg = if token.is_empty() {
+            format!("constant expression error: {msg}")
+        } else {
+            format!("constant expression error: {msg} `{token}`")
+        };
+        Error::located(msg, self.input, span)
+    }
+}
+
+fn apply(op: &str, lhs: u128, rhs: u128) -> core::result::Result<u128, &'static str> {
+    let overflow = "arithmetic overflow in";
+    match op {
+        "**" => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_pow(rhs)).ok_or(overflow),
+        "*" => lhs.checked_mul(rhs).ok_or(overflow),
+        "/" => lhs.checked_div(rhs).ok_or("division by zero in"),
+        "%" => lhs.checked_rem(rhs).ok_or("division by zero in"),
+        "+" => lhs.checked_add(rhs).ok_or(overflow),
+        "-" => lhs.checked_sub(rhs).ok_or("arithmetic underflow in"),
+        "<<" => {
+            let shifted = u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shl(rhs));
+            shifted.filter(|v| v >> rhs == lhs).ok_or(overflow)
+        }
+        ">>" => Ok(u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shr(rhs)).unwrap_or(0)),
+        "&" => Ok(lhs & rhs),
+        "^" => Ok(lhs ^ rhs),
+        "|" => Ok(lhs | rhs),
+        _ => unreachable!("unknown operator: {op}"),
+    }
+}
+
+/// Returns the size in bits of a `uint` type name.
+fn uint_bits(name: &str) -> Option<u32> {
+    match name.strip_prefix("uint")? {
+        "" => Some(256),
+        bits => bits.parse().ok().filter(|bits| (8..=256).contains(bits) && bits % 8 == 0),
+    }
+}
+
+/// Truncates a value to `bits` bits, like a conversion to a smaller `uint`.
+const fn truncate(value: u128, bits: u32) -> u128 {
+    if bits >= u128::BITS {
+        value
+    } else {
+        value & ((1 << bits) - 1)
+    }
+}
+
+/// Parses a decimal, hexadecimal or scientific notation integer literal.
+fn parse_number(s: &str) -> Option<u128> {
+    let s = s.replace('_', "");
+    if let Some(hex) = s.strip_prefix("0x") {
+        return u128::from_str_radix(hex, 16).ok();
+    }
+    match s.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => {
+            let exp = exp.parse::<u32>().ok()?;
+            mantissa.parse::<u128>().ok()?.checked_mul(10u128.checked_pow(exp)?)
+        }
+        None => s.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constants(name: &str) -> Option<u128> {
+        match name {
+            "N" => Some(3),
+            "MAX" => Some(8),
+            "Lib.SIZE" => Some(5),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn precedence() {
+        let eval = |s| eval_const_expr(s, constants).unwrap();
+        assert_eq!(eval("42"), 42);
+        assert_eq!(eval("1_000"), 1000);
+        assert_eq!(eval("0x10"), 16);
+        assert_eq!(eval("2e3"), 2000);
+        assert_eq!(eval("2 * MAX + 1"), 17);
+        assert_eq!(eval("1 + 2 * MAX"), 17);
+        assert_eq!(eval("(1 + 2) * MAX"), 24);
+        assert_eq!(eval("MAX - N - 1"), 4);
+        assert_eq!(eval("2 ** N ** 2"), 512);
+        assert_eq!(eval("2 * 3 ** 2"), 18);
+        assert_eq!(eval("1 << N + 1"), 16);
+        assert_eq!(eval("MAX | 1 & 3"), 9);
+        assert_eq!(eval("uint256(Lib.SIZE) % 2"), 1);
+        assert_eq!(eval("uint(MAX)"), 8);
+        assert_eq!(eval("uint8(300)"), 44);
+        assert_eq!(eval("uint16 (0x12345)"), 0x2345);
+    }
+
+    #[test]
+    fn errors() {
+        let e = eval_const_expr("N * M", constants).unwrap_err();
+        assert_eq!(e.message(), "constant expression error: unknown constant `M`");
+        assert_eq!(e.span(), Some(4..5));
+
+        let e = eval_const_expr("N - MAX", constants).unwrap_err();
+        assert_eq!(e.span(), Some(2..3));
+
+        eval_const_expr("MAX / (N - 3)", constants).unwrap_err();
+        eval_const_expr("2 ** 128", constants).unwrap_err();
+        eval_const_expr("(N + 1", constants).unwrap_err();
+        eval_const_expr("N N", constants).unwrap_err();
+
+        let e = eval_const_expr("foo(N)", constants).unwrap_err();
+        assert_eq!(e.message(), "constant expression error: unsupported function call `foo`");
+        eval_const_expr("uint7(N)", constants).unwrap_err();
+        eval_const_expr("int8(N)", constants).unwrap_err();
+
+        let nested = |n| format!("{}1{}", "(".repeat(n), ")".repeat(n));
+        assert_eq!(eval_const_expr(&nested(30), constants), Ok(1));
+        let e = eval_const_expr(&nested(100_000), constants).unwrap_err();
+        assert_eq!(e.message(), "constant expression error: expression is nested too deeply");
+        eval_const_expr(&"uint256(".repeat(100_000), constants).unwrap_err();
+        eval_const_expr(&"2 ** ".repeat(100_000), constants).unwrap_err();
+    }
+
+    #[test]
+    fn array_sizes() {
+        let eval = |s| eval_array_sizes(s, constants);
+        assert!(matches!(eval("uint256[2][]"), Ok(Cow::Borrowed("uint256[2][]"))));
+        assert_eq!(eval("uint256[N]").unwrap(), "uint256[3]");
+        assert_eq!(
+            eval("(bool[MAX / 2],bytes32[N][])[Lib.SIZE]").unwrap(),
+            "(bool[4],bytes32[3][])[5]"
+        );
+
+        let e = eval("(bool,uint8[N + X])").unwrap_err();
+        assert_eq!(e.input(), Some("(bool,uint8[N + X])"));
+        assert_eq!(e.span(), Some(16..17));
+    }
+}
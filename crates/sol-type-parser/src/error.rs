@@ -1,19 +1,61 @@
-use alloc::boxed::Box;
-use core::fmt;
+use crate::{ident::is_id_continue, suggest};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, ops::Range};
+use winnow::error::{ContextError, ParseError, StrContext};
 
 /// Parser result
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// Parser error.
+///
+/// Besides the message, errors produced while parsing a string carry the input
+/// and the byte span of the offending token, the set of tokens that were
+/// expected at that position, and possibly a suggested correction. All of
+/// these are included in the [`Display`](fmt::Display) implementation:
+///
+/// ```
+/// use alloy_sol_type_parser::TypeSpecifier;
+///
+/// let e = TypeSpecifier::parse("uint25 6[]").unwrap_err();
+/// assert_eq!(e.span(), Some(7..8));
+/// assert_eq!(e.suggestion(), Some("uint256[]"));
+/// assert_eq!(
+///     e.to_string(),
+///     "\
+/// parser error: unexpected `6`
+///   uint25 6[]
+///          ^
+/// help: did you mean `uint256[]`?"
+/// );
+/// ```
 #[derive(Clone, PartialEq, Eq)]
-pub struct Error(Repr);
+pub struct Error(Box<Repr>);
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Error").field(&self.0 .0).finish()
+        let Repr { message, input, span, expected, suggestion } = &*self.0;
+        let mut f = f.debug_struct("Error");
+        f.field("message", message);
+        if let Some(input) = input {
+            f.field("input", input);
+        }
+        if let Some(span) = span {
+            f.field("span", span);
+        }
+        if !expected.is_empty() {
+            f.field("expected", expected);
+        }
+        if let Some(suggestion) = suggestion {
+            f.field("suggestion", suggestion);
+        }
+        f.finish()
     }
 }
 
@@ -36,15 +78,21 @@ impl Error {
 
     /// Instantiate an invalid type string error. Invalid type string errors are
     /// for type strings that are not valid type strings. E.g. "uint256))))[".
+    ///
+    /// If the type string looks like a misspelled elementary type, such as
+    /// `"byte"` or `"unit256"`, a [suggestion](Self::suggestion) is attached.
     pub fn invalid_type_string(ty: impl fmt::Display) -> Self {
-        Self::_new("invalid type string: ", &ty)
+        Self::_new_suggested("invalid type string: ", ty.to_string())
     }
 
     /// Instantiate an invalid size error. Invalid size errors are for valid
     /// primitive types with invalid sizes. E.g. `"uint7"` or `"bytes1337"` or
     /// `"string[aaaaaa]"`.
+    ///
+    /// A [suggestion](Self::suggestion) with the closest valid size is
+    /// attached where possible.
     pub fn invalid_size(ty: impl fmt::Display) -> Self {
-        Self::_new("invalid size for type: ", &ty)
+        Self::_new_suggested("invalid size for type: ", ty.to_string())
     }
 
     // Not public API.
@@ -52,15 +100,295 @@ impl Error {
     #[inline(never)]
     #[cold]
     pub fn _new(s: &str, e: &dyn fmt::Display) -> Self {
-        Self(Repr(format!("{s}{e}").into_boxed_str()))
+        Self::from_message(format!("{s}{e}"))
+    }
+
+    #[inline(never)]
+    #[cold]
+    fn _new_suggested(s: &str, ty: String) -> Self {
+        let mut e = Self::from_message(format!("{s}{ty}"));
+        e.0.suggestion = suggest::root_type(&ty).map(Into::into);
+        e
+    }
+
+    fn from_message(message: String) -> Self {
+        Self(Box::new(Repr {
+            message: message.into_boxed_str(),
+            input: None,
+            span: None,
+            expected: Vec::new(),
+            suggestion: None,
+        }))
+    }
+
+    /// Instantiate a new error from a [`winnow`] parse error, keeping the
+    /// position and the expected tokens.
+    #[cold]
+    pub(crate) fn from_parse_error(e: ParseError<&str, ContextError>) -> Self {
+        let input = *e.input();
+        let span = token_span(input, e.offset());
+
+        let mut label = None;
+        let mut expected = Vec::<Box<str>>::new();
+        for context in e.inner().context() {
+            match context {
+                StrContext::Label(l) => label = label.or(Some(*l)),
+                StrContext::Expected(v) => {
+                    let v = v.to_string().into_boxed_str();
+                    if !expected.contains(&v) {
+                        expected.push(v);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        #[cfg(feature = "std")]
+        let cause = e.inner().cause().map(ToString::to_string);
+        #[cfg(not(feature = "std"))]
+        let cause: Option<String> = None;
+
+        let message = if let Some(cause) = cause {
+            format!("parser error: {cause}")
+        } else if let Some(label) = label {
+            format!("parser error: invalid {label}")
+        } else if span.start == input.len() {
+            "parser error: unexpected end of input".into()
+        } else {
+            format!("parser error: unexpected `{}`", &input[span.clone()])
+        };
+
+        let mut e = Self::located(message, input, span);
+        e.0.expected = expected;
+        e
+    }
+
+    /// Instantiate a new error located at `span` in `input`.
+    #[cold]
+    pub(crate) fn located(message: String, input: &str, span: Range<usize>) -> Self {
+        let mut e = Self::from_message(message);
+        e.0.input = Some(input.into());
+        e.0.span = Some(span);
+        e
+    }
+
+    /// Attaches a suggested correction of the whole input, if there isn't one
+    /// already.
+    pub(crate) fn or_suggest(mut self, f: impl FnOnce() -> Option<String>) -> Self {
+        if self.0.suggestion.is_none() {
+            self.0.suggestion = f().map(Into::into);
+        }
+        self
+    }
+
+    /// Locates an error raised for `inner` inside of `outer`, where `inner` is
+    /// a substring of `outer`.
+    ///
+    /// Errors without a location are located at the whole of `inner`, and the
+    /// suggestion, if any, is expanded to a correction of `outer`.
+    pub(crate) fn relocate(mut self, inner: &str, outer: &str) -> Self {
+        let Some(offset) = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize) else {
+            return self;
+        };
+        if offset + inner.len() > outer.len() {
+            return self;
+        }
+
+        let repr = &mut *self.0;
+        let span = match (&repr.input, &repr.span) {
+            (Some(input), Some(span)) if **input == *inner => span.clone(),
+            (None, None) => 0..inner.len(),
+            _ => return self,
+        };
+        let span = span.start + offset..span.end + offset;
+        if let Some(suggestion) = &repr.suggestion {
+            let (before, after) = (&outer[..offset], &outer[offset + inner.len()..]);
+            repr.suggestion = Some(format!("{before}{suggestion}{after}").into());
+        }
+        repr.input = Some(outer.into());
+        repr.span = Some(span);
+        self
+    }
+
+    /// Returns the error message, without the location or the suggestion.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    /// Returns the input string in which the error was found, if known.
+    #[inline]
+    pub fn input(&self) -> Option<&str> {
+        self.0.input.as_deref()
+    }
+
+    /// Returns the byte span of the offending token in the
+    /// [input](Self::input), if known.
+    #[inline]
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.0.span.clone()
+    }
+
+    /// Returns the tokens that were expected at the error position.
+    #[inline]
+    pub fn expected(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.0.expected.iter().map(|s| &**s)
+    }
+
+    /// Returns a suggested correction, if any.
+    ///
+    /// This is a corrected version of the whole [input](Self::input) if it is
+    /// known, or of the invalid type otherwise.
+    #[inline]
+    pub fn suggestion(&self) -> Option<&str> {
+        self.0.suggestion.as_deref()
     }
 }
 
+/// Returns the span of the token starting at `offset`, skipping whitespace.
+fn token_span(input: &str, offset: usize) -> Range<usize> {
+    let offset = offset.min(input.len());
+    let rest = &input[offset..];
+    let start = offset + (rest.len() - rest.trim_start().len());
+    let rest = &input[start..];
+    let len = match rest.chars().next() {
+        None => 0,
+        Some(c) if is_id_continue(c) => rest.find(|c| !is_id_continue(c)).unwrap_or(rest.len()),
+        Some(c) => c.len_utf8(),
+    };
+    start..start + len
+}
+
 #[derive(Clone, PartialEq, Eq)]
-struct Repr(Box<str>);
+struct Repr {
+    message: Box<str>,
+    input: Option<Box<str>>,
+    span: Option<Range<usize>>,
+    expected: Vec<Box<str>>,
+    suggestion: Option<Box<str>>,
+}
 
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(&self.message)?;
+
+        if let (Some(input), Some(span)) = (&self.input, &self.span) {
+            let column = input[..span.start].chars().count();
+            let width = input[span.clone()].chars().count().max(1);
+            write!(f, "\n  {input}\n  {:column$}{:^<width$}", "", "")?;
+        }
+
+        match &self.expected[..] {
+            [] => {}
+            [one] => write!(f, "\nexpected {one}")?,
+            [first, rest @ ..] => {
+                write!(f, "\nexpected one of {first}")?;
+                for e in rest {
+                    write!(f, ", {e}")?;
+                }
+            }
+        }
+
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\nhelp: did you mean `{suggestion}`?")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParameterSpecifier, TupleSpecifier, TypeSpecifier};
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn span_and_caret() {
+        let e = TypeSpecifier::parse("uint25 6[]").unwrap_err();
+        assert_eq!(e.input(), Some("uint25 6[]"));
+        assert_eq!(e.span(), Some(7..8));
+        assert_eq!(e.message(), "parser error: unexpected `6`");
+        assert_eq!(e.suggestion(), Some("uint256[]"));
+        assert!(e.to_string().contains("\n  uint25 6[]\n         ^\n"), "{e}");
+
+        let e = TypeSpecifier::parse("uint256[2]foo").unwrap_err();
+        assert_eq!(e.span(), Some(10..13));
+        assert!(e.to_string().contains("\n  uint256[2]foo\n            ^^^"), "{e}");
+    }
+
+    #[test]
+    fn expected_tokens() {
+        let e = TypeSpecifier::parse("uint256[2").unwrap_err();
+        assert_eq!(e.span(), Some(9..9));
+        assert_eq!(e.message(), "parser error: unexpected end of input");
+        assert_eq!(e.expected().collect::<Vec<_>>(), ["`]`"]);
+
+        let e = TupleSpecifier::parse("(bool,uint256").unwrap_err();
+        assert_eq!(e.span(), Some(13..13));
+        assert_eq!(e.expected().collect::<Vec<_>>(), ["`)`"]);
+        assert!(e.to_string().ends_with("\nexpected `)`"), "{e}");
+    }
+
+    #[test]
+    fn invalid_sizes() {
+        let e = TypeSpecifier::parse("uint265[]").unwrap().try_basic_solidity().unwrap_err();
+        assert_eq!(e.message(), "invalid size for type: uint265");
+        assert_eq!(e.input(), Some("uint265[]"));
+        assert_eq!(e.span(), Some(0..7));
+        assert_eq!(e.suggestion(), Some("uint256[]"));
+
+        let e = TypeSpecifier::parse("(bool,(bytes33,address))")
+            .unwrap()
+            .try_basic_solidity()
+            .unwrap_err();
+        assert_eq!(e.span(), Some(7..14));
+        assert_eq!(e.suggestion(), Some("(bool,(bytes32,address))"));
+        assert_eq!(
+            e.to_string(),
+            "\
+invalid size for type: bytes33
+  (bool,(bytes33,address))
+         ^^^^^^^
+help: did you mean `(bool,(bytes32,address))`?"
+        );
+    }
+
+    #[test]
+    fn typos() {
+        let suggest = |s| {
+            TypeSpecifier::parse(s)
+                .unwrap()
+                .try_basic_solidity()
+                .unwrap_err()
+                .suggestion()
+                .map(ToString::to_string)
+        };
+        assert_eq!(suggest("byte").as_deref(), Some("bytes1"));
+        assert_eq!(suggest("byte[]").as_deref(), Some("bytes1[]"));
+        assert_eq!(suggest("unit256").as_deref(), Some("uint256"));
+        assert_eq!(suggest("uint7").as_deref(), Some("uint8"));
+        assert_eq!(suggest("int1024").as_deref(), Some("int256"));
+        assert_eq!(suggest("bytes0").as_deref(), Some("bytes1"));
+        assert_eq!(suggest("adress").as_deref(), Some("address"));
+        assert_eq!(suggest("MyStruct"), None);
+    }
+
+    #[test]
+    fn address_payable() {
+        let e = TypeSpecifier::parse("address payable[]").unwrap_err();
+        assert_eq!(e.span(), Some(8..15));
+        assert_eq!(e.suggestion(), Some("address[]"));
+
+        let e = ParameterSpecifier::parse("address payable to").unwrap_err();
+        assert_eq!(e.suggestion(), Some("address to"));
+    }
+
+    #[test]
+    fn external_cause() {
+        let e = TypeSpecifier::parse("uint256[0]").unwrap_err();
+        if cfg!(feature = "std") {
+            assert!(e.message().contains("number would be zero for non-zero type"), "{e:?}");
+        }
+        assert_eq!(e.span(), Some(9..10));
     }
 }
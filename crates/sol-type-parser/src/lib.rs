@@ -23,6 +23,13 @@ extern crate alloc;
 mod error;
 pub use error::{Error, Result};
 
+/// Constant integer expressions.
+mod const_expr;
+pub use const_expr::{eval_array_sizes, eval_const_expr};
+
+/// Suggestions for common mistakes.
+mod suggest;
+
 /// Solidity ident rules.
 mod ident;
 pub use ident::{is_id_continue, is_id_start, is_valid_identifier, IDENT_REGEX};
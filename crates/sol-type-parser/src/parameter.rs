@@ -1,13 +1,13 @@
 use crate::{
+    suggest,
+    type_spec::array_sizes,
     utils::{opt_ws_ident, spanned, tuple_parser},
-    Error, Result, TypeSpecifier,
+    Error, Result, TupleSpecifier, TypeSpecifier, TypeStem,
 };
 use alloc::vec::Vec;
 use core::fmt;
 use winnow::{combinator::trace, PResult, Parser};
 
-// TODO: Parse visibility and state mutability
-
 /// Represents a function parameter.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ParameterSpecifier<'a> {
@@ -21,6 +21,8 @@ pub struct ParameterSpecifier<'a> {
     pub indexed: bool,
     /// The name of the parameter.
     pub name: Option<&'a str>,
+    /// The components of a tuple parameter. See [`components`](Self::components).
+    components: Vec<ParameterSpecifier<'a>>,
 }
 
 impl<'a> TryFrom<&'a str> for ParameterSpecifier<'a> {
@@ -36,7 +38,11 @@ impl<'a> ParameterSpecifier<'a> {
     /// Parse a parameter from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(|e| {
+            Error::from_parse_error(e).or_suggest(|| {
+                suggest::input(input, false, |s| ParameterSpecifier::parser.parse(s).is_ok())
+            })
+        })
     }
 
     /// [`winnow`] parser for this type.
@@ -44,7 +50,11 @@ impl<'a> ParameterSpecifier<'a> {
         trace(
             "ParameterSpecifier",
             spanned(|input: &mut &'a str| {
-                let ty = TypeSpecifier::parser(input)?;
+                let (ty, components) = if input.starts_with('(') || input.starts_with("tuple(") {
+                    Self::tuple_parser(input)?
+                } else {
+                    (TypeSpecifier::parser(input)?, Vec::new())
+                };
                 let mut name = opt_ws_ident(input)?;
 
                 let mut storage = None;
@@ -63,16 +73,60 @@ impl<'a> ParameterSpecifier<'a> {
                     indexed = true;
                     name = opt_ws_ident(input)?;
                 }
-                Ok((ty, storage, indexed, name))
+                Ok((ty, storage, indexed, name, components))
             }),
         )
         .parse_next(input)
-        .map(|(span, (ty, storage, indexed, name))| Self {
+        .map(|(span, (ty, storage, indexed, name, components))| Self {
             span,
             ty,
             storage,
             indexed,
             name,
+            components,
+        })
+    }
+
+    /// Returns the components of a tuple parameter, which may be named, as in
+    /// `tuple(uint256 a, bool b)[] c`. Empty for other types.
+    ///
+    /// The types of the components are also available in [`ty`](Self::ty),
+    /// whose span includes the component names in this case.
+    #[inline]
+    pub fn components(&self) -> &[ParameterSpecifier<'a>] {
+        &self.components
+    }
+
+    /// Consumes the parameter, returning its tuple components.
+    ///
+    /// See [`components`](Self::components) for more details.
+    #[inline]
+    pub fn into_components(self) -> Vec<ParameterSpecifier<'a>> {
+        self.components
+    }
+
+    /// Parses a tuple type whose components may be named, with optional array
+    /// suffixes.
+    fn tuple_parser(input: &mut &'a str) -> PResult<(TypeSpecifier<'a>, Vec<Self>)> {
+        trace(
+            "tuple_parser",
+            spanned(|input: &mut &'a str| {
+                let (tuple_span, components) = spanned(|input: &mut &'a str| {
+                    if let Some(stripped) = input.strip_prefix("tuple") {
+                        *input = stripped;
+                    }
+                    tuple_parser::<_, Vec<Self>, _>(Self::parser).parse_next(input)
+                })
+                .parse_next(input)?;
+                let sizes = array_sizes(input)?;
+                Ok((tuple_span, components, sizes))
+            }),
+        )
+        .parse_next(input)
+        .map(|(span, (tuple_span, components, sizes))| {
+            let types = components.iter().map(|c| c.ty.clone()).collect();
+            let stem = TypeStem::Tuple(TupleSpecifier { span: tuple_span, types });
+            (TypeSpecifier { span, stem, sizes }, components)
         })
     }
 }
@@ -99,7 +153,11 @@ impl<'a> Parameters<'a> {
     /// Parse a parameter list from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(|e| {
+            Error::from_parse_error(e).or_suggest(|| {
+                suggest::input(input, false, |s| Parameters::parser.parse(s).is_ok())
+            })
+        })
     }
 
     /// [`winnow`] parser for this type.
@@ -162,6 +220,7 @@ impl Storage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::num::NonZeroUsize;
 
     #[test]
     fn parse_param() {
@@ -173,6 +232,7 @@ mod tests {
                 storage: None,
                 indexed: false,
                 name: Some("name"),
+                components: vec![],
             })
         );
 
@@ -184,6 +244,7 @@ mod tests {
                 storage: None,
                 indexed: true,
                 name: Some("name"),
+                components: vec![],
             })
         );
 
@@ -195,6 +256,7 @@ mod tests {
                 storage: None,
                 indexed: true,
                 name: Some("name"),
+                components: vec![],
             })
         );
 
@@ -220,6 +282,7 @@ mod tests {
                     storage: None,
                     indexed: false,
                     name: None,
+                    components: vec![],
                 }]
             })
         );
@@ -234,6 +297,7 @@ mod tests {
                         storage: None,
                         indexed: false,
                         name: Some("a"),
+                        components: vec![],
                     },
                     ParameterSpecifier {
                         span: "bool b",
@@ -241,12 +305,38 @@ mod tests {
                         storage: None,
                         indexed: false,
                         name: Some("b"),
+                        components: vec![],
                     }
                 ]
             })
         );
     }
 
+    #[test]
+    fn parse_named_tuple() {
+        let param = ParameterSpecifier::parse("tuple(uint256 a, (bool b, bytes) c)[2] d").unwrap();
+        assert_eq!(param.name, Some("d"));
+        assert_eq!(param.ty.span(), "tuple(uint256 a, (bool b, bytes) c)[2]");
+        assert_eq!(param.ty.sizes, [NonZeroUsize::new(2)]);
+        let TypeStem::Tuple(tuple) = &param.ty.stem else { panic!("{:?}", param.ty.stem) };
+        assert_eq!(tuple.types.len(), 2);
+
+        let names = param.components().iter().map(|c| c.name).collect::<Vec<_>>();
+        assert_eq!(names, [Some("a"), Some("c")]);
+        let inner = &param.components()[1];
+        assert_eq!(inner.ty.span(), "(bool b, bytes)");
+        assert_eq!(inner.components()[0].name, Some("b"));
+        assert_eq!(inner.components()[1].name, None);
+        assert_eq!(inner.components()[1].ty, TypeSpecifier::parse("bytes").unwrap());
+
+        // unnamed components are also returned
+        let unnamed = ParameterSpecifier::parse("(uint256,(bool,bytes))[2] d").unwrap();
+        assert_eq!(unnamed.name, Some("d"));
+        assert_eq!(unnamed.components().len(), 2);
+        assert!(unnamed.components().iter().all(|c| c.name.is_none()));
+        assert_eq!(unnamed.components()[1].components().len(), 2);
+    }
+
     #[test]
     fn parse_storage() {
         assert_eq!(
@@ -256,7 +346,8 @@ mod tests {
                 ty: TypeSpecifier::parse("foo").unwrap(),
                 storage: None,
                 indexed: false,
-                name: Some("storag")
+                name: Some("storag"),
+                components: vec![],
             })
         );
         assert_eq!(
@@ -266,7 +357,8 @@ mod tests {
                 ty: TypeSpecifier::parse("foo").unwrap(),
                 storage: Some(Storage::Storage),
                 indexed: false,
-                name: None
+                name: None,
+                components: vec![],
             })
         );
         assert_eq!(
@@ -276,7 +368,8 @@ mod tests {
                 ty: TypeSpecifier::parse("foo").unwrap(),
                 storage: Some(Storage::Storage),
                 indexed: false,
-                name: "bar".into()
+                name: "bar".into(),
+                components: vec![],
             })
         );
         assert_eq!(
@@ -286,7 +379,8 @@ mod tests {
                 ty: TypeSpecifier::parse("foo").unwrap(),
                 storage: Some(Storage::Memory),
                 indexed: false,
-                name: "bar".into()
+                name: "bar".into(),
+                components: vec![],
             })
         );
         assert_eq!(
@@ -296,7 +390,8 @@ mod tests {
                 ty: TypeSpecifier::parse("foo").unwrap(),
                 storage: Some(Storage::Calldata),
                 indexed: false,
-                name: "bar".into()
+                name: "bar".into(),
+                components: vec![],
             })
         );
         ParameterSpecifier::parse("foo storag bar").unwrap_err();
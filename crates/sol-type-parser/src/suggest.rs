@@ -0,0 +1,105 @@
+use alloc::{string::String, vec::Vec};
+
+/// Elementary type names which are commonly misspelled.
+const ELEMENTARY: &[&str] = &["address", "bool", "string", "bytes", "uint", "int", "function"];
+
+/// Returns a corrected version of an invalid root type name, if one can be
+/// guessed.
+///
+/// Handles the `byte` alias, misspelled elementary type names like `unit256`
+/// and invalid sizes like `uint265` or `bytes33`.
+pub(crate) fn root_type(name: &str) -> Option<String> {
+    match name {
+        "byte" => return Some("bytes1".into()),
+        "boolean" => return Some("bool".into()),
+        _ => {}
+    }
+
+    let (word, digits) =
+        name.split_at(name.find(|c: char| c.is_ascii_digit()).unwrap_or(name.len()));
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let word = if ELEMENTARY.contains(&word) {
+        word
+    } else {
+        let max = if word.len() <= 4 { 1 } else { 2 };
+        ELEMENTARY
+            .iter()
+            .map(|&ty| (ty, distance(word, ty)))
+            .filter(|&(_, d)| d <= max)
+            .min_by_key(|&(_, d)| d)?
+            .0
+    };
+
+    let fixed = match word {
+        "uint" | "int" if !digits.is_empty() => format!("{word}{}", fix_size(digits, 8, 256, 8)),
+        "bytes" if !digits.is_empty() => format!("bytes{}", fix_size(digits, 1, 32, 1)),
+        _ => word.into(),
+    };
+    (fixed != name).then_some(fixed)
+}
+
+/// Returns a corrected version of `input` for which `check` succeeds.
+///
+/// Tries to drop `payable` from `address payable`, which is not an ABI type,
+/// and, if `strip_whitespace` is set, to remove all whitespace.
+pub(crate) fn input(
+    input: &str,
+    strip_whitespace: bool,
+    check: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let mut candidates = Vec::with_capacity(2);
+    if input.contains("address payable") {
+        candidates.push(input.replace("address payable", "address"));
+    }
+    if strip_whitespace && input.contains(char::is_whitespace) {
+        candidates.push(input.split_whitespace().collect());
+    }
+    candidates.into_iter().find(|s| check(s))
+}
+
+/// Returns the valid size closest to `digits`. Prefers fixing a transposition
+/// of two adjacent digits, as in `uint265`.
+fn fix_size(digits: &str, min: usize, max: usize, step: usize) -> usize {
+    let valid = |n: usize| (min..=max).contains(&n) && n % step == 0;
+    let n = digits.parse::<usize>().unwrap_or(usize::MAX);
+    if valid(n) {
+        return n;
+    }
+
+    let mut bytes = digits.as_bytes().to_vec();
+    for i in 1..bytes.len() {
+        bytes.swap(i - 1, i);
+        let swapped = core::str::from_utf8(&bytes).ok().and_then(|s| s.parse().ok());
+        if let Some(n) = swapped.filter(|&n| valid(n)) {
+            return n;
+        }
+        bytes.swap(i - 1, i);
+    }
+
+    (n.saturating_add(step / 2) / step * step).clamp(min, max)
+}
+
+/// Optimal string alignment distance between two ASCII strings.
+fn distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
@@ -1,4 +1,5 @@
 use crate::{
+    suggest,
     utils::{spanned, tuple_parser},
     Error, Result, TypeSpecifier,
 };
@@ -52,7 +53,11 @@ impl<'a> TupleSpecifier<'a> {
     /// Parse a tuple specifier from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(|e| {
+            Error::from_parse_error(e).or_suggest(|| {
+                suggest::input(input, true, |s| TupleSpecifier::parser.parse(s).is_ok())
+            })
+        })
     }
 
     /// [`winnow`] parser for this type.
@@ -79,7 +84,9 @@ impl<'a> TupleSpecifier<'a> {
     /// Returns true if the type is a basic Solidity type.
     #[inline]
     pub fn try_basic_solidity(&self) -> Result<()> {
-        self.types.iter().try_for_each(TypeSpecifier::try_basic_solidity)
+        self.types.iter().try_for_each(|ty| {
+            ty.try_basic_solidity().map_err(|e| e.relocate(ty.span(), self.span))
+        })
     }
 }
 
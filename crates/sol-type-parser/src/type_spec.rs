@@ -1,4 +1,5 @@
 use crate::{
+    suggest,
     utils::{spanned, str_parser},
     Error, Result, TypeStem,
 };
@@ -88,7 +89,10 @@ impl<'a> TypeSpecifier<'a> {
     /// Parse a type specifier from a string.
     #[inline]
     pub fn parse(s: &'a str) -> Result<Self> {
-        Self::parser.parse(s).map_err(Error::parser)
+        Self::parser.parse(s).map_err(|e| {
+            Error::from_parse_error(e)
+                .or_suggest(|| suggest::input(s, true, |s| TypeSpecifier::parser.parse(s).is_ok()))
+        })
     }
 
     /// [`winnow`] parser for this type.
@@ -97,15 +101,7 @@ impl<'a> TypeSpecifier<'a> {
             "TypeSpecifier",
             spanned(|input: &mut &'a str| {
                 let stem = TypeStem::parser(input)?;
-                let sizes = if input.starts_with('[') {
-                    repeat(
-                        1..,
-                        delimited(str_parser("["), array_size_parser, cut_err(str_parser("]"))),
-                    )
-                    .parse_next(input)?
-                } else {
-                    Vec::new()
-                };
+                let sizes = array_sizes(input)?;
                 Ok((stem, sizes))
             }),
         )
@@ -128,7 +124,7 @@ impl<'a> TypeSpecifier<'a> {
     /// Returns true if the type is a basic Solidity type.
     #[inline]
     pub fn try_basic_solidity(&self) -> Result<()> {
-        self.stem.try_basic_solidity()
+        self.stem.try_basic_solidity().map_err(|e| e.relocate(self.stem.span(), self.span))
     }
 
     /// Returns true if this type is an array.
@@ -138,6 +134,15 @@ impl<'a> TypeSpecifier<'a> {
     }
 }
 
+/// Parses zero or more array suffixes: `[]` or `[N]`.
+pub(crate) fn array_sizes(input: &mut &str) -> PResult<Vec<Option<NonZeroUsize>>> {
+    if !input.starts_with('[') {
+        return Ok(Vec::new());
+    }
+    repeat(1.., delimited(str_parser("["), array_size_parser, cut_err(str_parser("]"))))
+        .parse_next(input)
+}
+
 fn array_size_parser(input: &mut &str) -> PResult<Option<NonZeroUsize>> {
     let digits = digit0(input)?;
     if digits.is_empty() {
@@ -304,9 +309,8 @@ mod test {
 
     #[test]
     fn not_basic_solidity() {
-        assert_eq!(
-            TypeSpecifier::parse("MyStruct").unwrap().try_basic_solidity(),
-            Err(Error::invalid_type_string("MyStruct"))
-        );
+        let e = TypeSpecifier::parse("MyStruct").unwrap().try_basic_solidity().unwrap_err();
+        assert_eq!(e.message(), Error::invalid_type_string("MyStruct").message());
+        assert_eq!(e.span(), Some(0..8));
     }
 }
@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use crate::{Error, ParameterSpecifier, Result, RootType};
+use crate::{suggest, Error, ParameterSpecifier, Result, RootType};
 use alloc::{string::String, vec::Vec};
 use core::{slice, str};
 use winnow::{
@@ -109,17 +109,25 @@ pub fn parse_item<'a>(s: &mut &'a str) -> Result<&'a str> {
     trace("item", terminated(identifier, space0)).parse_next(s).map_err(Error::parser)
 }
 
-/// Returns `(name, inputs, outputs, anonymous)`.
+/// `(name, inputs, outputs, state_mutability, anonymous)`
+#[doc(hidden)]
+pub type ParsedSignature<'a, T> = (String, Vec<T>, Vec<T>, Option<&'a str>, bool);
+
+/// Returns `(name, inputs, outputs, state_mutability, anonymous)`.
+///
+/// The state mutability is returned as written, if any. The `external` and
+/// `public` visibility specifiers are accepted and ignored.
 #[doc(hidden)]
 pub fn parse_signature<'a, const OUT: bool, F: Fn(ParameterSpecifier<'a>) -> T, T>(
     s: &'a str,
     f: F,
-) -> Result<(String, Vec<T>, Vec<T>, bool)> {
+) -> Result<ParsedSignature<'a, T>> {
     trace(
         "signature",
         (
             RootType::parser.map(|x| x.span().into()),
             preceded(space0, tuple_parser(ParameterSpecifier::parser.map(&f))),
+            modifiers,
             |i: &mut _| {
                 if OUT {
                     preceded(
@@ -136,5 +144,36 @@ pub fn parse_signature<'a, const OUT: bool, F: Fn(ParameterSpecifier<'a>) -> T,
         ),
     )
     .parse(s)
-    .map_err(Error::parser)
+    .map(|(name, inputs, state_mutability, outputs, anonymous)| {
+        (name, inputs, outputs, state_mutability, anonymous)
+    })
+    .map_err(|e| {
+        Error::from_parse_error(e).or_suggest(|| {
+            suggest::input(s, false, |s| {
+                parse_signature::<OUT, _, _>(s, drop::<ParameterSpecifier<'_>>).is_ok()
+            })
+        })
+    })
+}
+
+/// Parses the visibility and state mutability specifiers of a function,
+/// returning the state mutability, if any.
+fn modifiers<'a>(input: &mut &'a str) -> PResult<Option<&'a str>> {
+    let mut state_mutability = None;
+    loop {
+        let checkpoint = *input;
+        let modifier = preceded(space0, opt(identifier)).parse_next(input)?;
+        match modifier {
+            Some("external" | "public") => {}
+            Some(kw @ ("pure" | "view" | "constant" | "payable" | "nonpayable"))
+                if state_mutability.is_none() =>
+            {
+                state_mutability = Some(kw)
+            }
+            _ => {
+                *input = checkpoint;
+                return Ok(state_mutability);
+            }
+        }
+    }
 }
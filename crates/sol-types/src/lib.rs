@@ -180,11 +180,14 @@ pub use ext::JsonAbiExt;
 
 mod impl_core;
 
+pub mod proxy;
+
 mod types;
 pub use types::{
     data_type as sol_data, decode_revert_reason, ContractError, EventTopic, GenericContractError,
     GenericRevertReason, Panic, PanicKind, Revert, Selectors, SolCall, SolConstructor, SolEnum,
-    SolError, SolEvent, SolEventInterface, SolInterface, SolStruct, SolType, SolValue, TopicList,
+    SolError, SolEvent, SolEventInterface, SolInterface, SolStorage, SolStruct, SolType, SolValue,
+    TopicList,
 };
 
 pub mod utils;
@@ -0,0 +1,201 @@
+//! [EIP-2535] Diamond interfaces.
+//!
+//! [EIP-2535]: https://eips.ethereum.org/EIPS/eip-2535
+
+// The call, return and event types generated by `sol!` have undocumented fields
+#![allow(missing_docs)]
+
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap},
+    vec::Vec,
+};
+use alloy_primitives::{Address, FixedBytes};
+
+crate::sol! {
+    #![sol(all_derives)]
+
+    /// The action of a [`FacetCut`].
+    enum FacetCutAction {
+        /// Add the selectors to the facet.
+        Add,
+        /// Replace the facet of the selectors.
+        Replace,
+        /// Remove the selectors.
+        Remove,
+    }
+
+    /// A change to the selectors of a facet.
+    struct FacetCut {
+        /// The facet address.
+        address facetAddress;
+        /// The action to perform.
+        FacetCutAction action;
+        /// The selectors to add, replace or remove.
+        bytes4[] functionSelectors;
+    }
+
+    /// A facet and its selectors, as returned by
+    /// [`IDiamondLoupe::facets`](IDiamondLoupe::facetsCall).
+    struct Facet {
+        /// The facet address.
+        address facetAddress;
+        /// The selectors routed to the facet.
+        bytes4[] functionSelectors;
+    }
+
+    /// The EIP-2535 `IDiamondCut` interface.
+    interface IDiamondCut {
+        /// Adds, replaces or removes facet selectors, then executes
+        /// `_calldata` on `_init` with `delegatecall`.
+        function diamondCut(FacetCut[] _diamondCut, address _init, bytes _calldata) external;
+
+        /// Emitted on every `diamondCut`.
+        event DiamondCut(FacetCut[] _diamondCut, address _init, bytes _calldata);
+    }
+
+    /// The EIP-2535 `IDiamondLoupe` interface.
+    interface IDiamondLoupe {
+        /// Returns all facets and their selectors.
+        function facets() external view returns (Facet[] facets_);
+
+        /// Returns the selectors supported by a facet.
+        function facetFunctionSelectors(address _facet) external view returns (bytes4[] facetFunctionSelectors_);
+
+        /// Returns all facet addresses.
+        function facetAddresses() external view returns (address[] facetAddresses_);
+
+        /// Returns the facet supporting a selector.
+        function facetAddress(bytes4 _functionSelector) external view returns (address facetAddress_);
+    }
+}
+
+/// Returns the facet each selector is routed to, as returned by
+/// [`IDiamondLoupe::facets`](IDiamondLoupe::facetsCall).
+///
+/// If a selector is listed under several facets, the last one wins.
+pub fn selector_map(facets: &[Facet]) -> BTreeMap<FixedBytes<4>, Address> {
+    facets
+        .iter()
+        .flat_map(|facet| {
+            facet.functionSelectors.iter().map(|&selector| (selector, facet.facetAddress))
+        })
+        .collect()
+}
+
+/// Applies a list of [`FacetCut`]s to a selector map, as `diamondCut` would.
+///
+/// Returns the selectors whose cut was invalid: added while already present,
+/// or replaced or removed while absent. These are skipped.
+pub fn apply_cuts(
+    selectors: &mut BTreeMap<FixedBytes<4>, Address>,
+    cuts: &[FacetCut],
+) -> Vec<FixedBytes<4>> {
+    let mut invalid = Vec::new();
+    for cut in cuts {
+        for &selector in &cut.functionSelectors {
+            let valid = match cut.action {
+                FacetCutAction::Add => match selectors.entry(selector) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(cut.facetAddress);
+                        true
+                    }
+                    Entry::Occupied(_) => false,
+                },
+                FacetCutAction::Replace => {
+                    selectors.get_mut(&selector).map(|facet| *facet = cut.facetAddress).is_some()
+                }
+                FacetCutAction::Remove => selectors.remove(&selector).is_some(),
+                // out-of-range action
+                _ => false,
+            };
+            if !valid {
+                invalid.push(selector);
+            }
+        }
+    }
+    invalid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SolCall, SolEvent};
+    use alloy_primitives::{address, fixed_bytes};
+
+    const FACET_A: Address = address!("00000000000000000000000000000000000000aa");
+    const FACET_B: Address = address!("00000000000000000000000000000000000000bb");
+
+    #[test]
+    fn selectors() {
+        assert_eq!(
+            IDiamondCut::diamondCutCall::SIGNATURE,
+            "diamondCut((address,uint8,bytes4[])[],address,bytes)"
+        );
+        assert_eq!(IDiamondCut::diamondCutCall::SELECTOR, [0x1f, 0x93, 0x1c, 0x1c]);
+        assert_eq!(IDiamondLoupe::facetsCall::SELECTOR, [0x7a, 0x0e, 0xd6, 0x27]);
+        assert_eq!(IDiamondLoupe::facetAddressCall::SELECTOR, [0xcd, 0xff, 0xac, 0xc6]);
+        assert_eq!(
+            IDiamondCut::DiamondCut::SIGNATURE,
+            "DiamondCut((address,uint8,bytes4[])[],address,bytes)"
+        );
+    }
+
+    #[test]
+    fn facets_roundtrip() {
+        let facets = vec![
+            Facet { facetAddress: FACET_A, functionSelectors: vec![fixed_bytes!("01020304")] },
+            Facet {
+                facetAddress: FACET_B,
+                functionSelectors: vec![fixed_bytes!("05060708"), fixed_bytes!("090a0b0c")],
+            },
+        ];
+        let encoded = IDiamondLoupe::facetsCall::abi_encode_returns(&(facets.clone(),));
+        let decoded = IDiamondLoupe::facetsCall::abi_decode_returns(&encoded, true).unwrap();
+        assert_eq!(decoded.facets_, facets);
+
+        let map = selector_map(&decoded.facets_);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&fixed_bytes!("090a0b0c")], FACET_B);
+    }
+
+    #[test]
+    fn cuts() {
+        let call = IDiamondCut::diamondCutCall {
+            _diamondCut: vec![
+                FacetCut {
+                    facetAddress: FACET_B,
+                    action: FacetCutAction::Replace,
+                    functionSelectors: vec![fixed_bytes!("01020304")],
+                },
+                FacetCut {
+                    facetAddress: Address::ZERO,
+                    action: FacetCutAction::Remove,
+                    functionSelectors: vec![fixed_bytes!("05060708"), fixed_bytes!("ffffffff")],
+                },
+                FacetCut {
+                    facetAddress: FACET_A,
+                    action: FacetCutAction::Add,
+                    functionSelectors: vec![fixed_bytes!("0d0e0f10"), fixed_bytes!("01020304")],
+                },
+            ],
+            _init: Address::ZERO,
+            _calldata: Vec::new(),
+        };
+        let decoded = IDiamondCut::diamondCutCall::abi_decode(&call.abi_encode(), true).unwrap();
+        assert_eq!(decoded, call);
+
+        let mut selectors = BTreeMap::from([
+            (fixed_bytes!("01020304"), FACET_A),
+            (fixed_bytes!("05060708"), FACET_A),
+        ]);
+        let invalid = apply_cuts(&mut selectors, &decoded._diamondCut);
+        assert_eq!(invalid, [fixed_bytes!("ffffffff"), fixed_bytes!("01020304")]);
+        assert_eq!(
+            selectors,
+            BTreeMap::from([
+                (fixed_bytes!("01020304"), FACET_B),
+                (fixed_bytes!("0d0e0f10"), FACET_A),
+            ])
+        );
+    }
+}
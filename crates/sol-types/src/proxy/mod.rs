@@ -0,0 +1,454 @@
+//! Proxy pattern detection.
+//!
+//! Recognizes the common ways a contract delegates its calls to another
+//! contract, using only its runtime bytecode and storage:
+//! - [EIP-1167] minimal proxies and [EIP-3448] MetaProxies, whose implementation address is
+//!   embedded in the bytecode;
+//! - Gnosis Safe proxies, which store their implementation in slot `0`;
+//! - [EIP-1967], [EIP-1822] and legacy OpenZeppelin proxies, which store their implementation in
+//!   well-known storage slots.
+//!
+//! [EIP-2535] Diamonds route each selector to a different facet. Their facets
+//! are not stored in a well-known slot, so the [`diamond`] module instead
+//! provides the types needed to encode and decode the Diamond interfaces.
+//!
+//! [EIP-1167]: https://eips.ethereum.org/EIPS/eip-1167
+//! [EIP-3448]: https://eips.ethereum.org/EIPS/eip-3448
+//! [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+//! [EIP-1822]: https://eips.ethereum.org/EIPS/eip-1822
+//! [EIP-2535]: https://eips.ethereum.org/EIPS/eip-2535
+
+use alloc::vec::Vec;
+use alloy_primitives::{b256, Address, Bytes, B256, U256};
+
+pub mod diamond;
+
+/// The [EIP-1967] implementation slot:
+/// `keccak256("eip1967.proxy.implementation") - 1`.
+///
+/// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+pub const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// The [EIP-1967] admin slot: `keccak256("eip1967.proxy.admin") - 1`.
+///
+/// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+pub const EIP1967_ADMIN_SLOT: B256 =
+    b256!("b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103");
+
+/// The [EIP-1967] beacon slot: `keccak256("eip1967.proxy.beacon") - 1`.
+///
+/// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+pub const EIP1967_BEACON_SLOT: B256 =
+    b256!("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+/// The [EIP-1967] rollback slot used by UUPS upgrade tests:
+/// `keccak256("eip1967.proxy.rollback") - 1`.
+///
+/// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+pub const EIP1967_ROLLBACK_SLOT: B256 =
+    b256!("4910fdfa16fed3260ed0e7147f7cc6da11a60208b5b9406d12a635614ffd9143");
+
+/// The [EIP-1822] implementation slot: `keccak256("PROXIABLE")`.
+///
+/// [EIP-1822]: https://eips.ethereum.org/EIPS/eip-1822
+pub const EIP1822_PROXIABLE_SLOT: B256 =
+    b256!("c5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bcf7");
+
+/// The legacy OpenZeppelin (ZeppelinOS) implementation slot:
+/// `keccak256("org.zeppelinos.proxy.implementation")`.
+pub const OZ_IMPLEMENTATION_SLOT: B256 =
+    b256!("7050c9e0f4ca769c69bd3a8ef740bc37934f8e2c036e5a723fd8ee048ed3f8c3");
+
+/// The legacy OpenZeppelin (ZeppelinOS) admin slot:
+/// `keccak256("org.zeppelinos.proxy.admin")`.
+pub const OZ_ADMIN_SLOT: B256 =
+    b256!("10d6a54a4754c8869d6886b5f5d7fbfa5b4522237ea5c60d11bc4e7a1ff9390b");
+
+/// The slot in which Gnosis Safe proxies store their implementation
+/// (`masterCopy` or `singleton`).
+pub const GNOSIS_SAFE_IMPLEMENTATION_SLOT: B256 = B256::ZERO;
+
+/// The [EIP-1167] runtime code before the implementation address, excluding
+/// the final `PUSH20` opcode.
+const EIP1167_PREFIX: [u8; 9] = [0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x36, 0x3d];
+
+/// The [EIP-1167] runtime code after the implementation address, excluding the
+/// final `PUSH1 <jumpdest> JUMPI REVERT JUMPDEST RETURN`.
+const EIP1167_SUFFIX: [u8; 9] = [0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91];
+
+/// The [EIP-3448] runtime code before the implementation address.
+const EIP3448_PREFIX: [u8; 21] = [
+    0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x3d, 0x60, 0x36, 0x80, 0x38, 0x03, 0x80, 0x91, 0x36,
+    0x39, 0x36, 0x01, 0x3d, 0x73,
+];
+
+/// The [EIP-3448] runtime code after the implementation address.
+const EIP3448_SUFFIX: [u8; 13] =
+    [0x5a, 0xf4, 0x3d, 0x3d, 0x93, 0x80, 0x3e, 0x60, 0x34, 0x57, 0xfd, 0x5b, 0xf3];
+
+/// The length of the [EIP-3448] runtime code, excluding the metadata.
+const EIP3448_LEN: usize = EIP3448_PREFIX.len() + 20 + EIP3448_SUFFIX.len();
+
+/// The start of the Gnosis Safe proxy runtime code, which loads the
+/// implementation from slot `0` and answers `masterCopy()` (`0xa619486e`)
+/// directly.
+const GNOSIS_SAFE_PREFIX: [u8; 38] = [
+    0x60, 0x80, 0x60, 0x40, 0x52, 0x73, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x60, 0x00, 0x54, 0x16, 0x7f, 0xa6,
+    0x19, 0x48, 0x6e, 0x00, 0x00, 0x00,
+];
+
+/// A recognized proxy, along with where its calls are delegated to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Proxy {
+    /// An [EIP-1167] minimal proxy.
+    ///
+    /// [EIP-1167]: https://eips.ethereum.org/EIPS/eip-1167
+    Minimal {
+        /// The implementation address.
+        implementation: Address,
+    },
+    /// An [EIP-3448] MetaProxy.
+    ///
+    /// [EIP-3448]: https://eips.ethereum.org/EIPS/eip-3448
+    Meta {
+        /// The implementation address.
+        implementation: Address,
+        /// The metadata appended to the proxy code.
+        metadata: Bytes,
+    },
+    /// A Gnosis Safe proxy.
+    GnosisSafe {
+        /// The implementation (`masterCopy`) address.
+        implementation: Address,
+    },
+    /// An [EIP-1967] proxy with an implementation slot.
+    ///
+    /// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+    Eip1967 {
+        /// The implementation address.
+        implementation: Address,
+    },
+    /// An [EIP-1967] beacon proxy.
+    ///
+    /// The implementation is returned by the beacon's `implementation()`
+    /// function, which cannot be resolved offline.
+    ///
+    /// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+    Eip1967Beacon {
+        /// The beacon address.
+        beacon: Address,
+    },
+    /// An [EIP-1822] universal upgradeable proxy.
+    ///
+    /// [EIP-1822]: https://eips.ethereum.org/EIPS/eip-1822
+    Eip1822 {
+        /// The implementation address.
+        implementation: Address,
+    },
+    /// A legacy OpenZeppelin (ZeppelinOS) proxy.
+    OpenZeppelin {
+        /// The implementation address.
+        implementation: Address,
+    },
+}
+
+impl Proxy {
+    /// Detects a proxy from its runtime bytecode and storage, using `sload` to
+    /// read storage slots.
+    ///
+    /// Proxies recognizable from their bytecode alone are detected first.
+    /// Otherwise, the well-known implementation slots are checked in order:
+    /// EIP-1967 implementation, EIP-1967 beacon, EIP-1822, and legacy
+    /// OpenZeppelin.
+    pub fn detect<F: FnMut(B256) -> B256>(code: &[u8], mut sload: F) -> Option<Self> {
+        if let Some(proxy) = Self::from_bytecode(code) {
+            return Some(proxy);
+        }
+        if is_gnosis_safe_proxy(code) {
+            let implementation = word_to_address(sload(GNOSIS_SAFE_IMPLEMENTATION_SLOT))?;
+            return Some(Self::GnosisSafe { implementation });
+        }
+        Self::from_storage(sload)
+    }
+
+    /// Detects a proxy whose implementation is embedded in its runtime
+    /// bytecode: [EIP-1167](Self::Minimal) and [EIP-3448](Self::Meta).
+    pub fn from_bytecode(code: &[u8]) -> Option<Self> {
+        if let Some(implementation) = eip1167_implementation(code) {
+            return Some(Self::Minimal { implementation });
+        }
+        eip3448_implementation(code).map(|(implementation, metadata)| Self::Meta {
+            implementation,
+            metadata: Bytes::copy_from_slice(metadata),
+        })
+    }
+
+    /// Detects a proxy from the well-known implementation slots, using `sload`
+    /// to read storage slots.
+    ///
+    /// A slot is considered set if it contains a non-zero address.
+    pub fn from_storage<F: FnMut(B256) -> B256>(mut sload: F) -> Option<Self> {
+        let mut read = |slot| word_to_address(sload(slot));
+        if let Some(implementation) = read(EIP1967_IMPLEMENTATION_SLOT) {
+            Some(Self::Eip1967 { implementation })
+        } else if let Some(beacon) = read(EIP1967_BEACON_SLOT) {
+            Some(Self::Eip1967Beacon { beacon })
+        } else if let Some(implementation) = read(EIP1822_PROXIABLE_SLOT) {
+            Some(Self::Eip1822 { implementation })
+        } else {
+            read(OZ_IMPLEMENTATION_SLOT).map(|implementation| Self::OpenZeppelin { implementation })
+        }
+    }
+
+    /// Returns the implementation address, if it is known.
+    ///
+    /// This is `None` only for [beacon proxies](Self::Eip1967Beacon).
+    #[inline]
+    pub const fn implementation(&self) -> Option<Address> {
+        match *self {
+            Self::Minimal { implementation }
+            | Self::Meta { implementation, .. }
+            | Self::GnosisSafe { implementation }
+            | Self::Eip1967 { implementation }
+            | Self::Eip1822 { implementation }
+            | Self::OpenZeppelin { implementation } => Some(implementation),
+            Self::Eip1967Beacon { .. } => None,
+        }
+    }
+
+    /// Returns the storage slot the implementation or beacon address was read
+    /// from, or `None` if it is embedded in the bytecode.
+    #[inline]
+    pub const fn slot(&self) -> Option<B256> {
+        match self {
+            Self::Minimal { .. } | Self::Meta { .. } => None,
+            Self::GnosisSafe { .. } => Some(GNOSIS_SAFE_IMPLEMENTATION_SLOT),
+            Self::Eip1967 { .. } => Some(EIP1967_IMPLEMENTATION_SLOT),
+            Self::Eip1967Beacon { .. } => Some(EIP1967_BEACON_SLOT),
+            Self::Eip1822 { .. } => Some(EIP1822_PROXIABLE_SLOT),
+            Self::OpenZeppelin { .. } => Some(OZ_IMPLEMENTATION_SLOT),
+        }
+    }
+}
+
+/// Returns the implementation address of an [EIP-1167] minimal proxy's runtime
+/// code.
+///
+/// This also recognizes the shorter variants which push an implementation
+/// address with leading zero bytes using a smaller `PUSH` opcode.
+///
+/// [EIP-1167]: https://eips.ethereum.org/EIPS/eip-1167
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, hex};
+/// use alloy_sol_types::proxy::eip1167_implementation;
+///
+/// let code = hex!("363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3");
+/// assert_eq!(
+///     eip1167_implementation(&code),
+///     Some(address!("bebebebebebebebebebebebebebebebebebebebe"))
+/// );
+/// ```
+pub fn eip1167_implementation(code: &[u8]) -> Option<Address> {
+    let rest = code.strip_prefix(&EIP1167_PREFIX[..])?;
+    let (&push, rest) = rest.split_first()?;
+    let n = push.checked_sub(0x5f).filter(|n| (1..=20).contains(n))? as usize;
+    if rest.len() < n {
+        return None;
+    }
+    let (address, rest) = rest.split_at(n);
+    let rest = rest.strip_prefix(&EIP1167_SUFFIX[..])?;
+    let jumpdest = (code.len() - 2) as u8;
+    if rest != [0x60, jumpdest, 0x57, 0xfd, 0x5b, 0xf3] {
+        return None;
+    }
+    let mut implementation = Address::ZERO;
+    implementation[20 - n..].copy_from_slice(address);
+    Some(implementation)
+}
+
+/// Returns the [EIP-1167] minimal proxy runtime code delegating to
+/// `implementation`.
+///
+/// [EIP-1167]: https://eips.ethereum.org/EIPS/eip-1167
+pub fn eip1167_code(implementation: Address) -> Vec<u8> {
+    let mut code = Vec::with_capacity(45);
+    code.extend_from_slice(&EIP1167_PREFIX);
+    code.push(0x73);
+    code.extend_from_slice(implementation.as_slice());
+    code.extend_from_slice(&EIP1167_SUFFIX);
+    code.extend_from_slice(&[0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3]);
+    code
+}
+
+/// Returns the implementation address and metadata of an [EIP-3448]
+/// MetaProxy's runtime code.
+///
+/// [EIP-3448]: https://eips.ethereum.org/EIPS/eip-3448
+pub fn eip3448_implementation(code: &[u8]) -> Option<(Address, &[u8])> {
+    let rest = code.strip_prefix(&EIP3448_PREFIX[..])?;
+    if rest.len() < 20 {
+        return None;
+    }
+    let (address, rest) = rest.split_at(20);
+    let rest = rest.strip_prefix(&EIP3448_SUFFIX[..])?;
+    // metadata, followed by its length as a `uint256`
+    let len_start = rest.len().checked_sub(32)?;
+    let len = U256::try_from_be_slice(&rest[len_start..])?;
+    if len != U256::from(len_start) {
+        return None;
+    }
+    Some((Address::from_slice(address), &rest[..len_start]))
+}
+
+/// Returns the [EIP-3448] MetaProxy runtime code delegating to
+/// `implementation`, with the given metadata.
+///
+/// [EIP-3448]: https://eips.ethereum.org/EIPS/eip-3448
+pub fn eip3448_code(implementation: Address, metadata: &[u8]) -> Vec<u8> {
+    let mut code = Vec::with_capacity(EIP3448_LEN + metadata.len() + 32);
+    code.extend_from_slice(&EIP3448_PREFIX);
+    code.extend_from_slice(implementation.as_slice());
+    code.extend_from_slice(&EIP3448_SUFFIX);
+    code.extend_from_slice(metadata);
+    code.extend_from_slice(&U256::from(metadata.len()).to_be_bytes::<32>());
+    code
+}
+
+/// Returns `true` if the given runtime code is a Gnosis Safe proxy
+/// (`GnosisSafeProxy` or `SafeProxy`), which stores its implementation in
+/// [slot `0`](GNOSIS_SAFE_IMPLEMENTATION_SLOT).
+pub fn is_gnosis_safe_proxy(code: &[u8]) -> bool {
+    // PUSH32 masterCopy selector, left-aligned, followed by `PUSH1 0 CALLDATALOAD EQ`
+    code.starts_with(&GNOSIS_SAFE_PREFIX)
+        && code.get(GNOSIS_SAFE_PREFIX.len()..GNOSIS_SAFE_PREFIX.len() + 29).map_or(false, |rest| {
+            rest[..25].iter().all(|&b| b == 0) && rest[25..] == [0x60, 0x00, 0x35, 0x14]
+        })
+}
+
+/// Converts a storage word to a non-zero address, if its upper 12 bytes are
+/// zero.
+#[inline]
+fn word_to_address(word: B256) -> Option<Address> {
+    if word[..12].iter().any(|&b| b != 0) {
+        return None;
+    }
+    let address = Address::from_word(word);
+    (!address.is_zero()).then_some(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex, keccak256};
+
+    const IMPLEMENTATION: Address = address!("bebebebebebebebebebebebebebebebebebebebe");
+
+    #[test]
+    fn slots() {
+        let hash = |s: &str| keccak256(s.as_bytes());
+        let hash_minus_one = |s: &str| B256::from(U256::from_be_bytes(hash(s).0) - U256::from(1));
+
+        assert_eq!(EIP1967_IMPLEMENTATION_SLOT, hash_minus_one("eip1967.proxy.implementation"));
+        assert_eq!(EIP1967_ADMIN_SLOT, hash_minus_one("eip1967.proxy.admin"));
+        assert_eq!(EIP1967_BEACON_SLOT, hash_minus_one("eip1967.proxy.beacon"));
+        assert_eq!(EIP1967_ROLLBACK_SLOT, hash_minus_one("eip1967.proxy.rollback"));
+        assert_eq!(EIP1822_PROXIABLE_SLOT, hash("PROXIABLE"));
+        assert_eq!(OZ_IMPLEMENTATION_SLOT, hash("org.zeppelinos.proxy.implementation"));
+        assert_eq!(OZ_ADMIN_SLOT, hash("org.zeppelinos.proxy.admin"));
+    }
+
+    #[test]
+    fn eip1167() {
+        let code = eip1167_code(IMPLEMENTATION);
+        assert_eq!(
+            code,
+            hex!("363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3")
+        );
+        assert_eq!(
+            Proxy::from_bytecode(&code),
+            Some(Proxy::Minimal { implementation: IMPLEMENTATION })
+        );
+
+        // PUSH18 variant for an address with two leading zero bytes
+        let mut short = code.clone();
+        short.drain(10..12);
+        short[9] = 0x71;
+        short[38] = 0x29;
+        assert_eq!(
+            eip1167_implementation(&short),
+            Some(address!("0000bebebebebebebebebebebebebebebebebebe"))
+        );
+
+        assert_eq!(eip1167_implementation(&code[..44]), None);
+        let mut wrong_jump = code.clone();
+        wrong_jump[40] = 0x2c;
+        assert_eq!(eip1167_implementation(&wrong_jump), None);
+    }
+
+    #[test]
+    fn eip3448() {
+        let code = eip3448_code(IMPLEMENTATION, b"meta");
+        assert_eq!(code.len(), 54 + 4 + 32);
+        assert_eq!(&code[..54], hex!("363d3d373d3d3d3d60368038038091363936013d73bebebebebebebebebebebebebebebebebebebebe5af43d3d93803e603457fd5bf3"));
+        assert_eq!(eip3448_implementation(&code), Some((IMPLEMENTATION, &b"meta"[..])));
+        assert_eq!(
+            Proxy::detect(&code, |_| unreachable!()),
+            Some(Proxy::Meta {
+                implementation: IMPLEMENTATION,
+                metadata: Bytes::from_static(b"meta")
+            })
+        );
+
+        assert_eq!(eip3448_implementation(&code[..code.len() - 1]), None);
+        assert_eq!(eip3448_implementation(&code[..54]), None);
+    }
+
+    #[test]
+    fn gnosis_safe() {
+        // `GnosisSafeProxy` v1.3.0, without metadata
+        let code = hex!("608060405273ffffffffffffffffffffffffffffffffffffffff600054167fa619486e0000000000000000000000000000000000000000000000000000000060003514156050578060005260206000f35b3660008037600080366000845af43d6000803e60008114156070573d6000fd5b3d6000f3fe");
+        assert!(is_gnosis_safe_proxy(&code));
+        assert!(!is_gnosis_safe_proxy(&code[..60]));
+
+        let proxy = Proxy::detect(&code, |slot| {
+            assert_eq!(slot, GNOSIS_SAFE_IMPLEMENTATION_SLOT);
+            IMPLEMENTATION.into_word()
+        });
+        assert_eq!(proxy, Some(Proxy::GnosisSafe { implementation: IMPLEMENTATION }));
+        assert_eq!(proxy.unwrap().slot(), Some(B256::ZERO));
+    }
+
+    #[test]
+    fn storage() {
+        let detect = |slot: B256, word: B256| {
+            Proxy::from_storage(|s| if s == slot { word } else { B256::ZERO })
+        };
+        let word = IMPLEMENTATION.into_word();
+
+        assert_eq!(
+            detect(EIP1967_IMPLEMENTATION_SLOT, word),
+            Some(Proxy::Eip1967 { implementation: IMPLEMENTATION })
+        );
+        let beacon = detect(EIP1967_BEACON_SLOT, word).unwrap();
+        assert_eq!(beacon, Proxy::Eip1967Beacon { beacon: IMPLEMENTATION });
+        assert_eq!(beacon.implementation(), None);
+        assert_eq!(beacon.slot(), Some(EIP1967_BEACON_SLOT));
+        assert_eq!(
+            detect(EIP1822_PROXIABLE_SLOT, word),
+            Some(Proxy::Eip1822 { implementation: IMPLEMENTATION })
+        );
+        assert_eq!(
+            detect(OZ_IMPLEMENTATION_SLOT, word),
+            Some(Proxy::OpenZeppelin { implementation: IMPLEMENTATION })
+        );
+
+        assert_eq!(detect(EIP1967_ADMIN_SLOT, word), None);
+        assert_eq!(detect(EIP1967_IMPLEMENTATION_SLOT, B256::repeat_byte(0xff)), None);
+        assert_eq!(Proxy::detect(&[0x60, 0x00], |_| B256::ZERO), None);
+    }
+}
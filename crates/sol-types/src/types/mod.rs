@@ -18,6 +18,9 @@ pub use interface::{
     SolInterface,
 };
 
+mod storage;
+pub use storage::SolStorage;
+
 mod r#struct;
 pub use r#struct::SolStruct;
 
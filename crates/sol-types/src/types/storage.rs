@@ -0,0 +1,719 @@
+use crate::{
+    abi::token::WordToken, private::SolTypeValue, sol_data::*, utils, Error, Result, SolType, Word,
+};
+use alloc::{collections::BTreeMap, string::String as RustString, vec::Vec};
+use alloy_primitives::{keccak256, B256, U256};
+use core::ops::Range;
+
+/// The maximum length of a dynamic array, `bytes` or `string` which will be
+/// read from storage.
+const MAX_LENGTH: usize = 1 << 24;
+
+/// A Solidity type which can be laid out in contract storage.
+///
+/// This follows the [Solidity storage layout rules][ref]:
+/// - value types smaller than 32 bytes are packed right-aligned into the same slot as long as they
+///   fit, otherwise they start a new slot;
+/// - structs and fixed-size arrays always start a new slot, and so does the item following them;
+/// - elements of fixed-size and dynamic arrays are packed like the fields of a struct;
+/// - dynamic arrays store their length in their slot and their elements starting at
+///   `keccak256(slot)`;
+/// - `bytes` and `string` store data shorter than 32 bytes in their slot together with `length *
+///   2`, or `length * 2 + 1` in their slot and the data starting at `keccak256(slot)`.
+///
+/// [ref]: https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html
+///
+/// # Implementer's Guide
+///
+/// It should not be necessary to implement this trait manually. Instead, use
+/// the [`sol!`](crate::sol!) procedural macro to parse Solidity syntax into
+/// types that implement this trait.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Address, B256, U256};
+/// use alloy_sol_types::{sol, SolStorage};
+///
+/// sol! {
+///     struct Position {
+///         address owner;
+///         uint64 opened;
+///         bool active;
+///         uint256 size;
+///     }
+/// }
+///
+/// // `owner`, `opened` and `active` are packed into the first slot.
+/// assert_eq!(Position::STORAGE_SLOTS, 2);
+///
+/// let position = Position {
+///     owner: Address::repeat_byte(0x11),
+///     opened: 1,
+///     active: true,
+///     size: U256::from(5),
+/// };
+/// let storage = Position::storage_encode(&position, B256::ZERO).unwrap();
+/// assert_eq!(storage.len(), 2);
+///
+/// let decoded = Position::storage_decode(B256::ZERO, |slot| {
+///     storage.get(&slot).copied().unwrap_or_default()
+/// })
+/// .unwrap();
+/// assert_eq!(decoded.owner, position.owner);
+/// assert_eq!(decoded.size, position.size);
+/// ```
+pub trait SolStorage: SolType {
+    /// The number of bytes this type occupies in storage.
+    ///
+    /// This is the packed size for value types, such as `1` for `bool` or `20`
+    /// for `address`, and a multiple of 32 for all other types.
+    const STORAGE_BYTES: usize;
+
+    /// The number of slots this type occupies in storage.
+    const STORAGE_SLOTS: usize = utils::words_for_len(Self::STORAGE_BYTES);
+
+    /// Writes a value into `storage`, starting at the given slot and byte
+    /// offset from the right of the slot.
+    ///
+    /// Packed values are merged with the existing contents of their slot.
+    /// Slots which are not part of the value are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a packed value does not fit in its slot at
+    /// `offset`.
+    fn storage_store(
+        rust: &Self::RustType,
+        slot: U256,
+        offset: usize,
+        storage: &mut BTreeMap<B256, B256>,
+    ) -> Result<()>;
+
+    /// Reads a value from storage, starting at the given slot and byte offset
+    /// from the right of the slot, using `sload` to read slots.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a packed value does not fit in its slot at
+    /// `offset`, or if the stored value is invalid.
+    fn storage_load<F: FnMut(B256) -> B256>(
+        slot: U256,
+        offset: usize,
+        sload: &mut F,
+    ) -> Result<Self::RustType>;
+
+    /// Encodes a value into the storage words it occupies when it is stored at
+    /// `base`.
+    #[inline]
+    fn storage_encode(rust: &Self::RustType, base: B256) -> Result<BTreeMap<B256, B256>> {
+        let mut storage = BTreeMap::new();
+        Self::storage_store(rust, base.into(), 0, &mut storage)?;
+        Ok(storage)
+    }
+
+    /// Decodes a value stored at `base`, using `sload` to read slots.
+    #[inline]
+    fn storage_decode<F: FnMut(B256) -> B256>(base: B256, mut sload: F) -> Result<Self::RustType> {
+        Self::storage_load(base.into(), 0, &mut sload)
+    }
+}
+
+/// Returns the byte range of a packed value of `size` bytes in its ABI word.
+#[inline]
+const fn word_range(size: usize, left_aligned: bool) -> Range<usize> {
+    if left_aligned {
+        0..size
+    } else {
+        32 - size..32
+    }
+}
+
+/// Returns the byte range of a packed value of `size` bytes at `offset` in its
+/// storage slot.
+#[inline]
+fn slot_range(size: usize, offset: usize) -> Result<Range<usize>> {
+    match offset.checked_add(size) {
+        Some(end) if end <= 32 => Ok(32 - end..32 - offset),
+        _ => Err(Error::custom(format!("{size} bytes do not fit in a slot at offset {offset}"))),
+    }
+}
+
+#[inline]
+fn slot_key(slot: U256) -> B256 {
+    B256::from(slot)
+}
+
+#[inline]
+fn data_slot(slot: U256) -> U256 {
+    keccak256(slot_key(slot)).into()
+}
+
+#[inline]
+fn load_length<F: FnMut(B256) -> B256>(slot: U256, sload: &mut F) -> Result<usize> {
+    let len = U256::from_be_bytes(sload(slot_key(slot)).0);
+    match usize::try_from(len) {
+        Ok(len) if len <= MAX_LENGTH => Ok(len),
+        _ => Err(Error::custom(format!("storage length {len} is too large"))),
+    }
+}
+
+/// Returns the position of the `index`th element of an array whose elements
+/// are `size` bytes long, starting at `base`.
+#[inline]
+fn element_position(base: U256, size: usize, index: usize) -> (U256, usize) {
+    if size < 32 {
+        let per_slot = 32 / size.max(1);
+        (base.wrapping_add(U256::from(index / per_slot)), index % per_slot * size)
+    } else {
+        (base.wrapping_add(U256::from(index) * U256::from(size / 32)), 0)
+    }
+}
+
+/// The number of bytes `N` elements of `size` bytes occupy in storage.
+const fn array_storage_bytes(size: usize, n: usize) -> usize {
+    if size == 0 || n == 0 {
+        0
+    } else if size < 32 {
+        let per_slot = 32 / size;
+        (n + per_slot - 1) / per_slot * 32
+    } else {
+        n * size
+    }
+}
+
+/// The number of bytes a struct or tuple with members of the given sizes
+/// occupies in storage.
+const fn tuple_storage_bytes(sizes: &[usize]) -> usize {
+    let mut slots = 0;
+    let mut offset = 0;
+    let mut i = 0;
+    while i < sizes.len() {
+        let size = sizes[i];
+        if offset != 0 && offset + size > 32 {
+            slots += 1;
+            offset = 0;
+        }
+        if size >= 32 {
+            slots += size / 32;
+        } else {
+            offset += size;
+        }
+        i += 1;
+    }
+    if offset != 0 {
+        slots += 1;
+    }
+    slots * 32
+}
+
+/// Tracks the position of the next member of a struct or tuple in storage.
+struct Cursor {
+    slot: U256,
+    offset: usize,
+}
+
+impl Cursor {
+    #[inline]
+    const fn new(slot: U256) -> Self {
+        Self { slot, offset: 0 }
+    }
+
+    /// Returns the position of the next member of `size` bytes, and advances
+    /// past it.
+    #[inline]
+    fn next(&mut self, size: usize) -> (U256, usize) {
+        if self.offset != 0 && self.offset + size > 32 {
+            self.slot = self.slot.wrapping_add(U256::from(1));
+            self.offset = 0;
+        }
+        let position = (self.slot, self.offset);
+        if size >= 32 {
+            self.slot = self.slot.wrapping_add(U256::from(size / 32));
+        } else {
+            self.offset += size;
+        }
+        position
+    }
+}
+
+// Value types: packed into a single slot
+macro_rules! value_impl {
+    ($size:expr, $left_aligned:expr) => {
+        const STORAGE_BYTES: usize = $size;
+
+        #[inline]
+        fn storage_store(
+            rust: &Self::RustType,
+            slot: U256,
+            offset: usize,
+            storage: &mut BTreeMap<B256, B256>,
+        ) -> Result<()> {
+            let range = slot_range($size, offset)?;
+            let token = SolTypeValue::<Self>::stv_to_tokens(rust);
+            let word = storage.entry(slot_key(slot)).or_default();
+            word[range].copy_from_slice(&token.0[word_range($size, $left_aligned)]);
+            Ok(())
+        }
+
+        #[inline]
+        fn storage_load<F: FnMut(B256) -> B256>(
+            slot: U256,
+            offset: usize,
+            sload: &mut F,
+        ) -> Result<Self::RustType> {
+            let range = slot_range($size, offset)?;
+            let mut word = Word::ZERO;
+            word[word_range($size, $left_aligned)].copy_from_slice(&sload(slot_key(slot))[range]);
+            Ok(Self::detokenize(WordToken(word)))
+        }
+    };
+}
+
+impl SolStorage for Bool {
+    value_impl!(1, false);
+}
+
+impl<const BITS: usize> SolStorage for Int<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+{
+    value_impl!(BITS / 8, false);
+}
+
+impl<const BITS: usize> SolStorage for Uint<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+{
+    value_impl!(BITS / 8, false);
+}
+
+impl<const N: usize> SolStorage for FixedBytes<N>
+where
+    ByteCount<N>: SupportedFixedBytes,
+{
+    value_impl!(N, true);
+}
+
+impl SolStorage for Address {
+    value_impl!(20, false);
+}
+
+impl SolStorage for Function {
+    value_impl!(24, true);
+}
+
+// Byte arrays: short or long encoding
+fn store_bytes(data: &[u8], slot: U256, storage: &mut BTreeMap<B256, B256>) {
+    let len = data.len();
+    if len < 32 {
+        let mut word = Word::ZERO;
+        word[..len].copy_from_slice(data);
+        word[31] = len as u8 * 2;
+        storage.insert(slot_key(slot), word);
+        return;
+    }
+
+    storage.insert(slot_key(slot), utils::pad_usize(len * 2 + 1));
+    let base = data_slot(slot);
+    for (i, chunk) in data.chunks(32).enumerate() {
+        let mut word = Word::ZERO;
+        word[..chunk.len()].copy_from_slice(chunk);
+        storage.insert(slot_key(base.wrapping_add(U256::from(i))), word);
+    }
+}
+
+fn load_bytes<F: FnMut(B256) -> B256>(slot: U256, sload: &mut F) -> Result<Vec<u8>> {
+    let word = sload(slot_key(slot));
+    if word[31] & 1 == 0 {
+        let len = word[31] as usize / 2;
+        if len > 31 {
+            return Err(Error::custom(format!("invalid short bytes length {len}")));
+        }
+        return Ok(word[..len].to_vec());
+    }
+
+    let len = load_length(slot, sload)? / 2;
+    let base = data_slot(slot);
+    let mut data = Vec::with_capacity(utils::next_multiple_of_32(len));
+    for i in 0..utils::words_for_len(len) {
+        data.extend_from_slice(sload(slot_key(base.wrapping_add(U256::from(i)))).as_slice());
+    }
+    data.truncate(len);
+    Ok(data)
+}
+
+impl SolStorage for Bytes {
+    const STORAGE_BYTES: usize = 32;
+
+    #[inline]
+    fn storage_store(
+        rust: &Self::RustType,
+        slot: U256,
+        _offset: usize,
+        storage: &mut BTreeMap<B256, B256>,
+    ) -> Result<()> {
+        store_bytes(rust, slot, storage);
+        Ok(())
+    }
+
+    #[inline]
+    fn storage_load<F: FnMut(B256) -> B256>(
+        slot: U256,
+        _offset: usize,
+        sload: &mut F,
+    ) -> Result<Self::RustType> {
+        load_bytes(slot, sload)
+    }
+}
+
+impl SolStorage for String {
+    const STORAGE_BYTES: usize = 32;
+
+    #[inline]
+    fn storage_store(
+        rust: &Self::RustType,
+        slot: U256,
+        _offset: usize,
+        storage: &mut BTreeMap<B256, B256>,
+    ) -> Result<()> {
+        store_bytes(rust.as_bytes(), slot, storage);
+        Ok(())
+    }
+
+    #[inline]
+    fn storage_load<F: FnMut(B256) -> B256>(
+        slot: U256,
+        _offset: usize,
+        sload: &mut F,
+    ) -> Result<Self::RustType> {
+        // Lossy for the same reason as in `String::detokenize`
+        load_bytes(slot, sload).map(|data| RustString::from_utf8_lossy(&data).into_owned())
+    }
+}
+
+// Arrays: elements packed starting at the data slot
+impl<T: SolStorage> SolStorage for Array<T> {
+    const STORAGE_BYTES: usize = 32;
+
+    fn storage_store(
+        rust: &Self::RustType,
+        slot: U256,
+        _offset: usize,
+        storage: &mut BTreeMap<B256, B256>,
+    ) -> Result<()> {
+        storage.insert(slot_key(slot), utils::pad_usize(rust.len()));
+        let base = data_slot(slot);
+        rust.iter().enumerate().try_for_each(|(i, item)| {
+            let (slot, offset) = element_position(base, T::STORAGE_BYTES, i);
+            T::storage_store(item, slot, offset, storage)
+        })
+    }
+
+    fn storage_load<F: FnMut(B256) -> B256>(
+        slot: U256,
+        _offset: usize,
+        sload: &mut F,
+    ) -> Result<Self::RustType> {
+        let len = load_length(slot, sload)?;
+        let base = data_slot(slot);
+        (0..len)
+            .map(|i| {
+                let (slot, offset) = element_position(base, T::STORAGE_BYTES, i);
+                T::storage_load(slot, offset, sload)
+            })
+            .collect()
+    }
+}
+
+impl<T: SolStorage, const N: usize> SolStorage for FixedArray<T, N> {
+    const STORAGE_BYTES: usize = array_storage_bytes(T::STORAGE_BYTES, N);
+
+    fn storage_store(
+        rust: &Self::RustType,
+        slot: U256,
+        _offset: usize,
+        storage: &mut BTreeMap<B256, B256>,
+    ) -> Result<()> {
+        rust.iter().enumerate().try_for_each(|(i, item)| {
+            let (slot, offset) = element_position(slot, T::STORAGE_BYTES, i);
+            T::storage_store(item, slot, offset, storage)
+        })
+    }
+
+    fn storage_load<F: FnMut(B256) -> B256>(
+        slot: U256,
+        _offset: usize,
+        sload: &mut F,
+    ) -> Result<Self::RustType> {
+        let items = (0..N)
+            .map(|i| {
+                let (slot, offset) = element_position(slot, T::STORAGE_BYTES, i);
+                T::storage_load(slot, offset, sload)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        match items.try_into() {
+            Ok(items) => Ok(items),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+// Tuples: members packed like struct fields
+macro_rules! tuple_impls {
+    ($count:literal $($ty:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($ty: SolStorage,)+> SolStorage for ($($ty,)+) {
+            const STORAGE_BYTES: usize = tuple_storage_bytes(&[$(<$ty>::STORAGE_BYTES,)+]);
+
+            fn storage_store(
+                rust: &Self::RustType,
+                slot: U256,
+                _offset: usize,
+                storage: &mut BTreeMap<B256, B256>,
+            ) -> Result<()> {
+                let ($($ty,)+) = rust;
+                let mut cursor = Cursor::new(slot);
+                $(
+                    let (slot, offset) = cursor.next(<$ty>::STORAGE_BYTES);
+                    <$ty>::storage_store($ty, slot, offset, storage)?;
+                )+
+                Ok(())
+            }
+
+            fn storage_load<F: FnMut(B256) -> B256>(
+                slot: U256,
+                _offset: usize,
+                sload: &mut F,
+            ) -> Result<Self::RustType> {
+                let mut cursor = Cursor::new(slot);
+                Ok(($({
+                    let (slot, offset) = cursor.next(<$ty>::STORAGE_BYTES);
+                    <$ty>::storage_load(slot, offset, sload)?
+                },)+))
+            }
+        }
+    };
+}
+
+impl SolStorage for () {
+    const STORAGE_BYTES: usize = 0;
+
+    #[inline]
+    fn storage_store(
+        _: &Self::RustType,
+        _: U256,
+        _: usize,
+        _: &mut BTreeMap<B256, B256>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn storage_load<F: FnMut(B256) -> B256>(_: U256, _: usize, _: &mut F) -> Result<()> {
+        Ok(())
+    }
+}
+
+all_the_tuples!(tuple_impls);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sol;
+    use alloy_primitives::{address, b256, hex, Address as RustAddress};
+
+    sol! {
+        struct Inner {
+            uint8 a;
+            bytes4 b;
+        }
+
+        struct Outer {
+            uint128 x;
+            uint64 y;
+            bool z;
+            Inner inner;
+            address owner;
+            uint16[3] small;
+            uint256[] list;
+            string name;
+            int32 delta;
+        }
+    }
+
+    fn decode<T: SolStorage>(storage: &BTreeMap<B256, B256>, base: B256) -> T::RustType {
+        T::storage_decode(base, |slot| storage.get(&slot).copied().unwrap_or_default()).unwrap()
+    }
+
+    fn word(n: u64) -> B256 {
+        U256::from(n).into()
+    }
+
+    #[test]
+    fn footprint() {
+        assert_eq!(Bool::STORAGE_BYTES, 1);
+        assert_eq!(Uint::<24>::STORAGE_BYTES, 3);
+        assert_eq!(Address::STORAGE_BYTES, 20);
+        assert_eq!(Function::STORAGE_BYTES, 24);
+        assert_eq!(String::STORAGE_SLOTS, 1);
+        assert_eq!(FixedArray::<Uint<8>, 32>::STORAGE_SLOTS, 1);
+        assert_eq!(FixedArray::<Uint<8>, 33>::STORAGE_SLOTS, 2);
+        assert_eq!(FixedArray::<Uint<128>, 3>::STORAGE_SLOTS, 2);
+        assert_eq!(FixedArray::<Address, 3>::STORAGE_SLOTS, 3);
+        assert_eq!(FixedArray::<FixedArray<Bool, 2>, 3>::STORAGE_SLOTS, 3);
+        assert_eq!(<(Bool, Uint<256>, Bool)>::STORAGE_SLOTS, 3);
+        assert_eq!(<(Bool, Bool, Uint<256>)>::STORAGE_SLOTS, 2);
+        assert_eq!(<()>::STORAGE_SLOTS, 0);
+        assert_eq!(Inner::STORAGE_SLOTS, 1);
+        // x, y, z | inner | owner | small | list | name | delta
+        assert_eq!(Outer::STORAGE_SLOTS, 7);
+    }
+
+    #[test]
+    fn packed_values() {
+        let value = (
+            true,
+            RustAddress::repeat_byte(0x11),
+            -2i16,
+            alloy_primitives::FixedBytes([0xaa, 0xbb]),
+            U256::from(7),
+        );
+        type T = (Bool, Address, Int<16>, FixedBytes<2>, Uint<256>);
+        let storage = T::storage_encode(&value, B256::ZERO).unwrap();
+        assert_eq!(
+            storage,
+            BTreeMap::from([
+                (
+                    word(0),
+                    b256!("00000000000000aabbfffe111111111111111111111111111111111111111101")
+                ),
+                (word(1), word(7)),
+            ])
+        );
+        assert_eq!(decode::<T>(&storage, B256::ZERO), value);
+    }
+
+    #[test]
+    fn packing_preserves_neighbours() {
+        let mut storage = BTreeMap::from([(word(0), B256::repeat_byte(0xff))]);
+        Uint::<8>::storage_store(&0, U256::ZERO, 1, &mut storage).unwrap();
+        assert_eq!(
+            storage[&word(0)],
+            b256!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00ff")
+        );
+    }
+
+    #[test]
+    fn invalid_offset() {
+        let mut storage = BTreeMap::new();
+        Uint::<16>::storage_store(&1, U256::ZERO, 30, &mut storage).unwrap();
+        Uint::<16>::storage_store(&1, U256::ZERO, 31, &mut storage).unwrap_err();
+        Address::storage_store(&RustAddress::ZERO, U256::ZERO, usize::MAX, &mut storage)
+            .unwrap_err();
+        Bool::storage_load(U256::ZERO, 32, &mut |_| B256::ZERO).unwrap_err();
+        assert_eq!(storage.len(), 1);
+    }
+
+    #[test]
+    fn bytes_and_strings() {
+        let short = String::storage_encode(&"hello".into(), B256::ZERO).unwrap();
+        assert_eq!(
+            short,
+            BTreeMap::from([(
+                word(0),
+                b256!("68656c6c6f00000000000000000000000000000000000000000000000000000a")
+            )])
+        );
+        assert_eq!(decode::<String>(&short, B256::ZERO), "hello");
+
+        let data = hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+            "2021"
+        )
+        .to_vec();
+        let long = Bytes::storage_encode(&data, word(3)).unwrap();
+        let base = keccak256(word(3));
+        assert_eq!(long.len(), 3);
+        assert_eq!(long[&word(3)], word(34 * 2 + 1));
+        assert_eq!(long[&base], B256::from_slice(&data[..32]));
+        assert_eq!(
+            long[&B256::from(U256::from_be_bytes(base.0) + U256::from(1))],
+            b256!("2021000000000000000000000000000000000000000000000000000000000000")
+        );
+        assert_eq!(decode::<Bytes>(&long, word(3)), data);
+
+        let invalid = BTreeMap::from([(word(0), word(0x40))]);
+        assert!(Bytes::storage_decode(B256::ZERO, |slot| invalid[&slot]).is_err());
+    }
+
+    #[test]
+    fn dynamic_arrays() {
+        let value = vec![1u16, 2, 3];
+        let storage = Array::<Uint<16>>::storage_encode(&value, word(5)).unwrap();
+        let base = keccak256(word(5));
+        assert_eq!(storage[&word(5)], word(3));
+        assert_eq!(
+            storage[&base],
+            b256!("0000000000000000000000000000000000000000000000000000000300020001")
+        );
+        assert_eq!(decode::<Array<Uint<16>>>(&storage, word(5)), value);
+
+        let huge = BTreeMap::from([(B256::ZERO, B256::repeat_byte(0xff))]);
+        assert!(Array::<Bool>::storage_decode(B256::ZERO, |slot| huge[&slot]).is_err());
+    }
+
+    #[test]
+    fn structs() {
+        let value = Outer {
+            x: 1,
+            y: 2,
+            z: true,
+            inner: Inner { a: 3, b: [4, 5, 6, 7].into() },
+            owner: address!("00000000219ab540356cBB839Cbe05303d7705Fa"),
+            small: [8, 9, 10],
+            list: vec![U256::from(11), U256::from(12)],
+            name: "alloy".into(),
+            delta: -1,
+        };
+        let base = word(10);
+        let storage = Outer::storage_encode(&value, base).unwrap();
+        let slot = |i: u64| word(10 + i);
+
+        assert_eq!(
+            storage[&slot(0)],
+            b256!("0000000000000001000000000000000200000000000000000000000000000001")
+        );
+        assert_eq!(
+            storage[&slot(1)],
+            b256!("0000000000000000000000000000000000000000000000000000000405060703")
+        );
+        assert_eq!(
+            storage[&slot(2)],
+            b256!("00000000000000000000000000000000219ab540356cbb839cbe05303d7705fa")
+        );
+        assert_eq!(
+            storage[&slot(3)],
+            b256!("0000000000000000000000000000000000000000000000000000000a00090008")
+        );
+        assert_eq!(storage[&slot(4)], word(2));
+        assert_eq!(storage[&keccak256(slot(4))], word(11));
+        assert_eq!(
+            storage[&slot(5)],
+            b256!("616c6c6f7900000000000000000000000000000000000000000000000000000a")
+        );
+        assert_eq!(
+            storage[&slot(6)],
+            b256!("00000000000000000000000000000000000000000000000000000000ffffffff")
+        );
+
+        let decoded = decode::<Outer>(&storage, base);
+        assert_eq!(decoded.x, value.x);
+        assert_eq!(decoded.inner.b, value.inner.b);
+        assert_eq!(decoded.owner, value.owner);
+        assert_eq!(decoded.small, value.small);
+        assert_eq!(decoded.list, value.list);
+        assert_eq!(decoded.name, value.name);
+        assert_eq!(decoded.delta, value.delta);
+    }
+}
@@ -776,3 +776,40 @@ fn decoder_fixed_array_before_dynamic() {
     let decoded = FullReport::abi_decode(&encoded, true).unwrap();
     assert_eq!(decoded, full_report);
 }
+
+#[test]
+fn constant_array_sizes() {
+    sol! {
+        uint256 constant N_TOKENS = 3;
+        uint256 constant MAX = 2 * N_TOKENS + 1;
+        uint256 constant WRAPPED = uint8(258);
+
+        struct Pool {
+            address[N_TOKENS] tokens;
+            bytes32[2 * MAX] slots;
+            uint8[(MAX - 1) / 2][Pools.COUNT] weights;
+            bool[WRAPPED] flags;
+        }
+
+        contract Pools {
+            uint256 constant COUNT = 1 << 2;
+
+            function balances(uint256[N_TOKENS + COUNT] amounts) external returns (uint128[COUNT] memory);
+        }
+    }
+
+    assert_eq!(
+        Pool::eip712_encode_type(),
+        "Pool(address[3] tokens,bytes32[14] slots,uint8[3][4] weights,bool[2] flags)"
+    );
+    let pool = Pool {
+        tokens: [Address::ZERO; 3],
+        slots: [Default::default(); 14],
+        weights: [[0; 3]; 4],
+        flags: [false; 2],
+    };
+    assert_eq!(<Pool as SolType>::abi_encoded_size(&pool), 32 * (3 + 14 + 12 + 2));
+
+    assert_eq!(Pools::balancesCall::SIGNATURE, "balances(uint256[7])");
+    let _: [u128; 4] = Pools::balancesReturn { _0: [0; 4] }._0;
+}
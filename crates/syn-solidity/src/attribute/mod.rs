@@ -1,4 +1,8 @@
-use crate::{kw, utils::DebugPunctuated, Expr, SolPath, Spanned};
+use crate::{
+    kw,
+    utils::{fmt_comma_separated, DebugPunctuated},
+    Expr, SolPath, Spanned,
+};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -136,13 +140,7 @@ impl fmt::Display for Modifier {
         self.name.fmt(f)?;
         if self.paren_token.is_some() {
             f.write_str("(")?;
-            for (i, _arg) in self.arguments.iter().enumerate() {
-                if i > 0 {
-                    f.write_str(", ")?;
-                }
-                // TODO: impl fmt::Display for Expr
-                f.write_str("<expr>")?;
-            }
+            fmt_comma_separated(f, &self.arguments)?;
             f.write_str(")")?;
         }
         Ok(())
@@ -1,6 +1,6 @@
 use crate::{
     kw,
-    utils::{DebugPunctuated, ParseNested},
+    utils::{fmt_comma_separated, DebugPunctuated, ParseNested},
     Expr, SolIdent, Spanned,
 };
 use proc_macro2::Span;
@@ -20,6 +20,13 @@ pub struct ExprCall {
     pub args: ArgList,
 }
 
+impl fmt::Display for ExprCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.expr.fmt(f)?;
+        self.args.fmt(f)
+    }
+}
+
 impl ParseNested for ExprCall {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { expr, args: input.parse()? })
@@ -53,6 +60,13 @@ impl fmt::Debug for ExprPayable {
     }
 }
 
+impl fmt::Display for ExprPayable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("payable")?;
+        self.args.fmt(f)
+    }
+}
+
 impl From<ExprPayable> for ExprCall {
     fn from(value: ExprPayable) -> Self {
         Self {
@@ -99,6 +113,14 @@ impl fmt::Debug for ArgList {
     }
 }
 
+impl fmt::Display for ArgList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        self.list.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
 impl Parse for ArgList {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
@@ -134,6 +156,15 @@ impl fmt::Debug for ArgListImpl {
     }
 }
 
+impl fmt::Display for ArgListImpl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unnamed(list) => fmt_comma_separated(f, list),
+            Self::Named(list) => list.fmt(f),
+        }
+    }
+}
+
 impl Parse for ArgListImpl {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         if input.peek(Brace) {
@@ -151,6 +182,13 @@ pub struct ExprCallOptions {
     pub args: NamedArgList,
 }
 
+impl fmt::Display for ExprCallOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.expr.fmt(f)?;
+        self.args.fmt(f)
+    }
+}
+
 impl ParseNested for ExprCallOptions {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { expr, args: input.parse()? })
@@ -184,6 +222,14 @@ impl fmt::Debug for NamedArgList {
     }
 }
 
+impl fmt::Display for NamedArgList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        fmt_comma_separated(f, &self.list)?;
+        f.write_str("}")
+    }
+}
+
 impl Parse for NamedArgList {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
@@ -218,6 +264,12 @@ impl fmt::Debug for NamedArg {
     }
 }
 
+impl fmt::Display for NamedArg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.arg)
+    }
+}
+
 impl Parse for NamedArg {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { name: input.parse()?, colon_token: input.parse()?, arg: input.parse()? })
@@ -1,5 +1,5 @@
 use crate::{
-    utils::{DebugPunctuated, ParseNested},
+    utils::{fmt_comma_separated, DebugPunctuated, ParseNested},
     Expr, Spanned,
 };
 use proc_macro2::Span;
@@ -25,6 +25,14 @@ impl fmt::Debug for ExprArray {
     }
 }
 
+impl fmt::Display for ExprArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        fmt_comma_separated(f, &self.elems)?;
+        f.write_str("]")
+    }
+}
+
 impl Parse for ExprArray {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
@@ -65,6 +73,23 @@ impl fmt::Debug for ExprIndex {
     }
 }
 
+impl fmt::Display for ExprIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.expr.fmt(f)?;
+        f.write_str("[")?;
+        if let Some(start) = &self.start {
+            start.fmt(f)?;
+        }
+        if self.colon_token.is_some() {
+            f.write_str(":")?;
+        }
+        if let Some(end) = &self.end {
+            end.fmt(f)?;
+        }
+        f.write_str("]")
+    }
+}
+
 impl ParseNested for ExprIndex {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         let content;
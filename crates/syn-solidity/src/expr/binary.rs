@@ -1,5 +1,6 @@
 use crate::{utils::ParseNested, Expr, Spanned};
 use proc_macro2::Span;
+use std::fmt;
 use syn::{
     parse::{Parse, ParseStream},
     Result,
@@ -13,6 +14,12 @@ pub struct ExprBinary {
     pub right: Box<Expr>,
 }
 
+impl fmt::Display for ExprBinary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.left, self.op, self.right)
+    }
+}
+
 impl ParseNested for ExprBinary {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { left: expr, op: input.parse()?, right: input.parse()? })
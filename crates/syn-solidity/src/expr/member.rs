@@ -23,6 +23,12 @@ impl fmt::Debug for ExprMember {
     }
 }
 
+impl fmt::Display for ExprMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.expr, self.member)
+    }
+}
+
 impl ParseNested for ExprMember {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { expr, dot_token: input.parse()?, member: input.parse()? })
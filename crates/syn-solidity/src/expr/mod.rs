@@ -125,6 +125,31 @@ impl fmt::Debug for Expr {
     }
 }
 
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Array(expr) => expr.fmt(f),
+            Self::Binary(expr) => expr.fmt(f),
+            Self::Call(expr) => expr.fmt(f),
+            Self::CallOptions(expr) => expr.fmt(f),
+            Self::Delete(expr) => expr.fmt(f),
+            Self::Ident(ident) => ident.fmt(f),
+            Self::Index(expr) => expr.fmt(f),
+            Self::Lit(lit) => lit.fmt(f),
+            Self::LitDenominated(lit) => lit.fmt(f),
+            Self::Member(expr) => expr.fmt(f),
+            Self::New(expr) => expr.fmt(f),
+            Self::Payable(expr) => expr.fmt(f),
+            Self::Postfix(expr) => expr.fmt(f),
+            Self::Ternary(expr) => expr.fmt(f),
+            Self::Tuple(expr) => expr.fmt(f),
+            Self::Type(ty) => ty.fmt(f),
+            Self::TypeCall(expr) => expr.fmt(f),
+            Self::Unary(expr) => expr.fmt(f),
+        }
+    }
+}
+
 impl Parse for Expr {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         // skip any attributes
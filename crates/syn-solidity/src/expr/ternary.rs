@@ -26,6 +26,12 @@ impl fmt::Debug for ExprTernary {
     }
 }
 
+impl fmt::Display for ExprTernary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ? {} : {}", self.cond, self.if_true, self.if_false)
+    }
+}
+
 impl ParseNested for ExprTernary {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         Ok(Self {
@@ -1,4 +1,7 @@
-use crate::{utils::DebugPunctuated, Expr, Spanned};
+use crate::{
+    utils::{fmt_comma_separated, DebugPunctuated},
+    Expr, Spanned,
+};
 use proc_macro2::Span;
 use std::fmt;
 use syn::{
@@ -22,6 +25,14 @@ impl fmt::Debug for ExprTuple {
     }
 }
 
+impl fmt::Display for ExprTuple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        fmt_comma_separated(f, &self.elems)?;
+        f.write_str(")")
+    }
+}
+
 impl Parse for ExprTuple {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
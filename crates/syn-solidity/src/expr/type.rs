@@ -22,6 +22,12 @@ impl fmt::Debug for ExprTypeCall {
     }
 }
 
+impl fmt::Display for ExprTypeCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "type({})", self.ty)
+    }
+}
+
 impl Parse for ExprTypeCall {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
@@ -60,6 +66,12 @@ impl fmt::Debug for ExprNew {
     }
 }
 
+impl fmt::Display for ExprNew {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "new {}", self.ty)
+    }
+}
+
 impl Parse for ExprNew {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { new_token: input.parse()?, ty: input.parse()? })
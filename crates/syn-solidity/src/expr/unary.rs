@@ -13,6 +13,12 @@ pub struct ExprUnary {
     pub expr: Box<Expr>,
 }
 
+impl fmt::Display for ExprUnary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op, self.expr)
+    }
+}
+
 impl Parse for ExprUnary {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { op: input.parse()?, expr: input.parse()? })
@@ -44,6 +50,12 @@ impl fmt::Debug for ExprDelete {
     }
 }
 
+impl fmt::Display for ExprDelete {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "delete {}", self.expr)
+    }
+}
+
 impl Parse for ExprDelete {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { delete_token: input.parse()?, expr: input.parse()? })
@@ -69,6 +81,12 @@ pub struct ExprPostfix {
     pub op: PostUnOp,
 }
 
+impl fmt::Display for ExprPostfix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.expr, self.op)
+    }
+}
+
 impl ParseNested for ExprPostfix {
     fn parse_nested(expr: Box<Expr>, input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { expr, op: input.parse()? })
@@ -44,6 +44,33 @@ impl fmt::Debug for Lit {
     }
 }
 
+impl fmt::Display for Lit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(lit) => f.write_str(if lit.value() { "true" } else { "false" }),
+            Self::Hex(lit) => fmt_str_values(f, "hex", lit.values.iter().map(|v| &**v)),
+            Self::Number(lit) => lit.fmt(f),
+            Self::Str(lit) => fmt_str_values(f, "", &lit.values),
+            Self::Unicode(lit) => fmt_str_values(f, "unicode", lit.values.iter().map(|v| &**v)),
+        }
+    }
+}
+
+/// Writes the string literals in their quoted form, preceded by `prefix`.
+fn fmt_str_values<'a>(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    values: impl IntoIterator<Item = &'a syn::LitStr>,
+) -> fmt::Result {
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(" ")?;
+        }
+        write!(f, "{prefix}{}", value.token())?;
+    }
+    Ok(())
+}
+
 impl Parse for Lit {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let lookahead = input.lookahead1();
@@ -24,6 +24,15 @@ impl fmt::Debug for LitNumber {
     }
 }
 
+impl fmt::Display for LitNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(lit) => lit.fmt(f),
+            Self::Float(lit) => lit.fmt(f),
+        }
+    }
+}
+
 impl Parse for LitNumber {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let lookahead = input.lookahead1();
@@ -111,6 +120,12 @@ pub struct LitDenominated {
     pub denom: SubDenomination,
 }
 
+impl fmt::Display for LitDenominated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.number, self.denom)
+    }
+}
+
 impl Parse for LitDenominated {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         Ok(Self { number: input.parse()?, denom: input.parse()? })
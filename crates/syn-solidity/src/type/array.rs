@@ -22,7 +22,7 @@ pub struct TypeArray {
 
 impl PartialEq for TypeArray {
     fn eq(&self, other: &Self) -> bool {
-        self.ty == other.ty && self.size() == other.size()
+        self.ty == other.ty && self.size_key() == other.size_key()
     }
 }
 
@@ -31,7 +31,8 @@ impl Eq for TypeArray {}
 impl Hash for TypeArray {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.ty.hash(state);
-        self.size().hash(state);
+        // unevaluated sizes all hash the same, which is consistent with `Eq`
+        self.try_size().ok().hash(state);
     }
 }
 
@@ -39,8 +40,10 @@ impl fmt::Display for TypeArray {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.ty.fmt(f)?;
         f.write_str("[")?;
-        if let Some(s) = self.size_lit() {
-            f.write_str(s.base10_digits())?;
+        match self.size.as_deref() {
+            Some(Expr::Lit(Lit::Number(n))) => f.write_str(n.base10_digits())?,
+            Some(expr) => expr.fmt(f)?,
+            None => {}
         }
         f.write_str("]")
     }
@@ -48,7 +51,13 @@ impl fmt::Display for TypeArray {
 
 impl fmt::Debug for TypeArray {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("TypeArray").field(&self.ty).field(&self.size()).finish()
+        let mut t = f.debug_tuple("TypeArray");
+        t.field(&self.ty);
+        match self.size.as_deref() {
+            Some(expr) if !self.is_size_evaluated() => t.field(expr),
+            _ => t.field(&self.size()),
+        };
+        t.finish()
     }
 }
 
@@ -76,16 +85,66 @@ impl Spanned for TypeArray {
 
 impl TypeArray {
     /// Returns the size of the array, or None if dynamic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is a constant expression which has not been
+    /// evaluated yet. See [`is_size_evaluated`](Self::is_size_evaluated).
     pub fn size(&self) -> Option<usize> {
-        self.size_lit().map(|s| s.base10_parse().unwrap())
+        self.try_size().unwrap()
     }
 
     /// Returns the size of the array, or None if dynamic.
+    ///
+    /// Returns an error if the size is a constant expression which has not
+    /// been evaluated yet. See [`is_size_evaluated`](Self::is_size_evaluated).
+    pub fn try_size(&self) -> Result<Option<usize>> {
+        self.try_size_lit()?.map(LitNumber::base10_parse).transpose()
+    }
+
+    /// Returns the size of the array, or None if dynamic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is a constant expression which has not been
+    /// evaluated yet. See [`is_size_evaluated`](Self::is_size_evaluated).
     pub fn size_lit(&self) -> Option<&LitNumber> {
-        self.size.as_ref().map(|s| match &**s {
-            Expr::Lit(Lit::Number(n)) => n,
-            _ => panic!("unevaluated literal in array size"),
-        })
+        self.try_size_lit().unwrap()
+    }
+
+    /// Returns the size of the array, or None if dynamic.
+    ///
+    /// Returns an error if the size is a constant expression which has not
+    /// been evaluated yet. See [`is_size_evaluated`](Self::is_size_evaluated).
+    pub fn try_size_lit(&self) -> Result<Option<&LitNumber>> {
+        self.size
+            .as_deref()
+            .map(|s| match s {
+                Expr::Lit(Lit::Number(n)) => Ok(n),
+                _ => {
+                    Err(syn::Error::new(s.span(), "unevaluated constant expression in array size"))
+                }
+            })
+            .transpose()
+    }
+
+    /// Returns `true` if the array is dynamic or if its size is an integer
+    /// literal.
+    ///
+    /// Sizes may also be constant expressions, like `N * 2`, which must be
+    /// evaluated and replaced with a literal before calling
+    /// [`size`](Self::size).
+    pub fn is_size_evaluated(&self) -> bool {
+        matches!(self.size.as_deref(), None | Some(Expr::Lit(Lit::Number(LitNumber::Int(_)))))
+    }
+
+    /// Sizes used for equality. Unevaluated sizes are compared by their
+    /// formatted expression, which does not depend on spans or whitespace.
+    fn size_key(&self) -> Option<std::result::Result<usize, String>> {
+        match self.try_size() {
+            Ok(size) => size.map(Ok),
+            Err(_) => self.size.as_ref().map(|expr| Err(expr.to_string())),
+        }
     }
 
     /// See [`Type::is_abi_dynamic`].
@@ -107,24 +166,59 @@ impl TypeArray {
                     None
                 } else {
                     let fork = content.fork();
-                    let sz = match fork.parse::<syn::LitInt>() {
-                        Ok(lit) => lit,
-                        Err(e) => {
-                            return Err(match fork.parse::<Expr>() {
-                                Ok(_) => syn::Error::new(
-                                    e.span(),
-                                    "generic expressions are not supported in array type sizes",
-                                ),
-                                Err(e) => e,
-                            })
+                    match fork.parse::<syn::LitInt>() {
+                        Ok(sz) if fork.is_empty() => {
+                            content.advance_to(&fork);
+                            // Validate the size
+                            sz.base10_parse::<NonZeroUsize>()?;
+                            Some(Box::new(Expr::Lit(Lit::Number(LitNumber::Int(sz)))))
                         }
-                    };
-                    content.advance_to(&fork);
-                    // Validate the size
-                    sz.base10_parse::<NonZeroUsize>()?;
-                    Some(Box::new(Expr::Lit(Lit::Number(LitNumber::Int(sz)))))
+                        // constant expression, evaluated during expansion
+                        _ => Some(Box::new(content.parse()?)),
+                    }
                 }
             },
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array(s: &str) -> TypeArray {
+        match syn::parse_str::<Type>(s).unwrap() {
+            Type::Array(array) => array,
+            ty => panic!("not an array: {ty:?}"),
+        }
+    }
+
+    #[test]
+    fn evaluated_size() {
+        let a = array("uint256[2]");
+        assert!(a.is_size_evaluated());
+        assert_eq!(a.try_size().unwrap(), Some(2));
+        assert_eq!(a.to_string(), "uint256[2]");
+
+        let a = array("bool[]");
+        assert_eq!(a.try_size().unwrap(), None);
+        assert_eq!(a.to_string(), "bool[]");
+    }
+
+    #[test]
+    fn unevaluated_size() {
+        let a = array("uint256[N * (2 + C.M)]");
+        assert!(!a.is_size_evaluated());
+        assert!(a.try_size().is_err());
+        assert!(a.try_size_lit().is_err());
+        assert_eq!(a.to_string(), "uint256[N * (2 + C.M)]");
+
+        assert_eq!(a, array("uint256[N*(2+C.M)]"));
+        assert_ne!(a, array("uint256[N * (2 + C.K)]"));
+        assert_ne!(a, array("uint256[2]"));
+
+        for s in ["uint8[type(uint8).max - 1]", "bytes[1 ether / 2]", "bool[-x ? y : z]"] {
+            assert_eq!(array(s).to_string(), s);
+        }
+    }
+}
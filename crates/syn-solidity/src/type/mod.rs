@@ -399,10 +399,10 @@ impl Type {
             Self::Custom(_) => s.push_str("tuple"),
             Self::Array(array) => {
                 array.ty.abi_name_raw(s);
-                if let Some(size) = array.size() {
-                    write!(s, "[{size}]").unwrap();
-                } else {
-                    s.push_str("[]");
+                match (array.try_size(), &array.size) {
+                    (Ok(Some(size)), _) => write!(s, "[{size}]").unwrap(),
+                    (Err(_), Some(expr)) => write!(s, "[{expr}]").unwrap(),
+                    _ => s.push_str("[]"),
                 }
             }
             _ => write!(s, "{self}").unwrap(),
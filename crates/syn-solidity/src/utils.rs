@@ -25,6 +25,20 @@ impl<T: fmt::Debug, P> fmt::Debug for DebugPunctuated<T, P> {
     }
 }
 
+/// Writes the items separated by `", "`.
+pub(crate) fn fmt_comma_separated<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    items: impl IntoIterator<Item = T>,
+) -> fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        item.fmt(f)?;
+    }
+    Ok(())
+}
+
 pub(crate) fn tts_until_semi(input: ParseStream<'_>) -> TokenStream {
     let mut tts = TokenStream::new();
     while !input.is_empty() && !input.peek(Token![;]) {